@@ -0,0 +1,154 @@
+use crate::error as asn1err;
+use crate::types::BitString;
+use ascii::AsciiString;
+use std::ops::RangeBounds;
+
+/// A trait for the types whose size can be constrained (SIZE), which
+/// is the number of items of a list, characters of a string, or bits
+/// of a BitString
+pub trait Asn1Size {
+    fn asn1_size(&self) -> usize;
+}
+
+impl<T> Asn1Size for Vec<T> {
+    fn asn1_size(&self) -> usize {
+        return self.len();
+    }
+}
+
+impl Asn1Size for String {
+    fn asn1_size(&self) -> usize {
+        return self.chars().count();
+    }
+}
+
+impl Asn1Size for AsciiString {
+    fn asn1_size(&self) -> usize {
+        return self.len();
+    }
+}
+
+impl Asn1Size for BitString {
+    fn asn1_size(&self) -> usize {
+        // the padding of a value which was not parsed can be invalid
+        return self
+            .bytes
+            .len()
+            .checked_mul(8)
+            .and_then(|bits| bits.checked_sub(self.padding_length as usize))
+            .unwrap_or(0);
+    }
+}
+
+/// Checks that the size of a value is in the given range, as
+/// `SIZE (1..MAX)`. The range representation is used in the error.
+pub fn check_size<T: Asn1Size + ?Sized, R: RangeBounds<usize>>(
+    value: &T,
+    range: R,
+    range_repr: &str,
+) -> asn1err::Result<()> {
+    let size = value.asn1_size();
+    if !range.contains(&size) {
+        return Err(asn1err::Error::ConstraintError(format!(
+            "Size {} out of range {}",
+            size, range_repr
+        )))?;
+    }
+    return Ok(());
+}
+
+/// Checks that a value is in the given range, as `INTEGER (0..65535)`.
+/// The range representation is used in the error.
+pub fn check_range<T: PartialOrd, R: RangeBounds<T>>(
+    value: &T,
+    range: R,
+    range_repr: &str,
+) -> asn1err::Result<()> {
+    if !range.contains(value) {
+        return Err(asn1err::Error::ConstraintError(format!(
+            "Value out of range {}",
+            range_repr
+        )))?;
+    }
+    return Ok(());
+}
+
+/// Checks that all the characters of a string are in the permitted
+/// alphabet, as `IA5String (FROM ("0123456789"))`
+pub fn check_alphabet<T: AsRef<str> + ?Sized>(
+    value: &T,
+    alphabet: &str,
+) -> asn1err::Result<()> {
+    for character in value.as_ref().chars() {
+        if !alphabet.contains(character) {
+            return Err(asn1err::Error::ConstraintError(format!(
+                "Character {:?} not in alphabet {:?}",
+                character, alphabet
+            )))?;
+        }
+    }
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_size_of_types() {
+        assert_eq!(3, vec![1, 2, 3].asn1_size());
+        assert_eq!(4, "añas".to_string().asn1_size());
+        assert_eq!(2, AsciiString::from_str("ab").unwrap().asn1_size());
+        assert_eq!(0, BitString::default().asn1_size());
+        assert_eq!(13, BitString::new(vec![0xff, 0xf8], 3).asn1_size());
+        assert_eq!(
+            0,
+            BitString {
+                bytes: vec![0xff],
+                padding_length: 9
+            }
+            .asn1_size()
+        );
+    }
+
+    #[test]
+    fn test_check_size() {
+        check_size(&vec![1], 1.., "1..").unwrap();
+        check_size(&"abcd".to_string(), 4..=4, "4").unwrap();
+    }
+
+    #[should_panic(expected = "ConstraintError(\"Size 0 out of range 1..\")")]
+    #[test]
+    fn test_check_size_out_of_range() {
+        check_size(&Vec::<u8>::new(), 1.., "1..").unwrap();
+    }
+
+    #[test]
+    fn test_check_range() {
+        check_range(&0, 0..=65535, "0..=65535").unwrap();
+        check_range(&-5, ..0, "..0").unwrap();
+    }
+
+    #[should_panic(
+        expected = "ConstraintError(\"Value out of range 0..=65535\")"
+    )]
+    #[test]
+    fn test_check_range_out_of_range() {
+        check_range(&65536, 0..=65535, "0..=65535").unwrap();
+    }
+
+    #[test]
+    fn test_check_alphabet() {
+        check_alphabet("1234", "0123456789").unwrap();
+        check_alphabet(&AsciiString::from_str("").unwrap(), "01").unwrap();
+    }
+
+    #[should_panic(
+        expected = "ConstraintError(\"Character 'a' not in alphabet \\\"01\\\"\")"
+    )]
+    #[test]
+    fn test_check_alphabet_with_invalid_character() {
+        check_alphabet("10a", "01").unwrap();
+    }
+}
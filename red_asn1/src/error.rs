@@ -1,12 +1,17 @@
-use ascii;
 use std::fmt;
 use std::result;
-use crate::tag::TagClass;
+use crate::tag::{Tag, TagClass};
 
 /// Result that encapsulates the Error type of this library
 pub type Result<T> = result::Result<T, Error>;
 
 /// Error in ASN1-DER parse/build operations
+///
+/// The errors of the fields and alternatives of the derived types are
+/// wrapped with the name of the type and the field, and the offset of
+/// the element from the start of the parsed octets, so the path and the
+/// position where the parse failed can be retrieved with `path` and
+/// `offset`.
 #[derive(Clone, Debug, PartialEq)]
 pub enum Error {
     /// Tag cannot be parsed because there are no data
@@ -16,7 +21,13 @@ pub enum Error {
     NotEnoughTagOctets(TagClass),
 
     /// Tag parsed is not the expected for the type
-    UnmatchedTag(TagClass),
+    UnmatchedTag { expected: Tag, found: Tag },
+
+    /// Tag parsed does not select any alternative of a choice
+    UnmatchedAlternative(Tag),
+
+    /// Discriminant does not select any value of the open type
+    UnknownDiscriminant(&'static str),
 
     /// No length was provided
     LengthEmpty,
@@ -30,11 +41,35 @@ pub enum Error {
     /// No found component with the identifier specified
     NoComponent,
 
-    /// Error in a field of a sequence
-    SequenceFieldError(String, String, Box<Error>),
+    /// Error in a field of a sequence, which starts at offset
+    SequenceFieldError {
+        sequence: &'static str,
+        field: &'static str,
+        offset: usize,
+        error: Box<Error>,
+    },
+
+    /// Error while processing a sequence, which starts at offset
+    SequenceError {
+        sequence: &'static str,
+        offset: usize,
+        error: Box<Error>,
+    },
 
-    /// Error while processing a sequence
-    SequenceError(String, Box<Error>),
+    /// Error in an alternative of a choice, which starts at offset
+    ChoiceAlternativeError {
+        choice: &'static str,
+        alternative: &'static str,
+        offset: usize,
+        error: Box<Error>,
+    },
+
+    /// Error while processing a choice, which starts at offset
+    ChoiceError {
+        choice: &'static str,
+        offset: usize,
+        error: Box<Error>,
+    },
 
     /// There are no enough data provided for the length specified
     NoDataForLength,
@@ -59,11 +94,205 @@ pub enum Error {
 
     /// Error in value due to a constraint in the type
     ConstraintError(String),
+
+    /// The input exceeds a limit of the decode options, or a length
+    /// which does not fit in a usize
+    LimitError(String),
+}
+
+impl Error {
+    /// Path of the fields and alternatives where the error happened,
+    /// starting with the name of the outermost type, as
+    /// "AsRep.enc_part.cipher". It is empty for errors which are not
+    /// wrapped by a sequence or a choice.
+    pub fn path(&self) -> String {
+        let mut path = match self {
+            Error::SequenceFieldError { sequence, .. }
+            | Error::SequenceError { sequence, .. } => sequence.to_string(),
+            Error::ChoiceAlternativeError { choice, .. }
+            | Error::ChoiceError { choice, .. } => choice.to_string(),
+            _ => return String::new(),
+        };
+
+        let mut error = self;
+        loop {
+            match error {
+                Error::SequenceFieldError {
+                    field,
+                    error: inner,
+                    ..
+                } => {
+                    path.push('.');
+                    path.push_str(field);
+                    error = inner;
+                }
+                Error::ChoiceAlternativeError {
+                    alternative,
+                    error: inner,
+                    ..
+                } => {
+                    path.push('.');
+                    path.push_str(alternative);
+                    error = inner;
+                }
+                Error::SequenceError { error: inner, .. }
+                | Error::ChoiceError { error: inner, .. } => {
+                    error = inner;
+                }
+                _ => return path,
+            }
+        }
+    }
+
+    /// Offset, from the start of the parsed octets, of the innermost
+    /// sequence, field or alternative where the error happened. It is
+    /// None for errors which are not wrapped by a sequence or a choice.
+    pub fn offset(&self) -> Option<usize> {
+        return match self {
+            Error::SequenceFieldError { offset, error, .. }
+            | Error::SequenceError { offset, error, .. }
+            | Error::ChoiceAlternativeError { offset, error, .. }
+            | Error::ChoiceError { offset, error, .. } => {
+                Some(error.offset().unwrap_or(*offset))
+            }
+            _ => None,
+        };
+    }
+
+    /// The innermost error, without the sequences and choices which
+    /// wrap it
+    pub fn root_cause(&self) -> &Error {
+        return match self {
+            Error::SequenceFieldError { error, .. }
+            | Error::SequenceError { error, .. }
+            | Error::ChoiceAlternativeError { error, .. }
+            | Error::ChoiceError { error, .. } => error.root_cause(),
+            _ => self,
+        };
+    }
+
+    /// Moves the offsets of the error, which are relative to the octets
+    /// of an inner value, by the offset of those octets in the outer
+    /// octets. Used by the types which parse inner values.
+    pub fn shift(self, offset: usize) -> Self {
+        return self.map_offsets(&|inner_offset| inner_offset + offset);
+    }
+
+    /// Applies the function to the offsets of the error and the errors
+    /// that it wraps
+    pub(crate) fn map_offsets(self, f: &dyn Fn(usize) -> usize) -> Self {
+        return match self {
+            Error::SequenceFieldError {
+                sequence,
+                field,
+                offset,
+                error,
+            } => Error::SequenceFieldError {
+                sequence,
+                field,
+                offset: f(offset),
+                error: Box::new(error.map_offsets(f)),
+            },
+            Error::SequenceError {
+                sequence,
+                offset,
+                error,
+            } => Error::SequenceError {
+                sequence,
+                offset: f(offset),
+                error: Box::new(error.map_offsets(f)),
+            },
+            Error::ChoiceAlternativeError {
+                choice,
+                alternative,
+                offset,
+                error,
+            } => Error::ChoiceAlternativeError {
+                choice,
+                alternative,
+                offset: f(offset),
+                error: Box::new(error.map_offsets(f)),
+            },
+            Error::ChoiceError {
+                choice,
+                offset,
+                error,
+            } => Error::ChoiceError {
+                choice,
+                offset: f(offset),
+                error: Box::new(error.map_offsets(f)),
+            },
+            error => error,
+        };
+    }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Error::EmptyTag(class) => write!(f, "no octets for {} tag", class),
+            Error::NotEnoughTagOctets(class) => {
+                write!(f, "not enough octets for {} tag number", class)
+            }
+            Error::UnmatchedTag { expected, found } => {
+                write!(f, "expected tag {}, found {}", expected, found)
+            }
+            Error::UnmatchedAlternative(tag) => {
+                write!(f, "tag {} does not match any alternative", tag)
+            }
+            Error::UnknownDiscriminant(open_type) => {
+                write!(f, "discriminant does not select any {}", open_type)
+            }
+            Error::LengthEmpty => write!(f, "no octets for length"),
+            Error::NotEnoughLengthOctects => {
+                write!(f, "not enough octets for length")
+            }
+            Error::NoValue => write!(f, "no value to build"),
+            Error::NoComponent => write!(f, "component not found"),
+            Error::SequenceFieldError { .. }
+            | Error::SequenceError { .. }
+            | Error::ChoiceAlternativeError { .. }
+            | Error::ChoiceError { .. } => write!(
+                f,
+                "{} at offset {}: {}",
+                self.path(),
+                self.offset().unwrap_or(0),
+                self.root_cause()
+            ),
+            Error::NoDataForLength => {
+                write!(f, "not enough octets for the length")
+            }
+            Error::NoAllDataConsumed => {
+                write!(f, "octets left after the value")
+            }
+            Error::IncorrectValue(message) => {
+                write!(f, "incorrect value: {}", message)
+            }
+            Error::Utf8Error => write!(f, "invalid UTF-8 string"),
+            Error::AsciiError => write!(f, "invalid ASCII string"),
+            Error::ParseIntError => write!(f, "invalid integer"),
+            Error::ImplementationError(message) => {
+                write!(f, "not supported: {}", message)
+            }
+            Error::ConstraintError(message) => {
+                write!(f, "constraint violated: {}", message)
+            }
+            Error::LimitError(message) => {
+                write!(f, "limit exceeded: {}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        return match self {
+            Error::SequenceFieldError { error, .. }
+            | Error::SequenceError { error, .. }
+            | Error::ChoiceAlternativeError { error, .. }
+            | Error::ChoiceError { error, .. } => Some(error.as_ref()),
+            _ => None,
+        };
     }
 }
 
@@ -95,15 +324,84 @@ impl From<ascii::ToAsciiCharError> for Error {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::tag::TagClass;
+    use crate::tag::{TagClass, TagType};
+    use std::error::Error as StdError;
 
     #[test]
     fn raise_empty_tag_error() {
-        let error_kind = super::Error::from(Error::EmptyTag(TagClass::Context));
+        let error_kind = Error::EmptyTag(TagClass::Context);
 
         match error_kind {
             Error::EmptyTag(tag_class) => assert_eq!(TagClass::Context, tag_class),
             _ => unreachable!(),
         }
     }
+
+    fn unmatched_tag() -> Error {
+        return Error::UnmatchedTag {
+            expected: Tag::new(2, TagType::Constructed, TagClass::Context),
+            found: Tag::new(3, TagType::Constructed, TagClass::Context),
+        };
+    }
+
+    fn nested_error() -> Error {
+        return Error::SequenceFieldError {
+            sequence: "AsRep",
+            field: "enc_part",
+            offset: 4,
+            error: Box::new(Error::SequenceFieldError {
+                sequence: "EncryptedData",
+                field: "cipher",
+                offset: 2,
+                error: Box::new(unmatched_tag()),
+            }),
+        };
+    }
+
+    #[test]
+    fn test_error_path_and_offset() {
+        let error = nested_error();
+
+        assert_eq!("AsRep.enc_part.cipher", error.path());
+        assert_eq!(Some(2), error.offset());
+        assert_eq!(Some(12), error.clone().shift(10).offset());
+        assert_eq!(&unmatched_tag(), error.root_cause());
+    }
+
+    #[test]
+    fn test_error_without_path() {
+        assert_eq!("", Error::NoDataForLength.path());
+        assert_eq!(None, Error::NoDataForLength.offset());
+        assert_eq!(Error::NoValue, Error::NoValue.shift(3));
+    }
+
+    #[test]
+    fn test_display_error() {
+        assert_eq!(
+            "AsRep.enc_part.cipher at offset 2: expected tag \
+             [context 2] constructed, found [context 3] constructed",
+            nested_error().to_string()
+        );
+        assert_eq!(
+            "incorrect value: Invalid flag",
+            Error::IncorrectValue("Invalid flag".to_string()).to_string()
+        );
+        assert_eq!(
+            "discriminant does not select any AlgorithmParameters",
+            Error::UnknownDiscriminant("AlgorithmParameters").to_string()
+        );
+    }
+
+    #[test]
+    fn test_error_source() {
+        let error = nested_error();
+        let source = error.source().unwrap();
+
+        assert_eq!(
+            "EncryptedData.cipher at offset 2: expected tag \
+             [context 2] constructed, found [context 3] constructed",
+            source.to_string()
+        );
+        assert!(source.source().unwrap().source().is_none());
+    }
 }
@@ -1,4 +1,5 @@
 use crate::error::{Error, Result};
+use crate::limits::max_length_octets;
 use nom::number::complete::be_u8;
 
 pub fn build_length(value_size: usize) -> Vec<u8> {
@@ -37,16 +38,32 @@ pub fn parse_length(raw: &[u8]) -> Result<(&[u8], usize)> {
     }
 
     let length_of_length = length;
-    let mut length = 0;
+    if let Some(max_length_octets) = max_length_octets() {
+        if length_of_length > max_length_octets {
+            return Err(Error::LimitError(format!(
+                "length of {} octets over {}",
+                length_of_length, max_length_octets
+            )))?;
+        }
+    }
+
+    let mut length: usize = 0;
 
     for _ in 1..(length_of_length + 1) {
-        length <<= 8;
         let (raw_tmp, len_byte) = be_u8(raw).map_err(
             |_: nom::Err<(&[u8], nom::error::ErrorKind)>| {
                 Error::NotEnoughLengthOctects
             },
         )?;
-        length += len_byte as usize;
+        length = length
+            .checked_mul(256)
+            .and_then(|length| length.checked_add(len_byte as usize))
+            .ok_or_else(|| {
+                Error::LimitError(format!(
+                    "length of {} octets too large",
+                    length_of_length
+                ))
+            })?;
         raw = raw_tmp;
     }
     return Ok((raw, length));
@@ -119,4 +136,26 @@ mod tests {
             parse_length(&[0x84, 0x05, 0x71, 0x6f, 0xa9]).unwrap()
         );
     }
+
+    #[test]
+    fn test_parse_length_with_leading_zeros() {
+        let mut raw = vec![0x8a];
+        raw.extend_from_slice(&[0x0; 9]);
+        raw.push(0x1);
+
+        assert_eq!((&[][..], 1), parse_length(&raw).unwrap());
+    }
+
+    #[test]
+    fn test_parse_too_large_length() {
+        let mut raw = vec![0x89, 0x1];
+        raw.extend_from_slice(&[0x0; 8]);
+
+        assert_eq!(
+            Err(Error::LimitError(
+                "length of 9 octets too large".to_string()
+            )),
+            parse_length(&raw)
+        );
+    }
 }
@@ -9,6 +9,10 @@
 //! assert_eq!(true, bool::parse(&[0x1, 0x1, 0xff]).unwrap().1);
 //! assert_eq!(false, bool::parse(&[0x1, 0x1, 0x0]).unwrap().1);
 //! 
+//! // decode requires all the octets to be consumed
+//! assert_eq!(true, bool::decode(&[0x1, 0x1, 0xff]).unwrap());
+//! assert!(bool::decode(&[0x1, 0x1, 0xff, 0x0]).is_err());
+//! 
 //! assert_eq!(true.build(), vec![0x1, 0x1, 0xff]);
 //! assert_eq!(false.build(), vec![0x1, 0x1, 0x0]);
 //! ```
@@ -52,7 +56,7 @@
 //! use himmelblau_red_asn1::*;
 //! use himmelblau_red_asn1_derive::Sequence;
 //! 
-//! #[derive(Sequence, Default)]
+//! #[derive(Sequence)]
 //! #[seq(application_tag = 1)]
 //! struct Person {
 //!     #[seq_field(context_tag = 0)]
@@ -91,23 +95,59 @@
 //! 
 //! ```
 //!
+//! ## Errors
+//! The errors in the fields of sequences and in the alternatives of choices
+//! are wrapped with the names of the types and fields, and the offset of
+//! the element from the start of the parsed octets. `Error::path` returns
+//! the path of the field which failed, as "AsRep.enc_part.cipher",
+//! `Error::offset` its offset and `Error::root_cause` the inner error.
+//!
+//! ## Untrusted input
+//! `decode_with` decodes with the limits of `DecodeOptions`: the maximum
+//! nesting depth, length of the input, elements of a SEQUENCE OF and
+//! octets of a length. An exceeded limit returns `Error::LimitError`.
+//! ```rust
+//! use himmelblau_red_asn1::{Asn1Object, DecodeOptions, Error, SequenceOf};
+//!
+//! let options = DecodeOptions {
+//!     max_elements: 2,
+//!     ..DecodeOptions::default()
+//! };
+//! let raw = [0x30, 0x9, 0x2, 0x1, 0x1, 0x2, 0x1, 0x2, 0x2, 0x1, 0x3];
+//!
+//! assert_eq!(3, SequenceOf::<i32>::decode(&raw).unwrap().len());
+//! assert_eq!(
+//!     Err(Error::LimitError("more than 2 elements".to_string())),
+//!     SequenceOf::<i32>::decode_with(&raw, &options)
+//! );
+//! ```
+//!
 //! ## Implemented types
 //! 
-//! | ASN1            | red_asn1 type   | Rust type                                |
-//! |-----------------|-----------------|------------------------------------------|
-//! | BOOLEAN         | Boolean         | bool                                     |
-//! | INTEGER         | Integer         | i128, i64, i32, i16, u32                 |
-//! | BIT STRING      | BitSring        |                                          |
-//! | OCTET STRING    | OctetString     | Vec\<u8\>                                |
-//! | GeneralString   | GeneralString   | String                                   |
-//! | IA5String       | IA5String       | ascii::AsciiString                       |
-//! | GeneralizedTime | GeneralizedTime |                                          |
-//! | SEQUENCE OF     | SequenceOf      | Vec<T: Asn1Object>                       |
-//! | SEQUENCE        |                 | struct with #[derive(Sequence, Default)] |
-//! | OPTIONAL        | Optional        | Option                                   |
-//! |                 |                 |                                          |
-
-
+//! | ASN1                        | red_asn1 type            | Rust type                                |
+//! |-----------------------------|--------------------------|------------------------------------------|
+//! | BOOLEAN                     | Boolean                  | bool                                     |
+//! | INTEGER                     | Integer                  | i128, i64, i32, i16, u32                 |
+//! | BIT STRING                  | BitSring                 |                                          |
+//! | OCTET STRING                | OctetString              | Vec\<u8\>                                |
+//! | OCTET STRING (CONTAINING T) | Containing\<T\>          |                                          |
+//! | BIT STRING (CONTAINING T)   | BitStringContaining\<T\> |                                          |
+//! | GeneralString               | GeneralString            | String                                   |
+//! | IA5String                   | IA5String                | ascii::AsciiString                       |
+//! | GeneralizedTime             | GeneralizedTime          |                                          |
+//! | DATE                        | Date                     | chrono::NaiveDate                        |
+//! | TIME-OF-DAY                 | TimeOfDay                | chrono::NaiveTime                        |
+//! | DATE-TIME                   | DateTime                 | chrono::NaiveDateTime                    |
+//! | DURATION                    | Duration                 | chrono::TimeDelta                        |
+//! | SEQUENCE OF                 | SequenceOf               | Vec<T: Asn1Object>                       |
+//! | SEQUENCE                    |                          | struct with #[derive(Sequence)]          |
+//! | CHOICE                      |                          | enum with #[derive(Choice)]              |
+//! | Name ::= T                  |                          | tuple struct with #[derive(Newtype)]     |
+//! | OPTIONAL                    | Optional                 | Option                                   |
+//! | ANY                         | Any                      |                                          |
+//! | [CLASS N] EXPLICIT T        | Explicit\<CLASS, N, T\>  |                                          |
+//! | [CLASS N] IMPLICIT T        | Implicit\<CLASS, N, T\>  |                                          |
+//! |                             |                          |                                          |
 
 mod error;
 pub use error::*;
@@ -125,3 +165,19 @@ pub use types::*;
 mod length;
 pub use length::{build_length, parse_length};
 
+mod limits;
+pub use limits::DecodeOptions;
+// used by the derived code, which parses the sequences without the
+// default parse of Asn1Object
+#[doc(hidden)]
+pub use limits::{enter_nesting, NestingGuard};
+
+mod constraints;
+pub use constraints::{check_alphabet, check_range, check_size, Asn1Size};
+
+mod tagging;
+pub use tagging::{
+    build_automatic, build_explicit, build_implicit, is_automatic_tag,
+    is_implicit_tag, parse_automatic, parse_explicit, parse_implicit,
+};
+
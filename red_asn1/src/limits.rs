@@ -0,0 +1,220 @@
+use crate::error::{Error, Result};
+use std::cell::{Cell, RefCell};
+
+/// Limits of the resources used to decode untrusted input, such as the
+/// messages received from the network. They are applied by
+/// `Asn1Object::decode_with` to all the values parsed by the decode.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodeOptions {
+    /// Maximum nesting depth of the parsed values
+    pub max_depth: usize,
+
+    /// Maximum length of the decoded octets
+    pub max_input_length: usize,
+
+    /// Maximum number of elements of a SEQUENCE OF
+    pub max_elements: usize,
+
+    /// Maximum number of octets of a length in long form
+    pub max_length_octets: usize,
+}
+
+impl Default for DecodeOptions {
+    fn default() -> Self {
+        return Self {
+            max_depth: 64,
+            max_input_length: 16 * 1024 * 1024,
+            max_elements: 65536,
+            max_length_octets: 4,
+        };
+    }
+}
+
+thread_local! {
+    static OPTIONS: RefCell<Option<DecodeOptions>> = const { RefCell::new(None) };
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Applies the options to the decodes of the current thread until it
+/// is dropped, restoring then the previous ones
+pub(crate) struct OptionsScope {
+    previous: Option<DecodeOptions>,
+    previous_depth: usize,
+}
+
+impl OptionsScope {
+    pub(crate) fn new(options: &DecodeOptions) -> Self {
+        return Self {
+            previous: OPTIONS.with(|o| o.replace(Some(options.clone()))),
+            previous_depth: DEPTH.with(|depth| depth.replace(0)),
+        };
+    }
+}
+
+impl Drop for OptionsScope {
+    fn drop(&mut self) {
+        OPTIONS.with(|o| *o.borrow_mut() = self.previous.take());
+        DEPTH.with(|depth| depth.set(self.previous_depth));
+    }
+}
+
+/// Applies the function to the options of the current decode, if any
+fn with_options<T>(f: impl FnOnce(&DecodeOptions) -> T) -> Option<T> {
+    return OPTIONS.with(|o| o.borrow().as_ref().map(f));
+}
+
+/// Level of nesting of a parsed value, which is left when dropped
+pub struct NestingGuard {
+    counted: bool,
+}
+
+impl Drop for NestingGuard {
+    fn drop(&mut self) {
+        if self.counted {
+            DEPTH.with(|depth| depth.set(depth.get() - 1));
+        }
+    }
+}
+
+/// Enters a level of nesting, failing if the maximum depth of the
+/// current decode options is exceeded. Used by the types whose parse
+/// does not use the default one, such as the derived sequences.
+pub fn enter_nesting() -> Result<NestingGuard> {
+    let max_depth = match with_options(|options| options.max_depth) {
+        Some(max_depth) => max_depth,
+        None => return Ok(NestingGuard { counted: false }),
+    };
+
+    let depth = DEPTH.with(|depth| depth.get()) + 1;
+    if depth > max_depth {
+        return Err(Error::LimitError(format!(
+            "nesting depth over {}",
+            max_depth
+        )))?;
+    }
+
+    DEPTH.with(|d| d.set(depth));
+    return Ok(NestingGuard { counted: true });
+}
+
+/// Checks the length of the decoded octets
+pub(crate) fn check_input_length(
+    options: &DecodeOptions,
+    length: usize,
+) -> Result<()> {
+    if length > options.max_input_length {
+        return Err(Error::LimitError(format!(
+            "input length {} over {}",
+            length, options.max_input_length
+        )))?;
+    }
+    return Ok(());
+}
+
+/// Checks the number of elements of a SEQUENCE OF, given the number
+/// of the elements already parsed plus the new one
+pub(crate) fn check_elements(count: usize) -> Result<()> {
+    if let Some(max_elements) = with_options(|options| options.max_elements) {
+        if count > max_elements {
+            return Err(Error::LimitError(format!(
+                "more than {} elements",
+                max_elements
+            )))?;
+        }
+    }
+    return Ok(());
+}
+
+/// Maximum number of octets of a length in long form. Without decode
+/// options, lengths are only limited to those that fit in a usize.
+pub(crate) fn max_length_octets() -> Option<usize> {
+    return with_options(|options| options.max_length_octets);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::Asn1Object;
+    use crate::types::{Integer, OctetString, SequenceOf};
+
+    fn options() -> DecodeOptions {
+        return DecodeOptions {
+            max_depth: 3,
+            max_input_length: 32,
+            max_elements: 2,
+            max_length_octets: 1,
+        };
+    }
+
+    #[test]
+    fn test_decode_with_options() {
+        assert_eq!(
+            vec![vec![1], vec![2]],
+            SequenceOf::<SequenceOf<Integer>>::decode_with(
+                &[
+                    0x30, 0xa, 0x30, 0x3, 0x2, 0x1, 0x1, 0x30, 0x3, 0x2, 0x1,
+                    0x2
+                ],
+                &options()
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_decode_over_max_depth() {
+        assert_eq!(
+            Err(Error::LimitError("nesting depth over 3".to_string())),
+            SequenceOf::<SequenceOf<SequenceOf<Integer>>>::decode_with(
+                &[0x30, 0x7, 0x30, 0x5, 0x30, 0x3, 0x2, 0x1, 0x1],
+                &options()
+            )
+        );
+    }
+
+    #[test]
+    fn test_decode_over_max_input_length() {
+        let mut raw = vec![0x4, 0x1f];
+        raw.extend_from_slice(&[0; 31]);
+
+        assert_eq!(
+            Err(Error::LimitError("input length 33 over 32".to_string())),
+            OctetString::decode_with(&raw, &options())
+        );
+    }
+
+    #[test]
+    fn test_decode_over_max_elements() {
+        assert_eq!(
+            Err(Error::LimitError("more than 2 elements".to_string())),
+            SequenceOf::<Integer>::decode_with(
+                &[0x30, 0x9, 0x2, 0x1, 0x1, 0x2, 0x1, 0x2, 0x2, 0x1, 0x3],
+                &options()
+            )
+        );
+    }
+
+    #[test]
+    fn test_decode_over_max_length_octets() {
+        assert_eq!(
+            Err(Error::LimitError("length of 2 octets over 1".to_string())),
+            Integer::decode_with(&[0x2, 0x82, 0x0, 0x1, 0x1], &options())
+        );
+    }
+
+    #[test]
+    fn test_options_are_restored_after_decode() {
+        SequenceOf::<Integer>::decode_with(&[0x30, 0x0], &options()).unwrap();
+
+        assert_eq!(None, max_length_octets());
+        assert_eq!(0, DEPTH.with(|depth| depth.get()));
+        assert_eq!(
+            3,
+            SequenceOf::<Integer>::decode(&[
+                0x30, 0x9, 0x2, 0x1, 0x1, 0x2, 0x1, 0x2, 0x2, 0x1, 0x3
+            ])
+            .unwrap()
+            .len()
+        );
+    }
+}
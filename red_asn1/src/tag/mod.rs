@@ -1,4 +1,5 @@
 
+#[allow(clippy::module_inception)]
 mod tag;
 pub use tag::*;
 
@@ -7,3 +8,6 @@ pub use tagclass::*;
 
 mod tagtype;
 pub use tagtype::*;
+
+mod tags;
+pub use tags::*;
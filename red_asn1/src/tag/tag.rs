@@ -1,6 +1,7 @@
 use super::{TagClass, TagType};
 use crate::error as asn1err;
 use nom::number::complete::be_u8;
+use std::fmt;
 
 /// Class to represent DER-ASN1 tags of the different types.
 ///
@@ -11,14 +12,14 @@ use nom::number::complete::be_u8;
 ///
 #[derive(Debug, PartialEq, Copy, Clone, Default)]
 pub struct Tag {
-    pub number: u8,
+    pub number: u32,
     pub r#type: TagType,
     pub class: TagClass,
 }
 
 impl Tag {
     /// Creates a new tag from a given number, type and class
-    pub fn new(number: u8, r#type: TagType, class: TagClass) -> Tag {
+    pub const fn new(number: u32, r#type: TagType, class: TagClass) -> Tag {
         return Tag {
             number,
             r#type,
@@ -27,13 +28,21 @@ impl Tag {
     }
 
     /// Shorcut of: `Tag::new(tag_number, TagType::Primitive, TagClass::Universal)`
-    pub fn new_primitive_universal(number: u8) -> Tag {
-        return Tag::new(number, TagType::Primitive, TagClass::Universal);
+    pub const fn new_primitive_universal(number: u8) -> Tag {
+        return Tag::new(
+            number as u32,
+            TagType::Primitive,
+            TagClass::Universal,
+        );
     }
 
     /// Shorcut of: `Tag::new(tag_number, TagType::Constructed, TagClass::Universal)`
-    pub fn new_constructed_universal(number: u8) -> Tag {
-        return Tag::new(number, TagType::Constructed, TagClass::Universal);
+    pub const fn new_constructed_universal(number: u8) -> Tag {
+        return Tag::new(
+            number as u32,
+            TagType::Constructed,
+            TagClass::Universal,
+        );
     }
 
     /// Produces an DER version of the tag in bytes
@@ -47,20 +56,28 @@ impl Tag {
         encoded_tag <<= 5;
 
         if self.number <= 30 {
-            encoded_tag += self.number;
+            encoded_tag += self.number as u8;
             return vec![encoded_tag];
         }
         encoded_tag |= 0b11111;
 
-        let mut encoded_tags = vec![encoded_tag];
+        // high tag number form: base 128, most significant group first,
+        // with the highest bit set in every octet except the last one
+        let mut number_octets = Vec::new();
+        let mut shifted_number = self.number;
 
-        let mut next_octet = self.number;
+        number_octets.push((shifted_number & 0b01111111) as u8);
+        shifted_number >>= 7;
 
-        while next_octet > 127 {
-            encoded_tags.push(next_octet | 0b10000000);
-            next_octet >>= 7;
+        while shifted_number > 0 {
+            number_octets
+                .push((shifted_number & 0b01111111) as u8 | 0b10000000);
+            shifted_number >>= 7;
         }
-        encoded_tags.push(next_octet);
+        number_octets.reverse();
+
+        let mut encoded_tags = vec![encoded_tag];
+        encoded_tags.append(&mut number_octets);
 
         return encoded_tags;
     }
@@ -75,7 +92,7 @@ impl Tag {
 
         let tag_class = (octet & 0xc0) >> 6;
         let tag_type = (octet & 0x20) >> 5;
-        let mut tag_number = octet & 0x1f;
+        let mut tag_number = (octet & 0x1f) as u32;
 
         if tag_number == 0x1f {
             let (raw_tmp, tag_number_long_form) =
@@ -93,9 +110,8 @@ impl Tag {
         return Ok((raw, tag));
     }
 
-    fn parse_high_tag_number(raw: &[u8]) -> asn1err::Result<(&[u8], u8)> {
-        let mut consumed_octets = 1;
-        let mut tag_number: u8 = 0;
+    fn parse_high_tag_number(raw: &[u8]) -> asn1err::Result<(&[u8], u32)> {
+        let mut tag_number: u32 = 0;
         let mut raw = raw;
         loop {
             let (raw_tmp, next_octet) = be_u8(raw).map_err(
@@ -104,18 +120,32 @@ impl Tag {
                 },
             )?;
             raw = raw_tmp;
-            tag_number +=
-                (next_octet & 0b01111111) << (7 * (consumed_octets - 1));
+
+            if tag_number > (u32::MAX >> 7) {
+                return Err(asn1err::Error::ImplementationError(
+                    "Tag number too large".to_string(),
+                ));
+            }
+
+            tag_number <<= 7;
+            tag_number |= (next_octet & 0b01111111) as u32;
             if next_octet & 0b10000000 == 0 {
                 break;
             }
-            consumed_octets += 1;
         }
 
         return Ok((raw, tag_number));
     }
 }
 
+/// Displays the tag as the class and number, followed by the type, such
+/// as "[context 0] constructed"
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{} {}] {}", self.class, self.number, self.r#type)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -168,16 +198,16 @@ mod tests {
             Tag::new(127, TagType::Primitive, TagClass::Universal).build()
         );
         assert_eq!(
-            vec![0x1F, 0x80, 0x01],
+            vec![0x1F, 0x81, 0x00],
             Tag::new(128, TagType::Primitive, TagClass::Universal).build()
         );
         assert_eq!(
-            vec![0x1F, 0xFF, 0x01],
+            vec![0x1F, 0x81, 0x7F],
             Tag::new(255, TagType::Primitive, TagClass::Universal).build()
         );
 
         assert_eq!(
-            vec![0xdf, 0xc6, 0x01],
+            vec![0xdf, 0x81, 0x46],
             Tag::new(198, TagType::Primitive, TagClass::Private).build()
         );
         assert_eq!(
@@ -251,16 +281,16 @@ mod tests {
         );
         assert_eq!(
             Tag::new(128, TagType::Primitive, TagClass::Universal),
-            _parse_tag(vec![0x1F, 0x80, 0x01])
+            _parse_tag(vec![0x1F, 0x81, 0x00])
         );
         assert_eq!(
             Tag::new(255, TagType::Primitive, TagClass::Universal),
-            _parse_tag(vec![0x1F, 0xFF, 0x01])
+            _parse_tag(vec![0x1F, 0x81, 0x7F])
         );
 
         assert_eq!(
             Tag::new(198, TagType::Primitive, TagClass::Private),
-            _parse_tag(vec![0xdf, 0xc6, 0x01])
+            _parse_tag(vec![0xdf, 0x81, 0x46])
         );
         assert_eq!(
             Tag::new(106, TagType::Constructed, TagClass::Private),
@@ -299,10 +329,39 @@ mod tests {
         let y: &[u8] = &[0x1, 0x2];
         assert_eq!(
             (Tag::new(198, TagType::Primitive, TagClass::Private), y),
-            _parse_tag_with_consumed_octets(&[0xdf, 0xc6, 0x01, 0x01, 0x02])
+            _parse_tag_with_consumed_octets(&[0xdf, 0x81, 0x46, 0x01, 0x02])
         );
     }
 
+    #[test]
+    fn test_build_and_parse_large_tag_numbers() {
+        let tags = vec![
+            (
+                Tag::new(0x4000, TagType::Primitive, TagClass::Context),
+                vec![0x9F, 0x81, 0x80, 0x00],
+            ),
+            (
+                Tag::new(0x3FFF, TagType::Constructed, TagClass::Application),
+                vec![0x7F, 0xFF, 0x7F],
+            ),
+            (
+                Tag::new(u32::MAX, TagType::Primitive, TagClass::Private),
+                vec![0xDF, 0x8F, 0xFF, 0xFF, 0xFF, 0x7F],
+            ),
+        ];
+
+        for (tag, raw) in tags {
+            assert_eq!(raw, tag.build());
+            assert_eq!(tag, _parse_tag(raw));
+        }
+    }
+
+    #[should_panic(expected = "ImplementationError(\"Tag number too large\")")]
+    #[test]
+    fn test_parse_tag_with_too_large_number() {
+        _parse_tag(vec![0x1F, 0x90, 0x80, 0x80, 0x80, 0x00]);
+    }
+
     #[should_panic(expected = "EmptyTag")]
     #[test]
     fn test_parse_empty_tag() {
@@ -315,6 +374,18 @@ mod tests {
         _parse_tag(vec![0x1F, 0x80, 0x81]);
     }
 
+    #[test]
+    fn test_display_tag() {
+        assert_eq!(
+            "[context 0] constructed",
+            Tag::new(0, TagType::Constructed, TagClass::Context).to_string()
+        );
+        assert_eq!(
+            "[universal 2] primitive",
+            Tag::new_primitive_universal(2).to_string()
+        );
+    }
+
     fn _parse_tag(raw: Vec<u8>) -> Tag {
         let (_, tag) = Tag::parse(&raw).unwrap();
         return tag;
@@ -0,0 +1,103 @@
+use super::{Tag, TagClass, TagType};
+
+/// Checks if two lists of possible tags have no tag in common, that is,
+/// the values of types with these tags can be distinguished. As in X.680,
+/// the tags are compared by class and number. An empty list, whose tags
+/// are unknown, is distinct from any other.
+pub const fn tags_are_distinct(tags: &[Tag], other_tags: &[Tag]) -> bool {
+    let mut i = 0;
+    while i < tags.len() {
+        let mut j = 0;
+        while j < other_tags.len() {
+            if tags[i].number == other_tags[j].number
+                && tags[i].class as u8 == other_tags[j].class as u8
+            {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    return true;
+}
+
+/// Number of tags of the union of several lists of possible tags, used
+/// to compute the tags of a choice. In case of any list is empty (the
+/// tags are unknown), the union is also unknown, so 0 is returned.
+pub const fn tags_union_len(tags_lists: &[&[Tag]]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < tags_lists.len() {
+        if tags_lists[i].is_empty() {
+            return 0;
+        }
+        len += tags_lists[i].len();
+        i += 1;
+    }
+    return len;
+}
+
+/// Union of several lists of possible tags, with the length given by
+/// `tags_union_len`
+pub const fn tags_union<const N: usize>(tags_lists: &[&[Tag]]) -> [Tag; N] {
+    let mut union = [Tag::new(0, TagType::Primitive, TagClass::Universal); N];
+    if N == 0 {
+        return union;
+    }
+
+    let mut k = 0;
+    let mut i = 0;
+    while i < tags_lists.len() {
+        let mut j = 0;
+        while j < tags_lists[i].len() {
+            union[k] = tags_lists[i][j];
+            k += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    return union;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOOLEAN: Tag = Tag::new_primitive_universal(0x1);
+    const INTEGER: Tag = Tag::new_primitive_universal(0x2);
+    const CONTEXT_0: Tag = Tag::new(0, TagType::Constructed, TagClass::Context);
+
+    #[test]
+    fn test_tags_are_distinct() {
+        assert!(tags_are_distinct(&[BOOLEAN], &[INTEGER]));
+        assert!(tags_are_distinct(&[INTEGER], &[CONTEXT_0]));
+        assert!(tags_are_distinct(&[], &[INTEGER]));
+        assert!(!tags_are_distinct(&[BOOLEAN, INTEGER], &[INTEGER]));
+    }
+
+    #[test]
+    fn test_tags_with_different_type_are_not_distinct() {
+        assert!(!tags_are_distinct(
+            &[CONTEXT_0],
+            &[Tag::new(0, TagType::Primitive, TagClass::Context)]
+        ));
+    }
+
+    #[test]
+    fn test_tags_union() {
+        const LISTS: &[&[Tag]] = &[&[BOOLEAN], &[INTEGER, CONTEXT_0]];
+        const LEN: usize = tags_union_len(LISTS);
+
+        assert_eq!(3, LEN);
+        assert_eq!([BOOLEAN, INTEGER, CONTEXT_0], tags_union::<LEN>(LISTS));
+    }
+
+    #[test]
+    fn test_tags_union_with_unknown_tags() {
+        const LISTS: &[&[Tag]] = &[&[BOOLEAN], &[]];
+        const LEN: usize = tags_union_len(LISTS);
+
+        assert_eq!(0, LEN);
+        assert_eq!(0, tags_union::<LEN>(LISTS).len());
+    }
+}
@@ -1,4 +1,5 @@
 use std::convert::From;
+use std::fmt;
 
 /// Enum with the different tag types
 /// * Primitive: Object which are not composed by other objects. For example, basic types like Integer, Boolean, ...
@@ -20,6 +21,14 @@ impl From<u8> for TagType {
     }
 }
 
+impl fmt::Display for TagType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TagType::Primitive => write!(f, "primitive"),
+            TagType::Constructed => write!(f, "constructed"),
+        }
+    }
+}
 
 impl Default for TagType {
     fn default() -> Self {
@@ -0,0 +1,449 @@
+use crate::error as asn1err;
+use crate::length::{build_length, parse_length};
+use crate::tag::{Tag, TagClass, TagType};
+use crate::traits::Asn1Object;
+
+/// Builds a value with IMPLICIT tagging, by replacing the tag of the value
+/// with a tag of the given number and class. The form (primitive or
+/// constructed) of the tag is kept from the value. An empty build (as
+/// an absent optional value) is kept empty. The type cannot be a CHOICE.
+pub fn build_implicit<T: Asn1Object>(
+    value: &T,
+    number: u32,
+    class: TagClass,
+) -> Vec<u8> {
+    // a choice cannot be tagged implicitly, since its tag selects the
+    // alternative, as in X.680
+    const { assert!(!T::CHOICE, "a CHOICE cannot be IMPLICIT") };
+    return build_retagged(value, number, class);
+}
+
+fn build_retagged<T: Asn1Object>(
+    value: &T,
+    number: u32,
+    class: TagClass,
+) -> Vec<u8> {
+    let built = value.build();
+    if built.is_empty() {
+        return built;
+    }
+
+    // the value has been just built, so its tag is valid
+    let (raw, value_tag) = Tag::parse(&built).unwrap();
+    let mut implicit_built = Tag::new(number, value_tag.r#type, class).build();
+    implicit_built.extend_from_slice(raw);
+
+    return implicit_built;
+}
+
+/// Parses a value with IMPLICIT tagging, with a tag of the given number
+/// and class that replaces the own tag of the value. The type cannot be
+/// a CHOICE.
+pub fn parse_implicit<T: Asn1Object>(
+    raw: &[u8],
+    number: u32,
+    class: TagClass,
+) -> asn1err::Result<(&[u8], T)> {
+    const { assert!(!T::CHOICE, "a CHOICE cannot be IMPLICIT") };
+    return parse_retagged(raw, number, class);
+}
+
+fn parse_retagged<T: Asn1Object>(
+    raw: &[u8],
+    number: u32,
+    class: TagClass,
+) -> asn1err::Result<(&[u8], T)> {
+    let (raw_length, parsed_tag) =
+        Tag::parse(raw).map_err(|error| match error {
+            asn1err::Error::NotEnoughTagOctets(_) => {
+                asn1err::Error::NotEnoughTagOctets(class)
+            }
+            asn1err::Error::EmptyTag(_) => asn1err::Error::EmptyTag(class),
+            _ => error,
+        })?;
+
+    if !is_implicit_tag::<T>(&parsed_tag, number, class) {
+        return Err(asn1err::Error::UnmatchedTag {
+            expected: Tag::new(number, T::tag().r#type, class),
+            found: parsed_tag,
+        })?;
+    }
+
+    let (raw_value, length) = parse_length(raw_length)?;
+    if length > raw_value.len() {
+        return Err(asn1err::Error::NoDataForLength)?;
+    }
+
+    let header_length = raw.len() - raw_value.len();
+    let (received, _) = raw.split_at(header_length + length);
+    let (raw_value, raw) = raw_value.split_at(length);
+
+    let mut value_built = T::tag().build();
+    value_built.append(&mut build_length(length));
+    let built_header_length = value_built.len();
+    value_built.extend_from_slice(raw_value);
+
+    // the offsets of the value in the built octets are moved to the
+    // value in the parsed octets, whose header can be longer or shorter
+    let value = T::decode_implicit(received, &value_built).map_err(|error| {
+        error.map_offsets(&|offset| {
+            if offset < built_header_length {
+                return offset;
+            }
+            return offset - built_header_length + header_length;
+        })
+    })?;
+    return Ok((raw, value));
+}
+
+/// Builds a value with EXPLICIT tagging, by wrapping the value with a
+/// constructed tag of the given number and class. An empty build (as
+/// an absent optional value) is kept empty.
+pub fn build_explicit<T: Asn1Object>(
+    value: &T,
+    number: u32,
+    class: TagClass,
+) -> Vec<u8> {
+    let mut built_value = value.build();
+    if built_value.is_empty() {
+        return built_value;
+    }
+
+    let mut built = Tag::new(number, TagType::Constructed, class).build();
+    built.append(&mut build_length(built_value.len()));
+    built.append(&mut built_value);
+
+    return built;
+}
+
+/// Parses a value with EXPLICIT tagging, wrapped by a constructed tag of
+/// the given number and class. The value must take all the wrapped octets.
+pub fn parse_explicit<T: Asn1Object>(
+    raw: &[u8],
+    number: u32,
+    class: TagClass,
+) -> asn1err::Result<(&[u8], T)> {
+    let (raw_length, parsed_tag) =
+        Tag::parse(raw).map_err(|error| match error {
+            asn1err::Error::NotEnoughTagOctets(_) => {
+                asn1err::Error::NotEnoughTagOctets(class)
+            }
+            asn1err::Error::EmptyTag(_) => asn1err::Error::EmptyTag(class),
+            _ => error,
+        })?;
+
+    let expected_tag = Tag::new(number, TagType::Constructed, class);
+    if parsed_tag != expected_tag {
+        return Err(asn1err::Error::UnmatchedTag {
+            expected: expected_tag,
+            found: parsed_tag,
+        })?;
+    }
+
+    let (raw_value, length) = parse_length(raw_length)?;
+    if length > raw_value.len() {
+        return Err(asn1err::Error::NoDataForLength)?;
+    }
+
+    let header_length = raw.len() - raw_value.len();
+    let (raw_value, raw) = raw_value.split_at(length);
+    let (raw_value, value) = T::parse(raw_value)
+        .map_err(|error| error.shift(header_length))?;
+    if !raw_value.is_empty() {
+        return Err(asn1err::Error::NoAllDataConsumed)?;
+    }
+
+    return Ok((raw, value));
+}
+
+/// Checks if a tag is the IMPLICIT tag of the type with the given
+/// number and class, which keeps the form (primitive or constructed)
+/// of the type tag
+pub fn is_implicit_tag<T: Asn1Object>(
+    tag: &Tag,
+    number: u32,
+    class: TagClass,
+) -> bool {
+    return *tag == Tag::new(number, T::tag().r#type, class);
+}
+
+/// Builds a value with AUTOMATIC tagging, which is EXPLICIT for choices
+/// and IMPLICIT for the rest of types, as in X.680
+pub fn build_automatic<T: Asn1Object>(
+    value: &T,
+    number: u32,
+    class: TagClass,
+) -> Vec<u8> {
+    if T::CHOICE {
+        return build_explicit(value, number, class);
+    }
+    return build_retagged(value, number, class);
+}
+
+/// Parses a value with AUTOMATIC tagging, which is EXPLICIT for choices
+/// and IMPLICIT for the rest of types, as in X.680
+pub fn parse_automatic<T: Asn1Object>(
+    raw: &[u8],
+    number: u32,
+    class: TagClass,
+) -> asn1err::Result<(&[u8], T)> {
+    if T::CHOICE {
+        return parse_explicit(raw, number, class);
+    }
+    return parse_retagged(raw, number, class);
+}
+
+/// Checks if a tag is the AUTOMATIC tag of the type with the given
+/// number and class
+pub fn is_automatic_tag<T: Asn1Object>(
+    tag: &Tag,
+    number: u32,
+    class: TagClass,
+) -> bool {
+    if T::CHOICE {
+        return *tag == Tag::new(number, TagType::Constructed, class);
+    }
+    return is_implicit_tag::<T>(tag, number, class);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Integer, OctetString, SequenceOf};
+
+    #[test]
+    fn test_build_explicit() {
+        assert_eq!(
+            vec![0xa0, 0x3, 0x2, 0x1, 0x5],
+            build_explicit(&Integer::from(5), 0, TagClass::Context)
+        );
+        assert_eq!(
+            Vec::<u8>::new(),
+            build_explicit(&Option::<Integer>::None, 0, TagClass::Context)
+        );
+    }
+
+    #[test]
+    fn test_parse_explicit() {
+        assert_eq!(
+            (&[0x22][..], Integer::from(5)),
+            parse_explicit::<Integer>(
+                &[0x61, 0x3, 0x2, 0x1, 0x5, 0x22],
+                1,
+                TagClass::Application
+            )
+            .unwrap()
+        );
+    }
+
+    #[should_panic(
+        expected = "UnmatchedTag { expected: Tag { number: 1, type: Constructed, class: Application }, found: Tag { number: 1, type: Primitive, class: Application } }"
+    )]
+    #[test]
+    fn test_parse_explicit_with_primitive_tag() {
+        parse_explicit::<Integer>(
+            &[0x41, 0x3, 0x2, 0x1, 0x5],
+            1,
+            TagClass::Application,
+        )
+        .unwrap();
+    }
+
+    #[should_panic(expected = "NoAllDataConsumed")]
+    #[test]
+    fn test_parse_explicit_with_excesive_value() {
+        parse_explicit::<Integer>(
+            &[0xa0, 0x4, 0x2, 0x1, 0x5, 0x0],
+            0,
+            TagClass::Context,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_build_implicit_primitive() {
+        assert_eq!(
+            vec![0x80, 0x1, 0x5],
+            build_implicit(&Integer::from(5), 0, TagClass::Context)
+        );
+        assert_eq!(
+            vec![0x42, 0x2, 0x1, 0x2],
+            build_implicit(
+                &OctetString::from(vec![0x1, 0x2]),
+                2,
+                TagClass::Application
+            )
+        );
+    }
+
+    #[test]
+    fn test_build_implicit_constructed() {
+        let value: SequenceOf<Integer> = vec![1];
+        assert_eq!(
+            vec![0xa1, 0x3, 0x2, 0x1, 0x1],
+            build_implicit(&value, 1, TagClass::Context)
+        );
+    }
+
+    #[test]
+    fn test_build_implicit_none() {
+        let value: Option<Integer> = None;
+        assert_eq!(
+            Vec::<u8>::new(),
+            build_implicit(&value, 1, TagClass::Context)
+        );
+    }
+
+    #[test]
+    fn test_parse_implicit() {
+        assert_eq!(
+            (&[0x22][..], Integer::from(5)),
+            parse_implicit::<Integer>(
+                &[0x80, 0x1, 0x5, 0x22],
+                0,
+                TagClass::Context
+            )
+            .unwrap()
+        );
+
+        let value: SequenceOf<Integer> = vec![1];
+        assert_eq!(
+            value,
+            parse_implicit::<SequenceOf<Integer>>(
+                &[0xa1, 0x3, 0x2, 0x1, 0x1],
+                1,
+                TagClass::Context
+            )
+            .unwrap()
+            .1
+        );
+    }
+
+    #[should_panic(
+        expected = "UnmatchedTag { expected: Tag { number: 0, type: Primitive, class: Context }, found: Tag { number: 1, type: Primitive, class: Context } }"
+    )]
+    #[test]
+    fn test_parse_implicit_with_other_tag() {
+        parse_implicit::<Integer>(&[0x81, 0x1, 0x5], 0, TagClass::Context)
+            .unwrap();
+    }
+
+    #[should_panic(
+        expected = "UnmatchedTag { expected: Tag { number: 0, type: Primitive, class: Context }, found: Tag { number: 0, type: Constructed, class: Context } }"
+    )]
+    #[test]
+    fn test_parse_implicit_with_other_form() {
+        parse_implicit::<Integer>(&[0xa0, 0x1, 0x5], 0, TagClass::Context)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_is_implicit_tag() {
+        let primitive = Tag::new(0, TagType::Primitive, TagClass::Context);
+        let constructed = Tag::new(0, TagType::Constructed, TagClass::Context);
+
+        assert!(is_implicit_tag::<Integer>(&primitive, 0, TagClass::Context));
+        assert!(!is_implicit_tag::<Integer>(
+            &constructed,
+            0,
+            TagClass::Context
+        ));
+        assert!(is_implicit_tag::<SequenceOf<Integer>>(
+            &constructed,
+            0,
+            TagClass::Context
+        ));
+        assert!(!is_implicit_tag::<Integer>(
+            &primitive,
+            1,
+            TagClass::Context
+        ));
+    }
+
+    #[should_panic(expected = "EmptyTag(Context)")]
+    #[test]
+    fn test_parse_implicit_empty() {
+        parse_implicit::<Integer>(&[], 0, TagClass::Context).unwrap();
+    }
+
+    #[should_panic(expected = "NoDataForLength")]
+    #[test]
+    fn test_parse_implicit_without_enough_data() {
+        parse_implicit::<Integer>(&[0x80, 0x2, 0x5], 0, TagClass::Context)
+            .unwrap();
+    }
+
+    /// Integer which is tagged as a choice
+    #[derive(Debug, PartialEq)]
+    struct ChoiceInteger(Integer);
+
+    impl Asn1Object for ChoiceInteger {
+        const CHOICE: bool = true;
+
+        fn tag() -> Tag {
+            return Integer::tag();
+        }
+
+        fn build_value(&self) -> Vec<u8> {
+            return self.0.build_value();
+        }
+
+        fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
+            return Ok(Self(Integer::decode_value(raw)?));
+        }
+    }
+
+    #[test]
+    fn test_build_automatic() {
+        assert_eq!(
+            vec![0x80, 0x1, 0x5],
+            build_automatic(&Integer::from(5), 0, TagClass::Context)
+        );
+        assert_eq!(
+            vec![0xa0, 0x3, 0x2, 0x1, 0x5],
+            build_automatic(&ChoiceInteger(5), 0, TagClass::Context)
+        );
+    }
+
+    #[test]
+    fn test_parse_automatic() {
+        assert_eq!(
+            5,
+            parse_automatic::<Integer>(&[0x80, 0x1, 0x5], 0, TagClass::Context)
+                .unwrap()
+                .1
+        );
+        assert_eq!(
+            ChoiceInteger(5),
+            parse_automatic::<ChoiceInteger>(
+                &[0xa0, 0x3, 0x2, 0x1, 0x5],
+                0,
+                TagClass::Context
+            )
+            .unwrap()
+            .1
+        );
+    }
+
+    #[test]
+    fn test_is_automatic_tag() {
+        let primitive = Tag::new(0, TagType::Primitive, TagClass::Context);
+        let constructed = Tag::new(0, TagType::Constructed, TagClass::Context);
+
+        assert!(is_automatic_tag::<Integer>(
+            &primitive,
+            0,
+            TagClass::Context
+        ));
+        assert!(!is_automatic_tag::<ChoiceInteger>(
+            &primitive,
+            0,
+            TagClass::Context
+        ));
+        assert!(is_automatic_tag::<ChoiceInteger>(
+            &constructed,
+            0,
+            TagClass::Context
+        ));
+    }
+}
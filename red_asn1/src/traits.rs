@@ -1,18 +1,47 @@
-use crate::tag::{Tag, TagClass};
+use crate::tag::Tag;
 use crate::error as asn1err;
 use crate::length::{build_length, parse_length};
+use crate::limits::{
+    check_input_length, enter_nesting, DecodeOptions, OptionsScope,
+};
 
 /// A trait to allow objects to be built/parsed from ASN1-DER
-pub trait Asn1Object: Sized + Default {
+pub trait Asn1Object: Sized {
+
+    /// Possible tags of the type, used to check at compile time that the
+    /// fields of a sequence or the alternatives of a choice can be
+    /// distinguished. Choices have a tag for each alternative. An empty
+    /// list means that the tags are unknown, so they are not checked.
+    const TAGS: &'static [Tag] = &[];
+
+    /// The type is a CHOICE, whose tag selects the alternative, so it
+    /// is always tagged explicitly by automatic tagging
+    const CHOICE: bool = false;
 
     /// Method to retrieve the tag of the object, used to identify each object in ASN1
     fn tag() -> Tag;
 
+    /// Method to check if a tag identifies an object of this type, generally
+    /// does not need to be overwritten. Types which can be identified by
+    /// several tags, such as choices, should overwrite it.
+    fn matches_tag(tag: &Tag) -> bool {
+        return *tag == Self::tag();
+    }
+
     /// Method which indicates how object value must be built
     fn build_value(&self) -> Vec<u8>;
 
-    /// Method which indicates how object value must be parsed
-    fn parse_value(&mut self, raw: &[u8]) -> asn1err::Result<()>;
+    /// Method which indicates how object value must be parsed, creating
+    /// the object from the octets of its value
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self>;
+
+    /// To parse the object value into an existing object, generally does
+    /// not need to be overwritten. Usually, just decode_value should be
+    /// overwritten
+    fn parse_value(&mut self, raw: &[u8]) -> asn1err::Result<()> {
+        *self = Self::decode_value(raw)?;
+        return Ok(());
+    }
 
     /// To encode the object to DER, generally does not need to be overwritten.
     /// Usually, just encode_value should be overwritten
@@ -28,33 +57,137 @@ pub trait Asn1Object: Sized + Default {
     }
 
     /// To parse the object from DER, generally does not need to be overwritten.
-    /// Usually, just parse_value should be overwritten
+    /// Usually, just parse_value should be overwritten. The offsets of
+    /// the errors of the value are moved to be relative to the tag. The
+    /// limits of the nesting depth and the other DecodeOptions are only
+    /// applied inside `decode_with`, so untrusted input should be decoded
+    /// with it.
     fn parse(raw: &[u8]) -> asn1err::Result<(&[u8], Self)> {
-        let (raw, parsed_tag) = Tag::parse(raw)?;
+        let _nesting = enter_nesting()?;
+        let (raw_length, parsed_tag) = Tag::parse(raw)?;
         if parsed_tag != Self::tag() {
-            return Err(asn1err::Error::UnmatchedTag(TagClass::Universal))?;
+            return Err(asn1err::Error::UnmatchedTag {
+                expected: Self::tag(),
+                found: parsed_tag,
+            })?;
         }
 
-        let (raw, length) = parse_length(raw)?;
-        if length > raw.len() {
+        let (raw_value, length) = parse_length(raw_length)?;
+        if length > raw_value.len() {
             return Err(asn1err::Error::NoDataForLength)?;
         }
 
-        let (raw_value, raw) = raw.split_at(length);
-        let mut asn1obj = Self::default();
-        asn1obj.parse_value(raw_value)?;
+        let header_length = raw.len() - raw_value.len();
+        let (raw_value, raw) = raw_value.split_at(length);
+        let asn1obj = Self::decode_value(raw_value)
+            .map_err(|error| error.shift(header_length))?;
 
         return Ok((raw, asn1obj));
     }
 
+    /// To decode an object from DER received with an IMPLICIT tag, given
+    /// the received octets and the same octets with the own tag of the
+    /// type, generally does not need to be overwritten. Types that keep
+    /// the received octets, such as Raw, should overwrite it.
+    fn decode_implicit(_raw: &[u8], retagged: &[u8]) -> asn1err::Result<Self> {
+        return Self::decode(retagged);
+    }
+
+    /// To decode an object from DER which takes all the octets,
+    /// generally does not need to be overwritten. As in `parse`, the
+    /// limits of DecodeOptions are only applied inside `decode_with`.
+    fn decode(raw: &[u8]) -> asn1err::Result<Self> {
+        let (raw, asn1obj) = Self::parse(raw)?;
+        if !raw.is_empty() {
+            return Err(asn1err::Error::NoAllDataConsumed)?;
+        }
+
+        return Ok(asn1obj);
+    }
+
+    /// To decode an object from untrusted DER, such as the messages
+    /// received from the network, with the limits of the options applied
+    /// to the values parsed inside, generally does not need to be
+    /// overwritten
+    fn decode_with(
+        raw: &[u8],
+        options: &DecodeOptions,
+    ) -> asn1err::Result<Self> {
+        check_input_length(options, raw.len())?;
+        let _scope = OptionsScope::new(options);
+        return Self::decode(raw);
+    }
+
+    /// To check the constraints of the object, such as the size or
+    /// range of its values, which are checked when it is parsed.
+    /// Types with inner objects should overwrite it to check them.
+    fn validate(&self) -> asn1err::Result<()> {
+        return Ok(());
+    }
+
+    /// To encode the object to DER checking its constraints before,
+    /// generally does not need to be overwritten
+    fn encode(&self) -> asn1err::Result<Vec<u8>> {
+        self.validate()?;
+        return Ok(self.build());
+    }
+
+}
+
+/// A trait to allow the components of a sequence to be built/parsed
+/// inside other sequence, without its tag and length (COMPONENTS OF)
+pub trait Asn1Components: Asn1Object {
+    /// Possible tags of the first component, which are the tags of the
+    /// leading components until the first one which cannot be absent,
+    /// used to check at compile time that the components can be
+    /// distinguished from the fields of the including sequence. An
+    /// empty list means that the tags are unknown, so they are not
+    /// checked.
+    const FIRST_TAGS: &'static [Tag] = &[];
+
+    /// Possible tags of the trailing components which can be absent,
+    /// which must be distinct from the tags of the following fields of
+    /// the including sequence. An empty list means that there are no
+    /// such components or that their tags are unknown.
+    const LAST_TAGS: &'static [Tag] = &[];
+
+    /// The last component takes the unknown trailing elements, so the
+    /// components cannot be included in other sequence
+    const UNKNOWN: bool = false;
+
+    /// To parse the components from the start of the octets, returning
+    /// the remaining ones, which belong to the including sequence
+    fn parse_components(raw: &[u8]) -> asn1err::Result<(&[u8], Self)>;
+
+    /// To encode the components, without the tag and length of the
+    /// sequence, generally does not need to be overwritten
+    fn build_components(&self) -> Vec<u8> {
+        return self.build_value();
+    }
 }
 
+/// A trait to allow open types (ANY DEFINED BY) to be built/parsed from
+/// ASN1-DER. The type of the value of an open type is selected by
+/// another value, the discriminant, such as an OID or an integer.
+pub trait Asn1OpenType: Sized {
+    /// Type of the value which selects the type of the open type value
+    type Discriminant;
+
+    /// To encode the value to DER, including its tag and length
+    fn build_open(&self) -> Vec<u8>;
+
+    /// To parse the value from DER, decoding it with the type that
+    /// corresponds to the discriminant
+    fn parse_open<'a>(
+        discriminant: &Self::Discriminant,
+        raw: &'a [u8],
+    ) -> asn1err::Result<(&'a [u8], Self)>;
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[derive(Default)]
     struct TestObject {
     }
 
@@ -68,8 +201,8 @@ mod tests {
             return vec![];
         }
 
-        fn parse_value(&mut self, _raw: &[u8]) -> asn1err::Result<()> {
-            return Ok(());
+        fn decode_value(_raw: &[u8]) -> asn1err::Result<Self> {
+            return Ok(Self {});
         }
 
     }
@@ -80,4 +213,20 @@ mod tests {
         TestObject::parse(&[0x0, 0x3, 0x0]).unwrap();
     }
 
+    #[test]
+    fn test_encode() {
+        assert_eq!(vec![0x0, 0x0], TestObject {}.encode().unwrap());
+    }
+
+    #[test]
+    fn test_decode() {
+        TestObject::decode(&[0x0, 0x0]).unwrap();
+    }
+
+    #[should_panic (expected = "NoAllDataConsumed")]
+    #[test]
+    fn test_decode_with_excesive_data() {
+        TestObject::decode(&[0x0, 0x0, 0x0]).unwrap();
+    }
+
 }
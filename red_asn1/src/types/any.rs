@@ -0,0 +1,83 @@
+use crate::error as asn1err;
+use crate::length::parse_length;
+use crate::tag::Tag;
+
+/// Class to hold an ASN1 object without decoding it (ANY), keeping
+/// its tag, length and value octets as they were received.
+///
+/// Used to store the values of open types whose discriminant is unknown.
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct Any {
+    pub raw: Vec<u8>,
+}
+
+impl Any {
+    pub fn new(raw: Vec<u8>) -> Self {
+        return Self { raw };
+    }
+
+    /// Tag of the held object
+    pub fn tag(&self) -> asn1err::Result<Tag> {
+        let (_, tag) = Tag::parse(&self.raw)?;
+        return Ok(tag);
+    }
+
+    /// Returns the held octets, without any modification
+    pub fn build(&self) -> Vec<u8> {
+        return self.raw.clone();
+    }
+
+    /// Takes the octets of the next object in raw, whatever its tag is
+    pub fn parse(raw: &[u8]) -> asn1err::Result<(&[u8], Self)> {
+        let (raw_local, _) = Tag::parse(raw)?;
+        let (raw_local, length) = parse_length(raw_local)?;
+        if length > raw_local.len() {
+            return Err(asn1err::Error::NoDataForLength)?;
+        }
+
+        let consumed = raw.len() - raw_local.len() + length;
+        let (raw_any, raw) = raw.split_at(consumed);
+
+        return Ok((raw, Self::new(raw_any.to_vec())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tag::{TagClass, TagType};
+
+    #[test]
+    fn test_build() {
+        assert_eq!(vec![0x2, 0x1, 0x9], Any::new(vec![0x2, 0x1, 0x9]).build());
+    }
+
+    #[test]
+    fn test_parse() {
+        let x: &[u8] = &[0x22];
+        assert_eq!(
+            (x, Any::new(vec![0xa1, 0x3, 0x2, 0x1, 0x9])),
+            Any::parse(&[0xa1, 0x3, 0x2, 0x1, 0x9, 0x22]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_tag() {
+        assert_eq!(
+            Tag::new(1, TagType::Constructed, TagClass::Context),
+            Any::new(vec![0xa1, 0x3, 0x2, 0x1, 0x9]).tag().unwrap()
+        );
+    }
+
+    #[should_panic(expected = "NoDataForLength")]
+    #[test]
+    fn test_parse_without_enough_data() {
+        Any::parse(&[0x4, 0x3, 0x0]).unwrap();
+    }
+
+    #[should_panic(expected = "EmptyTag")]
+    #[test]
+    fn test_parse_empty() {
+        Any::parse(&[]).unwrap();
+    }
+}
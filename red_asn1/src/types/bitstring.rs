@@ -2,7 +2,7 @@ use crate::error as asn1err;
 use crate::tag::Tag;
 use crate::traits::Asn1Object;
 
-pub static BIT_STRING_TAG_NUMBER: u8 = 0x3;
+pub const BIT_STRING_TAG_NUMBER: u8 = 0x3;
 
 /// Class to build/parse BitSring ASN1
 #[derive(Debug, PartialEq, Default, Clone)]
@@ -23,52 +23,59 @@ impl BitString {
     }
 
     fn pad_with_0(&mut self) {
-        match self.bytes.pop() {
-            Some(last_item) => {
-                self.bytes
-                    .push(Self::set_0_padding(last_item, self.padding_length));
-            }
-            None => {}
+        if let Some(last_item) = self.bytes.pop() {
+            self.bytes
+                .push(Self::set_0_padding(last_item, self.padding_length));
         }
     }
 
-    fn set_0_padding(mut item: u8, padding_length: u8) -> u8 {
-        item >>= padding_length;
-        item <<= padding_length;
-        return item;
+    fn set_0_padding(item: u8, padding_length: u8) -> u8 {
+        // an invalid padding, longer than the octet, clears all the bits
+        let mask = 0xffu8.checked_shl(padding_length as u32).unwrap_or(0);
+        return item & mask;
     }
 }
 
 impl Asn1Object for BitString {
+    const TAGS: &'static [Tag] =
+        &[Tag::new_primitive_universal(BIT_STRING_TAG_NUMBER)];
+
     fn tag() -> Tag {
         return Tag::new_primitive_universal(BIT_STRING_TAG_NUMBER);
     }
 
     fn build_value(&self) -> Vec<u8> {
         let mut encoded_value: Vec<u8> = vec![self.padding_length];
-
-        let mut values: Vec<u8> = Vec::new();
-        let bytes = &self.bytes;
-        for i in 0..bytes.len() {
-            values.push(bytes[i])
-        }
-        encoded_value.append(&mut values);
+        encoded_value.extend_from_slice(&self.bytes);
 
         return encoded_value;
     }
 
-    fn parse_value(&mut self, raw: &[u8]) -> asn1err::Result<()> {
-        if raw.len() == 0 {
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
+        if raw.is_empty() {
             return Err(asn1err::Error::IncorrectValue(
-                format!("No octects for BitString")
+                "No octects for BitString".to_string()
             ))?;
         }
 
         let (padding_length, raw_value) = raw.split_at(1);
+        let padding_length = padding_length[0];
 
-        *self = BitString::new(raw_value.to_vec(), padding_length[0]);
+        if padding_length > 7 {
+            return Err(asn1err::Error::IncorrectValue(format!(
+                "Invalid padding length {} for BitString",
+                padding_length
+            )))?;
+        }
+
+        if padding_length != 0 && raw_value.is_empty() {
+            return Err(asn1err::Error::IncorrectValue(format!(
+                "Padding length {} without octets for BitString",
+                padding_length
+            )))?;
+        }
 
-        return Ok(());
+        return Ok(BitString::new(raw_value.to_vec(), padding_length));
     }
 }
 
@@ -110,8 +117,8 @@ mod tests {
             BitString::parse(&[0x3, 0x2, 0x4, 0xF0]).unwrap().1
         );
         assert_eq!(
-            BitString::new(vec![], 4),
-            BitString::parse(&[0x3, 0x1, 0x4]).unwrap().1
+            BitString::new(vec![], 0),
+            BitString::parse(&[0x3, 0x1, 0x0]).unwrap().1
         );
     }
 
@@ -132,8 +139,8 @@ mod tests {
             BitString::parse(&[0x3, 0x2, 0x4, 0xF0, 0x11, 0x22]).unwrap()
         );
         assert_eq!(
-            (x, BitString::new(vec![], 4)),
-            BitString::parse(&[0x3, 0x1, 0x4, 0x11, 0x22]).unwrap()
+            (x, BitString::new(vec![], 0)),
+            BitString::parse(&[0x3, 0x1, 0x0, 0x11, 0x22]).unwrap()
         );
     }
 
@@ -149,6 +156,22 @@ mod tests {
         BitString::parse(&[0x3, 0x0]).unwrap();
     }
 
+    #[should_panic(
+        expected = "IncorrectValue(\"Invalid padding length 255 for BitString\")"
+    )]
+    #[test]
+    fn test_parse_with_invalid_padding_length() {
+        BitString::parse(&[0x3, 0x2, 0xff, 0x0]).unwrap();
+    }
+
+    #[should_panic(
+        expected = "IncorrectValue(\"Padding length 4 without octets for BitString\")"
+    )]
+    #[test]
+    fn test_parse_padding_without_octets() {
+        BitString::parse(&[0x3, 0x1, 0x4]).unwrap();
+    }
+
     #[test]
     fn test_value_get_bytes() {
         let b = BitString::new(vec![0x0, 0x1, 0x2, 0x3], 0);
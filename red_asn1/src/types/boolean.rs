@@ -2,12 +2,15 @@ use crate::error as asn1err;
 use crate::tag::Tag;
 use crate::traits::Asn1Object;
 
-pub static BOOLEAN_TAG_NUMBER: u8 = 0x1;
+pub const BOOLEAN_TAG_NUMBER: u8 = 0x1;
 
 /// Class to build/parse Boolean ASN1
 pub type Boolean = bool;
 
 impl Asn1Object for bool {
+    const TAGS: &'static [Tag] =
+        &[Tag::new_primitive_universal(BOOLEAN_TAG_NUMBER)];
+
     fn tag() -> Tag {
         return Tag::new_primitive_universal(BOOLEAN_TAG_NUMBER);
     }
@@ -16,15 +19,14 @@ impl Asn1Object for bool {
         return vec![(*self as u8) * 0xff];
     }
 
-    fn parse_value(&mut self, raw: &[u8]) -> asn1err::Result<()> {
-        if raw.len() == 0 {
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
+        if raw.is_empty() {
             return Err(asn1err::Error::IncorrectValue(
-                format!("No octects for bool")
+                "No octects for bool".to_string()
             ))?;
         }
 
-        *self = raw[0] != 0;
-        return Ok(());
+        return Ok(raw[0] != 0);
     }
 }
 
@@ -40,10 +42,10 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        assert_eq!(false, bool::parse(&[0x1, 0x1, 0x0]).unwrap().1);
-        assert_eq!(true, bool::parse(&[0x1, 0x1, 0xff]).unwrap().1);
-        assert_eq!(true, bool::parse(&[0x1, 0x1, 0x01]).unwrap().1);
-        assert_eq!(true, bool::parse(&[0x1, 0x1, 0x7b]).unwrap().1);
+        assert!(!bool::parse(&[0x1, 0x1, 0x0]).unwrap().1);
+        assert!(bool::parse(&[0x1, 0x1, 0xff]).unwrap().1);
+        assert!(bool::parse(&[0x1, 0x1, 0x01]).unwrap().1);
+        assert!(bool::parse(&[0x1, 0x1, 0x7b]).unwrap().1);
     }
 
     #[test]
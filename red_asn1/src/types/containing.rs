@@ -0,0 +1,196 @@
+use crate::error as asn1err;
+use crate::tag::Tag;
+use crate::traits::Asn1Object;
+use crate::types::{BIT_STRING_TAG_NUMBER, OCTET_STRING_TAG_NUMBER};
+use std::ops::{Deref, DerefMut};
+
+/// Class to build/parse OCTET STRING (CONTAINING T) ASN1, an OctetString
+/// whose octets are the DER encoding of a value of type T
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Containing<T: Asn1Object>(T);
+
+impl<T: Asn1Object> Containing<T> {
+    pub fn new(v: T) -> Self {
+        return Self(v);
+    }
+
+    pub fn into_inner(self) -> T {
+        return self.0;
+    }
+}
+
+impl<T: Asn1Object> Deref for Containing<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Asn1Object> DerefMut for Containing<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: Asn1Object> Asn1Object for Containing<T> {
+    const TAGS: &'static [Tag] =
+        &[Tag::new_primitive_universal(OCTET_STRING_TAG_NUMBER)];
+
+    fn tag() -> Tag {
+        return Tag::new_primitive_universal(OCTET_STRING_TAG_NUMBER);
+    }
+
+    fn build_value(&self) -> Vec<u8> {
+        return self.0.build();
+    }
+
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
+        return Ok(Self(parse_contained(raw)?));
+    }
+
+    fn validate(&self) -> asn1err::Result<()> {
+        return self.0.validate();
+    }
+}
+
+/// Class to build/parse BIT STRING (CONTAINING T) ASN1, a BitString
+/// without padding whose bits are the DER encoding of a value of type T
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BitStringContaining<T: Asn1Object>(T);
+
+impl<T: Asn1Object> BitStringContaining<T> {
+    pub fn new(v: T) -> Self {
+        return Self(v);
+    }
+
+    pub fn into_inner(self) -> T {
+        return self.0;
+    }
+}
+
+impl<T: Asn1Object> Deref for BitStringContaining<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Asn1Object> DerefMut for BitStringContaining<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: Asn1Object> Asn1Object for BitStringContaining<T> {
+    const TAGS: &'static [Tag] =
+        &[Tag::new_primitive_universal(BIT_STRING_TAG_NUMBER)];
+
+    fn tag() -> Tag {
+        return Tag::new_primitive_universal(BIT_STRING_TAG_NUMBER);
+    }
+
+    fn build_value(&self) -> Vec<u8> {
+        let mut encoded_value = vec![0];
+        encoded_value.append(&mut self.0.build());
+        return encoded_value;
+    }
+
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
+        if raw.is_empty() {
+            return Err(asn1err::Error::IncorrectValue(
+                "No octects for BitString".to_string(),
+            ))?;
+        }
+
+        let (padding_length, raw_value) = raw.split_at(1);
+        if padding_length[0] != 0 {
+            return Err(asn1err::Error::IncorrectValue(format!(
+                "Padding in BitString with contained value: {}",
+                padding_length[0]
+            )))?;
+        }
+
+        return Ok(Self(
+            parse_contained(raw_value).map_err(|error| error.shift(1))?,
+        ));
+    }
+
+    fn validate(&self) -> asn1err::Result<()> {
+        return self.0.validate();
+    }
+}
+
+/// Parse the contained value, which must take all the octets
+fn parse_contained<T: Asn1Object>(raw: &[u8]) -> asn1err::Result<T> {
+    let (raw, value) = T::parse(raw)?;
+    if !raw.is_empty() {
+        return Err(asn1err::Error::NoAllDataConsumed)?;
+    }
+    return Ok(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{GeneralString, Integer};
+
+    #[test]
+    fn test_build() {
+        assert_eq!(
+            vec![0x4, 0x3, 0x2, 0x1, 0x9],
+            Containing::new(Integer::from(9)).build()
+        );
+        assert_eq!(
+            vec![0x3, 0x4, 0x0, 0x2, 0x1, 0x9],
+            BitStringContaining::new(Integer::from(9)).build()
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Containing::new(GeneralString::from("ab")),
+            Containing::<GeneralString>::parse(&[
+                0x4, 0x4, 0x1b, 0x2, 0x61, 0x62
+            ])
+            .unwrap()
+            .1
+        );
+        assert_eq!(
+            BitStringContaining::new(Integer::from(9)),
+            BitStringContaining::<Integer>::parse(&[
+                0x3, 0x4, 0x0, 0x2, 0x1, 0x9
+            ])
+            .unwrap()
+            .1
+        );
+    }
+
+    #[test]
+    fn test_deref() {
+        let contained = Containing::new(Integer::from(9));
+        assert_eq!(9, *contained);
+        assert_eq!(9, contained.into_inner());
+    }
+
+    #[should_panic(expected = "UnmatchedTag")]
+    #[test]
+    fn test_parse_with_invalid_contained_tag() {
+        Containing::<Integer>::parse(&[0x4, 0x3, 0x4, 0x1, 0x9]).unwrap();
+    }
+
+    #[should_panic(expected = "NoAllDataConsumed")]
+    #[test]
+    fn test_parse_with_excesive_contained_bytes() {
+        Containing::<Integer>::parse(&[0x4, 0x4, 0x2, 0x1, 0x9, 0x0]).unwrap();
+    }
+
+    #[should_panic(
+        expected = "IncorrectValue(\"Padding in BitString with contained value: 2\")"
+    )]
+    #[test]
+    fn test_parse_bit_string_with_padding() {
+        BitStringContaining::<Integer>::parse(&[0x3, 0x4, 0x2, 0x2, 0x1, 0x9])
+            .unwrap();
+    }
+}
@@ -0,0 +1,213 @@
+use crate::error as asn1err;
+use crate::tag::Tag;
+use crate::traits::Asn1Object;
+use chrono::prelude::*;
+use std::ops::{Deref, DerefMut};
+use std::str;
+
+pub const DATE_TAG_NUMBER: u8 = 0x1f;
+
+/// Parses the decimal digits of a field of fixed size, such as the
+/// month of a date. Unlike `str::parse`, signs are not allowed.
+pub(super) fn parse_digits(raw: &[u8]) -> asn1err::Result<u32> {
+    if raw.is_empty() || !raw.iter().all(u8::is_ascii_digit) {
+        return Err(asn1err::Error::ParseIntError)?;
+    }
+    return Ok(str::from_utf8(raw)?.parse()?);
+}
+
+/// Checks that the year can be built with 4 digits
+pub(super) fn check_year(year: i32, type_name: &str) -> asn1err::Result<()> {
+    if !(0..=9999).contains(&year) {
+        return Err(asn1err::Error::ConstraintError(format!(
+            "Year {} out of range for {}",
+            year, type_name
+        )))?;
+    }
+    return Ok(());
+}
+
+/// Class to build/parse DATE ASN1 (YYYYMMDD). Only the years from 0 to
+/// 9999 can be encoded.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Date {
+    pub date: NaiveDate,
+}
+
+impl Deref for Date {
+    type Target = NaiveDate;
+    fn deref(&self) -> &Self::Target {
+        &self.date
+    }
+}
+
+impl DerefMut for Date {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.date
+    }
+}
+
+impl Asn1Object for Date {
+    const TAGS: &'static [Tag] =
+        &[Tag::new_primitive_universal(DATE_TAG_NUMBER)];
+
+    fn tag() -> Tag {
+        return Tag::new_primitive_universal(DATE_TAG_NUMBER);
+    }
+
+    fn build_value(&self) -> Vec<u8> {
+        return self.format("%Y%m%d").to_string().into_bytes();
+    }
+
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
+        if raw.len() != 8 {
+            return Err(asn1err::Error::IncorrectValue(format!(
+                "Invalid number of octets for Date: {} octets",
+                raw.len()
+            )))?;
+        }
+
+        let year = parse_digits(&raw[0..4])? as i32;
+        let month = parse_digits(&raw[4..6])?;
+        let day = parse_digits(&raw[6..8])?;
+
+        let date =
+            NaiveDate::from_ymd_opt(year, month, day).ok_or_else(|| {
+                asn1err::Error::IncorrectValue(
+                    "Invalid date for Date".to_string(),
+                )
+            })?;
+
+        return Ok(Self { date });
+    }
+
+    fn validate(&self) -> asn1err::Result<()> {
+        return check_year(self.year(), "Date");
+    }
+}
+
+impl Default for Date {
+    fn default() -> Self {
+        return Self {
+            date: DateTime::UNIX_EPOCH.date_naive(),
+        };
+    }
+}
+
+impl From<NaiveDate> for Date {
+    fn from(date: NaiveDate) -> Self {
+        return Self { date };
+    }
+}
+
+impl From<Date> for NaiveDate {
+    fn from(date: Date) -> Self {
+        return date.date;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_default() {
+        assert_eq!(
+            Date {
+                date: NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(),
+            },
+            Date::default()
+        )
+    }
+
+    #[test]
+    fn test_build() {
+        assert_eq!(
+            vec![
+                0x1f, 0x1f, 0x08, 0x31, 0x39, 0x38, 0x35, 0x31, 0x31, 0x30,
+                0x36
+            ],
+            Date::from(NaiveDate::from_ymd_opt(1985, 11, 6).unwrap()).build()
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Date::from(NaiveDate::from_ymd_opt(1985, 11, 6).unwrap()),
+            Date::parse(&[
+                0x1f, 0x1f, 0x08, 0x31, 0x39, 0x38, 0x35, 0x31, 0x31, 0x30,
+                0x36
+            ])
+            .unwrap()
+            .1
+        );
+    }
+
+    #[test]
+    fn test_parse_with_excesive_bytes() {
+        let rest: &[u8] = &[0x22, 0x22];
+        assert_eq!(
+            (
+                rest,
+                Date::from(NaiveDate::from_ymd_opt(1985, 11, 6).unwrap())
+            ),
+            Date::parse(&[
+                0x1f, 0x1f, 0x08, 0x31, 0x39, 0x38, 0x35, 0x31, 0x31, 0x30,
+                0x36, 0x22, 0x22
+            ])
+            .unwrap()
+        );
+    }
+
+    #[should_panic(
+        expected = "IncorrectValue(\"Invalid number of octets for Date: 7 octets\")"
+    )]
+    #[test]
+    fn test_parse_without_enough_value_octets() {
+        Date::parse(&[
+            0x1f, 0x1f, 0x07, 0x31, 0x39, 0x38, 0x35, 0x31, 0x31, 0x30,
+        ])
+        .unwrap();
+    }
+
+    #[should_panic(expected = "IncorrectValue(\"Invalid date for Date\")")]
+    #[test]
+    fn test_parse_invalid_date() {
+        Date::parse(&[
+            0x1f, 0x1f, 0x08, 0x31, 0x39, 0x38, 0x35, 0x31, 0x33, 0x30, 0x36,
+        ])
+        .unwrap();
+    }
+
+    #[should_panic(expected = "ParseIntError")]
+    #[test]
+    fn test_parse_signed_month() {
+        Date::parse(&[
+            0x1f, 0x1f, 0x08, 0x31, 0x39, 0x38, 0x35, 0x2b, 0x31, 0x30, 0x36,
+        ])
+        .unwrap();
+    }
+
+    #[test]
+    fn test_encode_year_out_of_range() {
+        assert_eq!(
+            Err(asn1err::Error::ConstraintError(
+                "Year 10000 out of range for Date".to_string()
+            )),
+            Date::from(NaiveDate::from_ymd_opt(10000, 1, 1).unwrap()).encode()
+        );
+        assert_eq!(
+            Err(asn1err::Error::ConstraintError(
+                "Year -1 out of range for Date".to_string()
+            )),
+            Date::from(NaiveDate::from_ymd_opt(-1, 1, 1).unwrap()).encode()
+        );
+    }
+
+    #[should_panic(expected = "UnmatchedTag")]
+    #[test]
+    fn test_parse_with_invalid_tag() {
+        Date::parse(&[0x1f, 0x20, 0x0]).unwrap();
+    }
+}
@@ -0,0 +1,190 @@
+use super::date::{check_year, parse_digits};
+use crate::error as asn1err;
+use crate::tag::Tag;
+use crate::traits::Asn1Object;
+use chrono::prelude::*;
+use std::ops::{Deref, DerefMut};
+
+pub const DATE_TIME_TAG_NUMBER: u8 = 0x21;
+
+/// Class to build/parse DATE-TIME ASN1 (YYYYMMDDHHMMSS). Only the years
+/// from 0 to 9999 can be encoded.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DateTime {
+    pub datetime: NaiveDateTime,
+}
+
+impl Deref for DateTime {
+    type Target = NaiveDateTime;
+    fn deref(&self) -> &Self::Target {
+        &self.datetime
+    }
+}
+
+impl DerefMut for DateTime {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.datetime
+    }
+}
+
+impl Asn1Object for DateTime {
+    const TAGS: &'static [Tag] =
+        &[Tag::new_primitive_universal(DATE_TIME_TAG_NUMBER)];
+
+    fn tag() -> Tag {
+        return Tag::new_primitive_universal(DATE_TIME_TAG_NUMBER);
+    }
+
+    fn build_value(&self) -> Vec<u8> {
+        return self.format("%Y%m%d%H%M%S").to_string().into_bytes();
+    }
+
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
+        if raw.len() != 14 {
+            return Err(asn1err::Error::IncorrectValue(format!(
+                "Invalid number of octets for DateTime: {} octets",
+                raw.len()
+            )))?;
+        }
+
+        let year = parse_digits(&raw[0..4])? as i32;
+        let month = parse_digits(&raw[4..6])?;
+        let day = parse_digits(&raw[6..8])?;
+        let hour = parse_digits(&raw[8..10])?;
+        let minute = parse_digits(&raw[10..12])?;
+        let second = parse_digits(&raw[12..14])?;
+
+        let datetime = NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(|date| date.and_hms_opt(hour, minute, second))
+            .ok_or_else(|| {
+                asn1err::Error::IncorrectValue(
+                    "Invalid date for DateTime".to_string(),
+                )
+            })?;
+
+        return Ok(Self { datetime });
+    }
+
+    fn validate(&self) -> asn1err::Result<()> {
+        return check_year(self.year(), "DateTime");
+    }
+}
+
+impl Default for DateTime {
+    fn default() -> Self {
+        return Self {
+            datetime: chrono::DateTime::UNIX_EPOCH.naive_utc(),
+        };
+    }
+}
+
+impl From<NaiveDateTime> for DateTime {
+    fn from(datetime: NaiveDateTime) -> Self {
+        return Self { datetime };
+    }
+}
+
+impl From<DateTime> for NaiveDateTime {
+    fn from(datetime: DateTime) -> Self {
+        return datetime.datetime;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _datetime(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+    ) -> NaiveDateTime {
+        return NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, second)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_create_default() {
+        assert_eq!(
+            DateTime::from(_datetime(1970, 1, 1, 0, 0, 0)),
+            DateTime::default()
+        )
+    }
+
+    #[test]
+    fn test_build() {
+        assert_eq!(
+            vec![
+                0x1f, 0x21, 0x0e, 0x31, 0x39, 0x38, 0x35, 0x31, 0x31, 0x30,
+                0x36, 0x32, 0x31, 0x30, 0x36, 0x32, 0x37
+            ],
+            DateTime::from(_datetime(1985, 11, 6, 21, 6, 27)).build()
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            DateTime::from(_datetime(1985, 11, 6, 21, 6, 27)),
+            DateTime::parse(&[
+                0x1f, 0x21, 0x0e, 0x31, 0x39, 0x38, 0x35, 0x31, 0x31, 0x30,
+                0x36, 0x32, 0x31, 0x30, 0x36, 0x32, 0x37
+            ])
+            .unwrap()
+            .1
+        );
+    }
+
+    #[should_panic(
+        expected = "IncorrectValue(\"Invalid number of octets for DateTime: 15 octets\")"
+    )]
+    #[test]
+    fn test_parse_with_generalized_time_format() {
+        DateTime::parse(&[
+            0x1f, 0x21, 0x0f, 0x31, 0x39, 0x38, 0x35, 0x31, 0x31, 0x30, 0x36,
+            0x32, 0x31, 0x30, 0x36, 0x32, 0x37, 0x5a,
+        ])
+        .unwrap();
+    }
+
+    #[should_panic(expected = "IncorrectValue(\"Invalid date for DateTime\")")]
+    #[test]
+    fn test_parse_invalid_date() {
+        DateTime::parse(&[
+            0x1f, 0x21, 0x0e, 0x31, 0x39, 0x38, 0x35, 0x30, 0x32, 0x33, 0x30,
+            0x32, 0x31, 0x30, 0x36, 0x32, 0x37,
+        ])
+        .unwrap();
+    }
+
+    #[should_panic(expected = "ParseIntError")]
+    #[test]
+    fn test_parse_signed_second() {
+        DateTime::parse(&[
+            0x1f, 0x21, 0x0e, 0x31, 0x39, 0x38, 0x35, 0x31, 0x31, 0x30, 0x36,
+            0x32, 0x31, 0x30, 0x36, 0x2b, 0x37,
+        ])
+        .unwrap();
+    }
+
+    #[test]
+    fn test_encode_year_out_of_range() {
+        assert_eq!(
+            Err(asn1err::Error::ConstraintError(
+                "Year 10000 out of range for DateTime".to_string()
+            )),
+            DateTime::from(_datetime(10000, 1, 1, 0, 0, 0)).encode()
+        );
+    }
+
+    #[should_panic(expected = "UnmatchedTag")]
+    #[test]
+    fn test_parse_with_invalid_tag() {
+        DateTime::parse(&[0x18, 0x0]).unwrap();
+    }
+}
@@ -0,0 +1,349 @@
+use crate::error as asn1err;
+use crate::tag::Tag;
+use crate::traits::Asn1Object;
+use chrono::TimeDelta;
+use std::ops::{Deref, DerefMut};
+use std::str;
+
+pub const DURATION_TAG_NUMBER: u8 = 0x22;
+
+/// Class to build/parse DURATION ASN1 (ISO 8601 duration, as `P1DT2H3M4.5S`).
+///
+/// Durations are built in their canonical form, with the time split
+/// into days, hours, minutes and seconds. Since a DURATION cannot be
+/// negative, the magnitude of a negative `TimeDelta` is built. Years
+/// and months have no fixed length, so parsing them is not supported.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Duration {
+    pub duration: TimeDelta,
+}
+
+impl Duration {
+    fn parse_component(
+        number: &str,
+        designator: char,
+        is_time: bool,
+    ) -> asn1err::Result<TimeDelta> {
+        if designator == 'S' && is_time {
+            return Self::parse_seconds(number);
+        }
+
+        let value: i64 = number.parse()?;
+        let component = match (designator, is_time) {
+            ('W', false) => TimeDelta::try_weeks(value),
+            ('D', false) => TimeDelta::try_days(value),
+            ('H', true) => TimeDelta::try_hours(value),
+            ('M', true) => TimeDelta::try_minutes(value),
+            ('Y', false) | ('M', false) => {
+                return Err(asn1err::Error::ImplementationError(
+                    "Years and months in Duration are not supported"
+                        .to_string(),
+                ));
+            }
+            _ => {
+                return Err(asn1err::Error::IncorrectValue(format!(
+                    "Invalid designator for Duration: {}",
+                    designator
+                )));
+            }
+        };
+
+        return component.ok_or_else(|| {
+            asn1err::Error::IncorrectValue("Too big Duration".to_string())
+        });
+    }
+
+    /// Position of the designator in a duration, whose components must
+    /// appear in this order and only once
+    fn designator_position(designator: char, is_time: bool) -> u8 {
+        return match (designator, is_time) {
+            ('Y', false) => 0,
+            ('M', false) => 1,
+            ('W', false) => 2,
+            ('D', false) => 3,
+            ('H', true) => 4,
+            ('M', true) => 5,
+            _ => 6,
+        };
+    }
+
+    fn parse_seconds(number: &str) -> asn1err::Result<TimeDelta> {
+        let mut parts = number.splitn(2, ['.', ',']);
+        let seconds: i64 = parts.next().unwrap_or_default().parse()?;
+        let mut nanoseconds = 0;
+
+        if let Some(fraction) = parts.next() {
+            if fraction.is_empty() || fraction.len() > 9 {
+                return Err(asn1err::Error::IncorrectValue(
+                    "Invalid fraction of seconds for Duration".to_string(),
+                ));
+            }
+            let digits: u32 = fraction.parse()?;
+            nanoseconds = digits * 10u32.pow(9 - fraction.len() as u32);
+        }
+
+        return TimeDelta::new(seconds, nanoseconds).ok_or_else(|| {
+            asn1err::Error::IncorrectValue("Too big Duration".to_string())
+        });
+    }
+}
+
+impl Deref for Duration {
+    type Target = TimeDelta;
+    fn deref(&self) -> &Self::Target {
+        &self.duration
+    }
+}
+
+impl DerefMut for Duration {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.duration
+    }
+}
+
+impl Asn1Object for Duration {
+    const TAGS: &'static [Tag] =
+        &[Tag::new_primitive_universal(DURATION_TAG_NUMBER)];
+
+    fn tag() -> Tag {
+        return Tag::new_primitive_universal(DURATION_TAG_NUMBER);
+    }
+
+    fn build_value(&self) -> Vec<u8> {
+        let duration = self.abs();
+        let seconds = duration.num_seconds();
+        let nanoseconds = duration.subsec_nanos();
+
+        let days = seconds / 86400;
+        let hours = (seconds % 86400) / 3600;
+        let minutes = (seconds % 3600) / 60;
+        let seconds = seconds % 60;
+
+        let mut formatted_string = "P".to_string();
+        if days != 0 {
+            formatted_string += &format!("{}D", days);
+        }
+
+        if hours != 0 || minutes != 0 || seconds != 0 || nanoseconds != 0 {
+            formatted_string += "T";
+            if hours != 0 {
+                formatted_string += &format!("{}H", hours);
+            }
+            if minutes != 0 {
+                formatted_string += &format!("{}M", minutes);
+            }
+            if seconds != 0 || nanoseconds != 0 {
+                formatted_string += &seconds.to_string();
+                if nanoseconds != 0 {
+                    let fraction = format!("{:09}", nanoseconds);
+                    formatted_string += ".";
+                    formatted_string += fraction.trim_end_matches('0');
+                }
+                formatted_string += "S";
+            }
+        } else if days == 0 {
+            formatted_string += "T0S";
+        }
+
+        return formatted_string.into_bytes();
+    }
+
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
+        let value = str::from_utf8(raw)?;
+        if value.len() < 3 || !value.starts_with('P') {
+            return Err(asn1err::Error::IncorrectValue(
+                "Invalid format for Duration".to_string(),
+            ))?;
+        }
+
+        let mut duration = TimeDelta::zero();
+        let mut is_time = false;
+        let mut number = String::new();
+        let mut last_position = None;
+
+        for ch in value[1..].chars() {
+            match ch {
+                '0'..='9' | '.' | ',' => number.push(ch),
+                'T' if !is_time && number.is_empty() => is_time = true,
+                _ if number.is_empty() => {
+                    return Err(asn1err::Error::IncorrectValue(
+                        "Invalid format for Duration".to_string(),
+                    ))?;
+                }
+                designator => {
+                    let component =
+                        Self::parse_component(&number, designator, is_time)?;
+                    let position =
+                        Self::designator_position(designator, is_time);
+                    if last_position >= Some(position) {
+                        return Err(asn1err::Error::IncorrectValue(format!(
+                            "Unordered designator for Duration: {}",
+                            designator
+                        )))?;
+                    }
+                    last_position = Some(position);
+                    duration =
+                        duration.checked_add(&component).ok_or_else(|| {
+                            asn1err::Error::IncorrectValue(
+                                "Too big Duration".to_string(),
+                            )
+                        })?;
+                    number.clear();
+                }
+            }
+        }
+
+        if !number.is_empty() || value.ends_with('T') {
+            return Err(asn1err::Error::IncorrectValue(
+                "Invalid format for Duration".to_string(),
+            ))?;
+        }
+
+        return Ok(Self { duration });
+    }
+}
+
+impl From<TimeDelta> for Duration {
+    fn from(duration: TimeDelta) -> Self {
+        return Self { duration };
+    }
+}
+
+impl From<Duration> for TimeDelta {
+    fn from(duration: Duration) -> Self {
+        return duration.duration;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _build_value(duration: TimeDelta) -> String {
+        return String::from_utf8(Duration::from(duration).build_value())
+            .unwrap();
+    }
+
+    fn _parse_value(value: &str) -> TimeDelta {
+        let mut duration = Duration::default();
+        duration.parse_value(value.as_bytes()).unwrap();
+        return duration.duration;
+    }
+
+    #[test]
+    fn test_build() {
+        assert_eq!(
+            vec![0x1f, 0x22, 0x06, 0x50, 0x31, 0x44, 0x54, 0x32, 0x48],
+            Duration::from(TimeDelta::try_hours(26).unwrap()).build()
+        );
+    }
+
+    #[test]
+    fn test_build_value() {
+        assert_eq!("PT0S", _build_value(TimeDelta::zero()));
+        assert_eq!("P2D", _build_value(TimeDelta::try_days(2).unwrap()));
+        assert_eq!("PT1M", _build_value(TimeDelta::try_minutes(1).unwrap()));
+        assert_eq!(
+            "P1DT2H3M4.5S",
+            _build_value(TimeDelta::new(93784, 500000000).unwrap())
+        );
+        assert_eq!("PT0.000000001S", _build_value(TimeDelta::nanoseconds(1)));
+        assert_eq!("PT30S", _build_value(TimeDelta::try_seconds(-30).unwrap()));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            TimeDelta::try_hours(26).unwrap(),
+            Duration::parse(&[
+                0x1f, 0x22, 0x06, 0x50, 0x31, 0x44, 0x54, 0x32, 0x48
+            ])
+            .unwrap()
+            .1
+            .duration
+        );
+    }
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(TimeDelta::zero(), _parse_value("PT0S"));
+        assert_eq!(TimeDelta::try_weeks(3).unwrap(), _parse_value("P3W"));
+        assert_eq!(
+            TimeDelta::new(93784, 500000000).unwrap(),
+            _parse_value("P1DT2H3M4.5S")
+        );
+        assert_eq!(
+            TimeDelta::new(4, 250000000).unwrap(),
+            _parse_value("PT4,25S")
+        );
+        assert_eq!(TimeDelta::try_hours(36).unwrap(), _parse_value("PT36H"));
+    }
+
+    #[should_panic(expected = "Years and months in Duration are not supported")]
+    #[test]
+    fn test_parse_years() {
+        _parse_value("P1Y");
+    }
+
+    #[should_panic(expected = "Years and months in Duration are not supported")]
+    #[test]
+    fn test_parse_months() {
+        _parse_value("P1M");
+    }
+
+    #[should_panic(
+        expected = "IncorrectValue(\"Invalid format for Duration\")"
+    )]
+    #[test]
+    fn test_parse_without_designator() {
+        _parse_value("PT12");
+    }
+
+    #[should_panic(
+        expected = "IncorrectValue(\"Invalid format for Duration\")"
+    )]
+    #[test]
+    fn test_parse_empty_time() {
+        _parse_value("P1DT");
+    }
+
+    #[should_panic(
+        expected = "IncorrectValue(\"Invalid format for Duration\")"
+    )]
+    #[test]
+    fn test_parse_without_p() {
+        _parse_value("1DT2H");
+    }
+
+    #[should_panic(
+        expected = "IncorrectValue(\"Invalid designator for Duration: S\")"
+    )]
+    #[test]
+    fn test_parse_seconds_in_date() {
+        _parse_value("P1S");
+    }
+
+    #[should_panic(
+        expected = "IncorrectValue(\"Unordered designator for Duration: H\")"
+    )]
+    #[test]
+    fn test_parse_unordered_designators() {
+        _parse_value("PT1S1H");
+    }
+
+    #[should_panic(
+        expected = "IncorrectValue(\"Unordered designator for Duration: H\")"
+    )]
+    #[test]
+    fn test_parse_repeated_designator() {
+        _parse_value("PT1H1H");
+    }
+
+    #[should_panic(
+        expected = "IncorrectValue(\"Unordered designator for Duration: W\")"
+    )]
+    #[test]
+    fn test_parse_unordered_date_designators() {
+        _parse_value("P1D1W");
+    }
+}
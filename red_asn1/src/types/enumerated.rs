@@ -4,7 +4,7 @@ use crate::tag::Tag;
 use crate::traits::Asn1Object;
 use std::ops::{Deref, DerefMut};
 
-pub static ENUMERATED_TAG_NUMBER: u8 = 0x0a;
+pub const ENUMERATED_TAG_NUMBER: u8 = 0x0a;
 
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct Enumerated<T: Asn1Int>(T);
@@ -29,6 +29,9 @@ impl<T: Asn1Int> DerefMut for Enumerated<T> {
 }
 
 impl<T: Asn1Int> Asn1Object for Enumerated<T> {
+    const TAGS: &'static [Tag] =
+        &[Tag::new_primitive_universal(ENUMERATED_TAG_NUMBER)];
+
     fn tag() -> Tag {
         return Tag::new_primitive_universal(ENUMERATED_TAG_NUMBER);
     }
@@ -37,9 +40,8 @@ impl<T: Asn1Int> Asn1Object for Enumerated<T> {
         return self.0.build_int_value();
     }
 
-    fn parse_value(&mut self, raw: &[u8]) -> asn1err::Result<()> {
-        self.0 = T::parse_int_value(raw)?;
-        return Ok(());
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
+        return Ok(Self(T::parse_int_value(raw)?));
     }
 }
 
@@ -49,21 +51,21 @@ mod tests {
 
     #[test]
     fn test_build() {
-        assert_eq!(vec![0xa, 0x1, 0x0], Enumerated::new(0 as u32).build());
-        assert_eq!(vec![0xa, 0x1, 0x1], Enumerated::new(1 as u32).build());
+        assert_eq!(vec![0xa, 0x1, 0x0], Enumerated::new(0_u32).build());
+        assert_eq!(vec![0xa, 0x1, 0x1], Enumerated::new(1_u32).build());
         assert_eq!(
             vec![0xa, 0x1, 0xff],
             Enumerated::new((-1i32) as u32).build()
         );
 
-        assert_eq!(vec![0xa, 0x1, 0x7F], Enumerated::new(127 as u32).build());
+        assert_eq!(vec![0xa, 0x1, 0x7F], Enumerated::new(127_u32).build());
         assert_eq!(
             vec![0xa, 0x2, 0x00, 0x80],
-            Enumerated::new(128 as u32).build()
+            Enumerated::new(128_u32).build()
         );
         assert_eq!(
             vec![0xa, 0x2, 0x01, 0x00],
-            Enumerated::new(256 as u32).build()
+            Enumerated::new(256_u32).build()
         );
         assert_eq!(
             vec![0xa, 0x1, 0x80],
@@ -76,18 +78,18 @@ mod tests {
 
         assert_eq!(
             vec![0xa, 0x4, 0xF8, 0x45, 0x33, 0x8],
-            Enumerated::new(4165284616 as u32).build()
+            Enumerated::new(4165284616_u32).build()
         );
     }
 
     #[test]
     fn test_parse() {
         assert_eq!(
-            Enumerated::new(0 as u32),
+            Enumerated::new(0_u32),
             Enumerated::<u32>::parse(&[0xa, 0x1, 0x0]).unwrap().1
         );
         assert_eq!(
-            Enumerated::new(1 as u32),
+            Enumerated::new(1_u32),
             Enumerated::<u32>::parse(&[0xa, 0x1, 0x1]).unwrap().1
         );
         assert_eq!(
@@ -96,7 +98,7 @@ mod tests {
         );
 
         assert_eq!(
-            Enumerated::new(127 as u32),
+            Enumerated::new(127_u32),
             Enumerated::<u32>::parse(&[0xa, 0x1, 0x7F]).unwrap().1
         );
         assert_eq!(
@@ -5,7 +5,7 @@ use chrono::prelude::*;
 use std::ops::{Deref, DerefMut};
 use std::str;
 
-pub static GENERALIZED_TIME_TAG_NUMBER: u8 = 0x18;
+pub const GENERALIZED_TIME_TAG_NUMBER: u8 = 0x18;
 
 /// Class to build/parse GeneralizedTime ASN1
 
@@ -28,6 +28,9 @@ impl DerefMut for GeneralizedTime {
 }
 
 impl Asn1Object for GeneralizedTime {
+    const TAGS: &'static [Tag] =
+        &[Tag::new_primitive_universal(GENERALIZED_TIME_TAG_NUMBER)];
+
     fn tag() -> Tag {
         return Tag::new_primitive_universal(GENERALIZED_TIME_TAG_NUMBER);
     }
@@ -52,11 +55,11 @@ impl Asn1Object for GeneralizedTime {
         return formatted_string.into_bytes();
     }
 
-    fn parse_value(&mut self, raw: &[u8]) -> asn1err::Result<()> {
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
         if raw.len() < 15 {
-            return Err(asn1err::Error::IncorrectValue(format!(
-                "No octects for GeneralizedTime"
-            )))?;
+            return Err(asn1err::Error::IncorrectValue(
+                "No octects for GeneralizedTime".to_string(),
+            ))?;
         }
 
         let year_str = str::from_utf8(&raw[0..4])?;
@@ -79,29 +82,38 @@ impl Asn1Object for GeneralizedTime {
             decisecond = decisecond_str.parse()?;
         }
 
-        let is_utc: bool = raw[raw.len() - 1] == 'Z' as u8;
+        let is_utc: bool = raw[raw.len() - 1] == b'Z';
 
         if is_utc {
-            self.time = Utc.ymd(year, month, day).and_hms_nano(
-                hour,
-                minute,
-                second,
-                decisecond * 100000000,
-            );
+            let time = NaiveDate::from_ymd_opt(year, month, day)
+                .and_then(|date| {
+                    date.and_hms_nano_opt(
+                        hour,
+                        minute,
+                        second,
+                        decisecond * 100000000,
+                    )
+                })
+                .ok_or_else(|| {
+                    asn1err::Error::IncorrectValue(
+                        "Invalid date for GeneralizedTime".to_string(),
+                    )
+                })?;
+            return Ok(Self {
+                time: Utc.from_utc_datetime(&time),
+            });
         } else {
             return Err(asn1err::Error::ImplementationError(
                 "Local time parse is not implemented yet".to_string(),
             ))?;
         }
-
-        return Ok(());
     }
 }
 
 impl Default for GeneralizedTime {
     fn default() -> Self {
         return Self {
-            time: Utc.timestamp(0, 0),
+            time: DateTime::UNIX_EPOCH,
         };
     }
 }
@@ -113,6 +125,7 @@ impl From<DateTime<Utc>> for GeneralizedTime {
 }
 
 #[cfg(test)]
+#[allow(deprecated)]
 mod tests {
     use super::*;
 
@@ -2,12 +2,15 @@ use crate::error as asn1err;
 use crate::tag::Tag;
 use crate::traits::Asn1Object;
 
-pub static GENERALSTRING_TAG_NUMBER: u8 = 0x1b;
+pub const GENERALSTRING_TAG_NUMBER: u8 = 0x1b;
 
 /// Class to build/parse GeneralString ASN1
 pub type GeneralString = String;
 
 impl Asn1Object for String {
+    const TAGS: &'static [Tag] =
+        &[Tag::new_primitive_universal(GENERALSTRING_TAG_NUMBER)];
+
     fn tag() -> Tag {
         return Tag::new_primitive_universal(GENERALSTRING_TAG_NUMBER);
     }
@@ -16,9 +19,8 @@ impl Asn1Object for String {
         return self.as_bytes().to_vec();
     }
 
-    fn parse_value(&mut self, raw: &[u8]) -> asn1err::Result<()> {
-        *self = String::from_utf8(raw.to_vec())?;
-        return Ok(());
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
+        return Ok(String::from_utf8(raw.to_vec())?);
     }
 }
 
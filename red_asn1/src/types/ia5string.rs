@@ -3,12 +3,15 @@ use crate::tag::Tag;
 use crate::traits::Asn1Object;
 use ascii::{AsciiChar, AsciiString};
 
-pub static IA5STRING_TAG_NUMBER: u8 = 0x16;
+pub const IA5STRING_TAG_NUMBER: u8 = 0x16;
 
 /// Class to build/parse IA5String ASN1
 pub type IA5String = AsciiString;
 
 impl Asn1Object for AsciiString {
+    const TAGS: &'static [Tag] =
+        &[Tag::new_primitive_universal(IA5STRING_TAG_NUMBER)];
+
     fn tag() -> Tag {
         return Tag::new_primitive_universal(IA5STRING_TAG_NUMBER);
     }
@@ -23,16 +26,14 @@ impl Asn1Object for AsciiString {
         return encoded_value;
     }
 
-    fn parse_value(&mut self, raw: &[u8]) -> asn1err::Result<()> {
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
         let mut value = AsciiString::with_capacity(raw.len());
 
         for byte in raw.iter() {
             value.push(AsciiChar::from_ascii(*byte)?);
         }
 
-        *self = value;
-
-        return Ok(());
+        return Ok(value);
     }
 }
 
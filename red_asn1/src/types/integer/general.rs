@@ -4,7 +4,7 @@ pub fn parse_integer_value(
     raw: &[u8],
     max_length: usize,
 ) -> asn1err::Result<i128> {
-    if raw.len() == 0 {
+    if raw.is_empty() {
         return Err(asn1err::Error::IncorrectValue(format!(
             "No octets for i{}",
             max_length * 8
@@ -20,7 +20,7 @@ pub fn parse_integer_value(
     }
 
     let signed_bit = (raw[0] & 0x80) >> 7;
-    let mut value = (signed_bit as i128) * -1;
+    let mut value = -(signed_bit as i128);
 
     for byte in raw.iter() {
         value <<= 8;
@@ -20,73 +20,49 @@ mod tests {
 
     #[test]
     fn test_build() {
-        assert_eq!(vec![0x2, 0x1, 0x0], i32::from(0).build());
-        assert_eq!(vec![0x2, 0x1, 0x1], i32::from(1).build());
-        assert_eq!(vec![0x2, 0x1, 0xff], i32::from(-1).build());
+        assert_eq!(vec![0x2, 0x1, 0x0], 0i32.build());
+        assert_eq!(vec![0x2, 0x1, 0x1], 1i32.build());
+        assert_eq!(vec![0x2, 0x1, 0xff], (-1i32).build());
 
-        assert_eq!(vec![0x2, 0x1, 0x7F], i32::from(127).build());
-        assert_eq!(vec![0x2, 0x2, 0x00, 0x80], i32::from(128).build());
-        assert_eq!(vec![0x2, 0x2, 0x01, 0x00], i32::from(256).build());
-        assert_eq!(vec![0x2, 0x1, 0x80], i32::from(-128).build());
-        assert_eq!(vec![0x2, 0x2, 0xFF, 0x7F], i32::from(-129).build());
+        assert_eq!(vec![0x2, 0x1, 0x7F], 127i32.build());
+        assert_eq!(vec![0x2, 0x2, 0x00, 0x80], 128i32.build());
+        assert_eq!(vec![0x2, 0x2, 0x01, 0x00], 256i32.build());
+        assert_eq!(vec![0x2, 0x1, 0x80], (-128i32).build());
+        assert_eq!(vec![0x2, 0x2, 0xFF, 0x7F], (-129i32).build());
     }
 
     #[test]
     fn test_parse() {
-        assert_eq!(i32::from(0), i32::parse(&[0x2, 0x1, 0x0]).unwrap().1);
-        assert_eq!(i32::from(1), i32::parse(&[0x2, 0x1, 0x1]).unwrap().1);
-        assert_eq!(i32::from(-1), i32::parse(&[0x2, 0x1, 0xff]).unwrap().1);
+        assert_eq!(0, i32::parse(&[0x2, 0x1, 0x0]).unwrap().1);
+        assert_eq!(1, i32::parse(&[0x2, 0x1, 0x1]).unwrap().1);
+        assert_eq!(-1, i32::parse(&[0x2, 0x1, 0xff]).unwrap().1);
 
-        assert_eq!(i32::from(127), i32::parse(&[0x2, 0x1, 0x7F]).unwrap().1);
-        assert_eq!(
-            i32::from(128),
-            i32::parse(&[0x2, 0x2, 0x00, 0x80]).unwrap().1
-        );
-        assert_eq!(
-            i32::from(256),
-            i32::parse(&[0x2, 0x2, 0x01, 0x00]).unwrap().1
-        );
-        assert_eq!(i32::from(-128), i32::parse(&[0x2, 0x1, 0x80]).unwrap().1);
-        assert_eq!(
-            i32::from(-129),
-            i32::parse(&[0x2, 0x2, 0xFF, 0x7F]).unwrap().1
-        );
+        assert_eq!(127, i32::parse(&[0x2, 0x1, 0x7F]).unwrap().1);
+        assert_eq!(128, i32::parse(&[0x2, 0x2, 0x00, 0x80]).unwrap().1);
+        assert_eq!(256, i32::parse(&[0x2, 0x2, 0x01, 0x00]).unwrap().1);
+        assert_eq!(-128, i32::parse(&[0x2, 0x1, 0x80]).unwrap().1);
+        assert_eq!(-129, i32::parse(&[0x2, 0x2, 0xFF, 0x7F]).unwrap().1);
     }
 
     #[test]
     fn test_parse_with_excesive_bytes() {
         let x: &[u8] = &[0x22];
-        assert_eq!(
-            (x, i32::from(0)),
-            i32::parse(&[0x2, 0x1, 0x0, 0x22]).unwrap()
-        );
-        assert_eq!(
-            (x, i32::from(1)),
-            i32::parse(&[0x2, 0x1, 0x1, 0x22]).unwrap()
-        );
-        assert_eq!(
-            (x, i32::from(-1)),
-            i32::parse(&[0x2, 0x1, 0xff, 0x22]).unwrap()
-        );
+        assert_eq!((x, 0), i32::parse(&[0x2, 0x1, 0x0, 0x22]).unwrap());
+        assert_eq!((x, 1), i32::parse(&[0x2, 0x1, 0x1, 0x22]).unwrap());
+        assert_eq!((x, -1), i32::parse(&[0x2, 0x1, 0xff, 0x22]).unwrap());
 
+        assert_eq!((x, 127), i32::parse(&[0x2, 0x1, 0x7F, 0x22]).unwrap());
         assert_eq!(
-            (x, i32::from(127)),
-            i32::parse(&[0x2, 0x1, 0x7F, 0x22]).unwrap()
-        );
-        assert_eq!(
-            (x, i32::from(128)),
+            (x, 128),
             i32::parse(&[0x2, 0x2, 0x00, 0x80, 0x22]).unwrap()
         );
         assert_eq!(
-            (x, i32::from(256)),
+            (x, 256),
             i32::parse(&[0x2, 0x2, 0x01, 0x00, 0x22]).unwrap()
         );
+        assert_eq!((x, -128), i32::parse(&[0x2, 0x1, 0x80, 0x22]).unwrap());
         assert_eq!(
-            (x, i32::from(-128)),
-            i32::parse(&[0x2, 0x1, 0x80, 0x22]).unwrap()
-        );
-        assert_eq!(
-            (x, i32::from(-129)),
+            (x, -129),
             i32::parse(&[0x2, 0x2, 0xFF, 0x7F, 0x22]).unwrap()
         );
     }
@@ -32,11 +32,11 @@ mod tests {
 
         assert_eq!(
             vec![0x2, 0x5, 0x00, 0xF8, 0x45, 0x33, 0x8],
-            i64::from(4165284616i64).build()
+            4165284616i64.build()
         );
         assert_eq!(
             vec![0x2, 0x5, 0xFF, 0x3A, 0xAC, 0x53, 0xDB],
-            i64::from(-3310595109i64).build()
+            (-3310595109i64).build()
         );
     }
 
@@ -62,13 +62,13 @@ mod tests {
         );
 
         assert_eq!(
-            i64::from(4165284616i64),
+            4165284616i64,
             i64::parse(&[0x2, 0x5, 0x00, 0xF8, 0x45, 0x33, 0x8])
                 .unwrap()
                 .1
         );
         assert_eq!(
-            i64::from(-3310595109i64),
+            -3310595109i64,
             i64::parse(&[0x2, 0x5, 0xFF, 0x3A, 0xAC, 0x53, 0xDB])
                 .unwrap()
                 .1
@@ -113,12 +113,12 @@ mod tests {
         );
 
         assert_eq!(
-            (x, i64::from(4165284616i64)),
+            (x, 4165284616i64),
             i64::parse(&[0x2, 0x5, 0x00, 0xF8, 0x45, 0x33, 0x8, 0x22])
                 .unwrap()
         );
         assert_eq!(
-            (x, i64::from(-3310595109i64)),
+            (x, -3310595109i64),
             i64::parse(&[0x2, 0x5, 0xFF, 0x3A, 0xAC, 0x53, 0xDB, 0x22])
                 .unwrap()
         );
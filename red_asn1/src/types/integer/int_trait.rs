@@ -11,6 +11,9 @@ pub trait Asn1Int: Sized + Default {
 
 
 impl<T: Asn1Int> Asn1Object for T {
+    const TAGS: &'static [Tag] =
+        &[Tag::new_primitive_universal(INTEGER_TAG_NUMBER)];
+
     fn tag() -> Tag {
         return Tag::new_primitive_universal(INTEGER_TAG_NUMBER);
     }
@@ -19,8 +22,7 @@ impl<T: Asn1Int> Asn1Object for T {
         return self.build_int_value();
     }
 
-    fn parse_value(&mut self, raw: &[u8]) -> asn1err::Result<()> {
-        *self = Self::parse_int_value(raw)?;
-        return Ok(());
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
+        return Self::parse_int_value(raw);
     }
 }
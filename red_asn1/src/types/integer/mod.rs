@@ -6,7 +6,7 @@ mod int64;
 mod int_trait;
 mod uint32;
 
-pub static INTEGER_TAG_NUMBER: u8 = 0x2;
+pub const INTEGER_TAG_NUMBER: u8 = 0x2;
 /// Class to build/parse Integer ASN1
 pub type Integer = i128;
 
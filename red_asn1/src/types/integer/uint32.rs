@@ -19,35 +19,35 @@ mod tests {
 
     #[test]
     fn test_build() {
-        assert_eq!(vec![0x2, 0x1, 0x0], (0 as u32).build());
-        assert_eq!(vec![0x2, 0x1, 0x1], (1 as u32).build());
+        assert_eq!(vec![0x2, 0x1, 0x0], 0_u32.build());
+        assert_eq!(vec![0x2, 0x1, 0x1], 1_u32.build());
         assert_eq!(vec![0x2, 0x1, 0xff], ((-1i32) as u32).build());
 
-        assert_eq!(vec![0x2, 0x1, 0x7F], (127 as u32).build());
-        assert_eq!(vec![0x2, 0x2, 0x00, 0x80], (128 as u32).build());
-        assert_eq!(vec![0x2, 0x2, 0x01, 0x00], (256 as u32).build());
+        assert_eq!(vec![0x2, 0x1, 0x7F], 127_u32.build());
+        assert_eq!(vec![0x2, 0x2, 0x00, 0x80], 128_u32.build());
+        assert_eq!(vec![0x2, 0x2, 0x01, 0x00], 256_u32.build());
         assert_eq!(vec![0x2, 0x1, 0x80], (-128i32 as u32).build());
         assert_eq!(vec![0x2, 0x2, 0xFF, 0x7F], (-129i32 as u32).build());
 
         assert_eq!(
             vec![0x2, 0x4, 0xF8, 0x45, 0x33, 0x8],
-            (4165284616 as u32).build()
+            4165284616_u32.build()
         );
     }
 
     #[test]
     fn test_parse() {
-        assert_eq!((0 as u32), u32::parse(&[0x2, 0x1, 0x0]).unwrap().1);
-        assert_eq!((1 as u32), u32::parse(&[0x2, 0x1, 0x1]).unwrap().1);
+        assert_eq!(0_u32, u32::parse(&[0x2, 0x1, 0x0]).unwrap().1);
+        assert_eq!(1_u32, u32::parse(&[0x2, 0x1, 0x1]).unwrap().1);
         assert_eq!((-1i32 as u32), u32::parse(&[0x2, 0x1, 0xff]).unwrap().1);
 
-        assert_eq!((127 as u32), u32::parse(&[0x2, 0x1, 0x7F]).unwrap().1);
+        assert_eq!(127_u32, u32::parse(&[0x2, 0x1, 0x7F]).unwrap().1);
         assert_eq!(
-            u32::from(128u32),
+            128u32,
             u32::parse(&[0x2, 0x2, 0x00, 0x80]).unwrap().1
         );
         assert_eq!(
-            u32::from(256u32),
+            256u32,
             u32::parse(&[0x2, 0x2, 0x01, 0x00]).unwrap().1
         );
         assert_eq!((-128i32 as u32), u32::parse(&[0x2, 0x1, 0x80]).unwrap().1);
@@ -1,9 +1,24 @@
+mod any;
+pub use any::Any;
+
 mod bitstring;
 pub use bitstring::*;
 
 mod boolean;
 pub use boolean::*;
 
+mod containing;
+pub use containing::{BitStringContaining, Containing};
+
+mod date;
+pub use date::{Date, DATE_TAG_NUMBER};
+
+mod datetime;
+pub use datetime::{DateTime, DATE_TIME_TAG_NUMBER};
+
+mod duration;
+pub use duration::{Duration, DURATION_TAG_NUMBER};
+
 mod generalizedtime;
 pub use generalizedtime::{GeneralizedTime, GENERALIZED_TIME_TAG_NUMBER};
 
@@ -25,12 +40,23 @@ pub use octetstring::*;
 mod oid;
 pub use oid::Oid;
 
+mod raw;
+pub use raw::Raw;
+
 mod sequenceof;
 pub use sequenceof::*;
 
 mod optional;
 pub use optional::Optional;
 
+mod tagged;
+pub use tagged::{
+    Application, Asn1TagClass, Context, Explicit, Implicit, Private, Universal,
+};
+
+mod timeofday;
+pub use timeofday::{TimeOfDay, TIME_OF_DAY_TAG_NUMBER};
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -46,5 +72,21 @@ mod tests {
         assert_eq!(vec![0x30], SequenceOf::<Integer>::tag().build());
         assert_eq!(vec![0x16], IA5String::tag().build());
         assert_eq!(vec![0x18], GeneralizedTime::tag().build());
+        assert_eq!(vec![0x1f, 0x1f], Date::tag().build());
+        assert_eq!(vec![0x1f, 0x20], TimeOfDay::tag().build());
+        assert_eq!(vec![0x1f, 0x21], DateTime::tag().build());
+        assert_eq!(vec![0x1f, 0x22], Duration::tag().build());
+    }
+
+    #[test]
+    fn test_tags_constants() {
+        assert_eq!(&[Boolean::tag()], Boolean::TAGS);
+        assert_eq!(&[Integer::tag()], Integer::TAGS);
+        assert_eq!(&[OctetString::tag()], OctetString::TAGS);
+        assert_eq!(&[SequenceOf::<Integer>::tag()], SequenceOf::<Integer>::TAGS);
+        assert_eq!(&[GeneralString::tag()], Optional::<GeneralString>::TAGS);
+        assert_eq!(&[Oid::tag()], Raw::<Oid>::TAGS);
+        assert_eq!(&[OctetString::tag()], Containing::<Integer>::TAGS);
+        assert_eq!(&[Duration::tag()], Duration::TAGS);
     }
 }
@@ -2,12 +2,15 @@ use crate::error as asn1err;
 use crate::tag::Tag;
 use crate::traits::Asn1Object;
 
-pub static OCTET_STRING_TAG_NUMBER: u8 = 0x4;
+pub const OCTET_STRING_TAG_NUMBER: u8 = 0x4;
 
 /// Class to build/parse OctetString ASN1
 pub type OctetString = Vec<u8>;
 
 impl Asn1Object for OctetString {
+    const TAGS: &'static [Tag] =
+        &[Tag::new_primitive_universal(OCTET_STRING_TAG_NUMBER)];
+
     fn tag() -> Tag {
         return Tag::new_primitive_universal(OCTET_STRING_TAG_NUMBER);
     }
@@ -16,9 +19,8 @@ impl Asn1Object for OctetString {
         return self.clone();
     }
 
-    fn parse_value(&mut self, raw: &[u8]) -> asn1err::Result<()> {
-        *self = raw.to_vec();
-        return Ok(());
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
+        return Ok(raw.to_vec());
     }
 }
 
@@ -6,7 +6,7 @@ use crate::error as asn1err;
 use crate::tag::Tag;
 use crate::traits::Asn1Object;
 
-pub static OID_TAG_NUMBER: u8 = 0x6;
+pub const OID_TAG_NUMBER: u8 = 0x6;
 
 /// Class to build/parse Object Identifiers
 #[derive(Debug, PartialEq, Default, Clone)]
@@ -27,6 +27,9 @@ impl Oid {
 }
 
 impl Asn1Object for Oid {
+    const TAGS: &'static [Tag] =
+        &[Tag::new_primitive_universal(OID_TAG_NUMBER)];
+
     fn tag() -> Tag {
         return Tag::new_primitive_universal(OID_TAG_NUMBER);
     }
@@ -41,12 +44,12 @@ impl Asn1Object for Oid {
 
             let octet = (value & 0x7f) as u8;
             value_octets.insert(0, octet);
-            value = value >> 7;
+            value >>= 7;
 
             while value > 0 {
                 let octet = (value & 0x7f) as u8 | 0x80;
                 value_octets.insert(0, octet);
-                value = value >> 7;
+                value >>= 7;
             }
 
             octets.extend(value_octets);
@@ -55,37 +58,43 @@ impl Asn1Object for Oid {
         return octets;
     }
 
-    fn parse_value(&mut self, raw: &[u8]) -> asn1err::Result<()> {
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
         let mut raw = raw.to_vec();
 
         let octet0 = if raw.is_empty() { 0 } else { raw.remove(0) };
 
-        self.value1 = match octet0 {
+        let value1 = match octet0 {
             0..=39 => 0,
             40..=79 => 1,
             _ => 2,
         };
-        self.value2 = octet0 - (self.value1 * 40u8);
+        let value2 = octet0 - (value1 * 40u8);
 
+        let mut values = Vec::new();
         let mut current_value = 0;
 
         for n in raw {
             let v = (n & 0x7f) as u64;
+            if current_value > (u64::MAX >> 7) {
+                return Err(asn1err::Error::ImplementationError(
+                    "OID component too large for u64".to_string(),
+                ))?;
+            }
             current_value = (current_value << 7) + v;
             if (n & 0x80) == 0 {
-                self.values.push(current_value);
+                values.push(current_value);
                 current_value = 0;
             }
         }
 
-        return Ok(());
+        return Ok(Self::new(value1, value2, values));
     }
 }
 
-impl Into<Vec<u64>> for Oid {
-    fn into(self) -> Vec<u64> {
-        let mut v = vec![self.value1 as u64, self.value2 as u64];
-        v.extend(self.values);
+impl From<Oid> for Vec<u64> {
+    fn from(oid: Oid) -> Self {
+        let mut v = vec![oid.value1 as u64, oid.value2 as u64];
+        v.extend(oid.values);
         return v;
     }
 }
@@ -193,6 +202,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_oid_with_too_large_component() {
+        assert_eq!(
+            Err(asn1err::Error::ImplementationError(
+                "OID component too large for u64".to_string()
+            )),
+            Oid::decode(&[
+                0x06, 0x0b, 0x2a, 0x82, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+                0x80, 0x80, 0x0
+            ])
+        );
+    }
+
     #[test]
     fn test_try_from_vu64() {
         assert_eq!(
@@ -1,7 +1,6 @@
 use crate::error as asn1err;
-use crate::length::{parse_length, build_length};
 use crate::tag::Tag;
-use crate::traits::Asn1Object;
+use crate::traits::{Asn1Object, Asn1OpenType};
 
 /// Class to build/parse SequenceOf ASN1
 pub type Optional<T> = Option<T>;
@@ -9,56 +8,81 @@ pub type Optional<T> = Option<T>;
 impl<T: Asn1Object> Asn1Object for Option<T> {
     fn build(&self) -> Vec<u8> {
         if let Some(value) = self {
-            let mut built = Self::tag().build();
-            let mut built_value = value.build_value();
-            let mut built_length = build_length(built_value.len());
-            built.append(&mut built_length);
-            built.append(&mut built_value);
-
-            return built;
+            return value.build();
         }
 
         return Vec::new();
     }
 
     fn parse(raw: &[u8]) -> asn1err::Result<(&[u8], Self)> {
-        let parsed_tag;
-        let raw_local;
         match Tag::parse(raw) {
             Err(_) => return Ok((raw, None)),
-            Ok((raw_tmp, tag)) => {
-                raw_local = raw_tmp;
-                parsed_tag = tag;
+            Ok((_, parsed_tag)) => {
+                if !T::matches_tag(&parsed_tag) {
+                    return Ok((raw, None));
+                }
             }
         }
 
-        if parsed_tag != Self::tag() {
-            return Ok((raw, None));
-        }
-
-        let (raw_local, length) = parse_length(raw_local)?;
-        if length > raw_local.len() {
-            return Err(asn1err::Error::NoDataForLength)?;
-        }
-
-        let (raw_value, raw_local) = raw_local.split_at(length);
-        let mut asn1obj = T::default();
-        asn1obj.parse_value(raw_value)?;
+        let (raw, asn1obj) = T::parse(raw)?;
+        return Ok((raw, Some(asn1obj)));
+    }
 
-        return Ok((raw_local, Some(asn1obj)));
+    fn decode_implicit(raw: &[u8], retagged: &[u8]) -> asn1err::Result<Self> {
+        return Ok(Some(T::decode_implicit(raw, retagged)?));
     }
 
+    const TAGS: &'static [Tag] = T::TAGS;
+    const CHOICE: bool = T::CHOICE;
+
     fn tag() -> Tag {
         return T::tag();
     }
 
+    fn matches_tag(tag: &Tag) -> bool {
+        return T::matches_tag(tag);
+    }
+
     fn build_value(&self) -> Vec<u8> {
         unimplemented!()
     }
 
-    fn parse_value(&mut self, _: &[u8]) -> asn1err::Result<()> {
+    fn decode_value(_: &[u8]) -> asn1err::Result<Self> {
         unimplemented!()
     }
+
+    fn validate(&self) -> asn1err::Result<()> {
+        if let Some(value) = self {
+            return value.validate();
+        }
+
+        return Ok(());
+    }
+}
+
+/// An optional open type is absent when there are no more data to parse
+impl<T: Asn1OpenType> Asn1OpenType for Option<T> {
+    type Discriminant = T::Discriminant;
+
+    fn build_open(&self) -> Vec<u8> {
+        if let Some(value) = self {
+            return value.build_open();
+        }
+
+        return Vec::new();
+    }
+
+    fn parse_open<'a>(
+        discriminant: &Self::Discriminant,
+        raw: &'a [u8],
+    ) -> asn1err::Result<(&'a [u8], Self)> {
+        if raw.is_empty() {
+            return Ok((raw, None));
+        }
+
+        let (raw, value) = T::parse_open(discriminant, raw)?;
+        return Ok((raw, Some(value)));
+    }
 }
 
 #[cfg(test)]
@@ -101,7 +125,6 @@ mod tests {
         );
     }
 
-
     #[test]
     fn test_parse_none_mismatch_tag() {
         let o: Option<Integer> = None;
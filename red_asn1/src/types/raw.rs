@@ -0,0 +1,215 @@
+use crate::error as asn1err;
+use crate::length::{build_length, parse_length};
+use crate::tag::Tag;
+use crate::traits::Asn1Object;
+use std::ops::Deref;
+
+/// Class to parse a value of type T keeping its original encoding.
+///
+/// The octets of the value (tag, length and value) are stored as they
+/// were parsed and returned unchanged by `build`, so they can be used
+/// to verify checksums or signatures. As modifying the value would not
+/// change the stored octets, the value can only be read.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Raw<T: Asn1Object> {
+    value: T,
+    raw: Vec<u8>,
+    value_start: usize,
+}
+
+impl<T: Asn1Object> Raw<T> {
+    /// Creates a new Raw from a value, with its DER encoding
+    pub fn new(value: T) -> Self {
+        let raw = value.build();
+        let value_start = raw.len() - value.build_value().len();
+        return Self {
+            value,
+            raw,
+            value_start,
+        };
+    }
+
+    /// Creates a Raw from a value and the octets it was parsed from,
+    /// which contain a tag and a length followed by the value
+    fn from_parsed(value: T, raw: &[u8]) -> asn1err::Result<Self> {
+        let (raw_length, _) = Tag::parse(raw)?;
+        let (raw_value, _) = parse_length(raw_length)?;
+
+        return Ok(Self {
+            value,
+            raw: raw.to_vec(),
+            value_start: raw.len() - raw_value.len(),
+        });
+    }
+
+    /// The octets of the value, as they were parsed
+    pub fn raw(&self) -> &[u8] {
+        return &self.raw;
+    }
+
+    pub fn into_inner(self) -> T {
+        return self.value;
+    }
+}
+
+impl<T: Asn1Object> Deref for Raw<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T: Asn1Object> Asn1Object for Raw<T> {
+    const TAGS: &'static [Tag] = T::TAGS;
+    const CHOICE: bool = T::CHOICE;
+
+    fn tag() -> Tag {
+        return T::tag();
+    }
+
+    fn build(&self) -> Vec<u8> {
+        return self.raw.clone();
+    }
+
+    fn parse(raw: &[u8]) -> asn1err::Result<(&[u8], Self)> {
+        let (rest, value) = T::parse(raw)?;
+        let (raw, _) = raw.split_at(raw.len() - rest.len());
+        return Ok((rest, Self::from_parsed(value, raw)?));
+    }
+
+    fn decode_implicit(raw: &[u8], retagged: &[u8]) -> asn1err::Result<Self> {
+        let value = T::decode_implicit(raw, retagged)?;
+        return Self::from_parsed(value, raw);
+    }
+
+    fn build_value(&self) -> Vec<u8> {
+        return self.raw[self.value_start..].to_vec();
+    }
+
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
+        let value = T::decode_value(raw)?;
+
+        // only the value octets were received, so they are kept after
+        // the tag and length of the type
+        let mut encoded = T::tag().build();
+        encoded.append(&mut build_length(raw.len()));
+        let value_start = encoded.len();
+        encoded.extend_from_slice(raw);
+
+        return Ok(Self {
+            value,
+            raw: encoded,
+            value_start,
+        });
+    }
+
+    fn validate(&self) -> asn1err::Result<()> {
+        return self.value.validate();
+    }
+}
+
+impl<T: Asn1Object + Default> Default for Raw<T> {
+    fn default() -> Self {
+        return Self::new(T::default());
+    }
+}
+
+impl<T: Asn1Object> From<T> for Raw<T> {
+    fn from(value: T) -> Self {
+        return Self::new(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tag::TagClass;
+    use crate::tagging::{build_implicit, parse_implicit};
+    use crate::types::{Integer, OctetString};
+
+    #[test]
+    fn test_new() {
+        let raw = Raw::new(Integer::from(9));
+        assert_eq!(9, *raw);
+        assert_eq!(&[0x2, 0x1, 0x9], raw.raw());
+        assert_eq!(vec![0x2, 0x1, 0x9], raw.build());
+    }
+
+    #[test]
+    fn test_parse_keeps_encoding() {
+        let x: &[u8] = &[0x22];
+        let encoded = [0x4, 0x81, 0x2, 0x1, 0x2];
+
+        let (rest, raw) =
+            Raw::<OctetString>::parse(&[0x4, 0x81, 0x2, 0x1, 0x2, 0x22])
+                .unwrap();
+
+        assert_eq!(x, rest);
+        assert_eq!(&vec![0x1, 0x2], &*raw);
+        assert_eq!(&encoded, raw.raw());
+        assert_eq!(encoded.to_vec(), raw.build());
+        assert_eq!(vec![0x1, 0x2], raw.build_value());
+    }
+
+    #[test]
+    fn test_build_optional() {
+        let raw = Some(
+            Raw::<OctetString>::parse(&[0x4, 0x81, 0x1, 0xff])
+                .unwrap()
+                .1,
+        );
+        assert_eq!(vec![0x4, 0x81, 0x1, 0xff], raw.build());
+    }
+
+    #[test]
+    fn test_parse_implicit_keeps_encoding() {
+        let encoded = [0x80, 0x81, 0x2, 0x1, 0x2];
+
+        let (_, raw) =
+            parse_implicit::<Raw<OctetString>>(&encoded, 0, TagClass::Context)
+                .unwrap();
+
+        assert_eq!(&vec![0x1, 0x2], &*raw);
+        assert_eq!(&encoded, raw.raw());
+        assert_eq!(vec![0x1, 0x2], raw.build_value());
+        assert_eq!(
+            encoded.to_vec(),
+            build_implicit(&raw, 0, TagClass::Context)
+        );
+    }
+
+    #[test]
+    fn test_parse_optional_implicit_keeps_encoding() {
+        let encoded = [0x81, 0x81, 0x1, 0x5];
+
+        let (_, raw) = parse_implicit::<Option<Raw<Integer>>>(
+            &encoded,
+            1,
+            TagClass::Context,
+        )
+        .unwrap();
+
+        assert_eq!(&encoded, raw.unwrap().raw());
+    }
+
+    #[test]
+    fn test_decode_value_keeps_encoding() {
+        let raw = Raw::<bool>::decode_value(&[0x1]).unwrap();
+
+        assert!(*raw);
+        assert_eq!(&[0x1, 0x1, 0x1], raw.raw());
+        assert_eq!(vec![0x1], raw.build_value());
+    }
+
+    #[test]
+    fn test_build_value_of_new() {
+        let raw = Raw::new(OctetString::from(vec![0x1, 0x2]));
+        assert_eq!(vec![0x1, 0x2], raw.build_value());
+    }
+
+    #[should_panic(expected = "UnmatchedTag")]
+    #[test]
+    fn test_parse_with_invalid_tag() {
+        Raw::<Integer>::parse(&[0x4, 0x1, 0x0]).unwrap();
+    }
+}
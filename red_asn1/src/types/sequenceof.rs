@@ -1,13 +1,17 @@
 use crate::error as asn1err;
+use crate::limits::check_elements;
 use crate::tag::Tag;
 use crate::traits::Asn1Object;
 
-pub static SEQUENCE_TAG_NUMBER: u8 = 0x10;
+pub const SEQUENCE_TAG_NUMBER: u8 = 0x10;
 
 /// Class to build/parse SequenceOf ASN1
 pub type SequenceOf<T> = Vec<T>;
 
 impl<T: Asn1Object> Asn1Object for Vec<T> {
+    const TAGS: &'static [Tag] =
+        &[Tag::new_constructed_universal(SEQUENCE_TAG_NUMBER)];
+
     fn tag() -> Tag {
         return Tag::new_constructed_universal(SEQUENCE_TAG_NUMBER);
     }
@@ -20,17 +24,27 @@ impl<T: Asn1Object> Asn1Object for Vec<T> {
         return value;
     }
 
-    fn parse_value(&mut self, raw: &[u8]) -> asn1err::Result<()> {
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
         let mut components: Vec<T> = Vec::new();
+        let raw_value = raw;
         let mut raw = raw;
 
         while !raw.is_empty() {
-            let (raw_tmp, component) = T::parse(raw)?;
+            check_elements(components.len() + 1)?;
+            let offset = raw_value.len() - raw.len();
+            let (raw_tmp, component) =
+                T::parse(raw).map_err(|error| error.shift(offset))?;
             raw = raw_tmp;
             components.push(component);
         }
 
-        *self = components;
+        return Ok(components);
+    }
+
+    fn validate(&self) -> asn1err::Result<()> {
+        for item in self.iter() {
+            item.validate()?;
+        }
         return Ok(());
     }
 }
@@ -42,9 +56,8 @@ mod tests {
 
     #[test]
     fn test_build_sequence_of_integers() {
-        let mut seq_of: SequenceOf<Integer> = SequenceOf::default();
-        seq_of.push(Integer::from(9));
-        seq_of.push(Integer::from(1000));
+        let seq_of: SequenceOf<Integer> =
+            vec![Integer::from(9), Integer::from(1000)];
 
         assert_eq!(
             vec![
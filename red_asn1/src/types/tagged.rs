@@ -0,0 +1,352 @@
+use crate::error as asn1err;
+use crate::tag::{Tag, TagClass, TagType};
+use crate::tagging::{
+    build_explicit, build_implicit, is_implicit_tag, parse_explicit,
+    parse_implicit,
+};
+use crate::traits::Asn1Object;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+
+/// Trait to select the class of the tag of Explicit and Implicit
+/// in their generic parameters
+pub trait Asn1TagClass {
+    const CLASS: TagClass;
+}
+
+/// Universal class, to be used in tagged types
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Universal;
+
+impl Asn1TagClass for Universal {
+    const CLASS: TagClass = TagClass::Universal;
+}
+
+/// Application class, to be used in tagged types
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Application;
+
+impl Asn1TagClass for Application {
+    const CLASS: TagClass = TagClass::Application;
+}
+
+/// Context class, to be used in tagged types
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Context;
+
+impl Asn1TagClass for Context {
+    const CLASS: TagClass = TagClass::Context;
+}
+
+/// Private class, to be used in tagged types
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Private;
+
+impl Asn1TagClass for Private {
+    const CLASS: TagClass = TagClass::Private;
+}
+
+/// Class to build/parse EXPLICIT tagged types ([CLASS N] EXPLICIT T),
+/// where a constructed tag wraps the value of type T. For example,
+/// `Explicit<Context, 0, Integer>` is `[0] EXPLICIT INTEGER`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Explicit<C: Asn1TagClass, const N: u32, T: Asn1Object> {
+    value: T,
+    class: PhantomData<C>,
+}
+
+impl<C: Asn1TagClass, const N: u32, T: Asn1Object> Explicit<C, N, T> {
+    pub fn new(value: T) -> Self {
+        return Self {
+            value,
+            class: PhantomData,
+        };
+    }
+
+    pub fn into_inner(self) -> T {
+        return self.value;
+    }
+}
+
+impl<C: Asn1TagClass, const N: u32, T: Asn1Object> Deref for Explicit<C, N, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<C: Asn1TagClass, const N: u32, T: Asn1Object> DerefMut
+    for Explicit<C, N, T>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<C: Asn1TagClass, const N: u32, T: Asn1Object> From<T>
+    for Explicit<C, N, T>
+{
+    fn from(value: T) -> Self {
+        return Self::new(value);
+    }
+}
+
+impl<C: Asn1TagClass, const N: u32, T: Asn1Object + Default> Default
+    for Explicit<C, N, T>
+{
+    fn default() -> Self {
+        return Self::new(T::default());
+    }
+}
+
+impl<C: Asn1TagClass, const N: u32, T: Asn1Object> Asn1Object
+    for Explicit<C, N, T>
+{
+    const TAGS: &'static [Tag] = &[Tag::new(N, TagType::Constructed, C::CLASS)];
+
+    fn tag() -> Tag {
+        return Tag::new(N, TagType::Constructed, C::CLASS);
+    }
+
+    fn build_value(&self) -> Vec<u8> {
+        return self.value.build();
+    }
+
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
+        return Ok(Self::new(T::decode(raw)?));
+    }
+
+    fn validate(&self) -> asn1err::Result<()> {
+        return self.value.validate();
+    }
+
+    fn build(&self) -> Vec<u8> {
+        return build_explicit(&self.value, N, C::CLASS);
+    }
+
+    fn parse(raw: &[u8]) -> asn1err::Result<(&[u8], Self)> {
+        let (raw, value) = parse_explicit(raw, N, C::CLASS)?;
+        return Ok((raw, Self::new(value)));
+    }
+}
+
+/// Class to build/parse IMPLICIT tagged types ([CLASS N] IMPLICIT T),
+/// where the tag replaces the tag of the value of type T. For example,
+/// `Implicit<Application, 1, OctetString>` is
+/// `[APPLICATION 1] IMPLICIT OCTET STRING`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Implicit<C: Asn1TagClass, const N: u32, T: Asn1Object> {
+    value: T,
+    class: PhantomData<C>,
+}
+
+impl<C: Asn1TagClass, const N: u32, T: Asn1Object> Implicit<C, N, T> {
+    pub fn new(value: T) -> Self {
+        return Self {
+            value,
+            class: PhantomData,
+        };
+    }
+
+    pub fn into_inner(self) -> T {
+        return self.value;
+    }
+}
+
+impl<C: Asn1TagClass, const N: u32, T: Asn1Object> Deref for Implicit<C, N, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<C: Asn1TagClass, const N: u32, T: Asn1Object> DerefMut
+    for Implicit<C, N, T>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<C: Asn1TagClass, const N: u32, T: Asn1Object> From<T>
+    for Implicit<C, N, T>
+{
+    fn from(value: T) -> Self {
+        return Self::new(value);
+    }
+}
+
+impl<C: Asn1TagClass, const N: u32, T: Asn1Object + Default> Default
+    for Implicit<C, N, T>
+{
+    fn default() -> Self {
+        return Self::new(T::default());
+    }
+}
+
+impl<C: Asn1TagClass, const N: u32, T: Asn1Object> Asn1Object
+    for Implicit<C, N, T>
+{
+    const TAGS: &'static [Tag] = match T::TAGS {
+        [inner_tag, ..] => &[Tag::new(N, inner_tag.r#type, C::CLASS)],
+        [] => &[],
+    };
+
+    /// Tag of the type, with the form (primitive or constructed) of T
+    fn tag() -> Tag {
+        return Tag::new(N, T::tag().r#type, C::CLASS);
+    }
+
+    fn matches_tag(tag: &Tag) -> bool {
+        return is_implicit_tag::<T>(tag, N, C::CLASS);
+    }
+
+    fn build_value(&self) -> Vec<u8> {
+        return self.value.build_value();
+    }
+
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
+        return Ok(Self::new(T::decode_value(raw)?));
+    }
+
+    fn validate(&self) -> asn1err::Result<()> {
+        return self.value.validate();
+    }
+
+    fn build(&self) -> Vec<u8> {
+        return build_implicit(&self.value, N, C::CLASS);
+    }
+
+    fn parse(raw: &[u8]) -> asn1err::Result<(&[u8], Self)> {
+        let (raw, value) = parse_implicit(raw, N, C::CLASS)?;
+        return Ok((raw, Self::new(value)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Integer, OctetString, SequenceOf};
+
+    #[test]
+    fn test_tags_of_tagged_types() {
+        assert_eq!(
+            &[Tag::new(1, TagType::Constructed, TagClass::Application)],
+            Explicit::<Application, 1, Integer>::TAGS
+        );
+        assert_eq!(
+            &[Tag::new(2, TagType::Primitive, TagClass::Context)],
+            Implicit::<Context, 2, Integer>::TAGS
+        );
+        assert_eq!(
+            &[Tag::new(3, TagType::Constructed, TagClass::Private)],
+            Implicit::<Private, 3, SequenceOf<Integer>>::TAGS
+        );
+    }
+
+    #[test]
+    fn test_build_explicit() {
+        assert_eq!(
+            vec![0xa0, 0x3, 0x2, 0x1, 0x5],
+            Explicit::<Context, 0, Integer>::new(5).build()
+        );
+        assert_eq!(
+            vec![0x61, 0x3, 0x4, 0x1, 0x1],
+            Explicit::<Application, 1, OctetString>::new(vec![0x1]).build()
+        );
+    }
+
+    #[test]
+    fn test_parse_explicit() {
+        let (raw, value) = Explicit::<Private, 2, Integer>::parse(&[
+            0xe2, 0x3, 0x2, 0x1, 0x5, 0x22,
+        ])
+        .unwrap();
+
+        assert_eq!(&[0x22], raw);
+        assert_eq!(5, *value);
+    }
+
+    #[should_panic(
+        expected = "UnmatchedTag { expected: Tag { number: 0, type: Constructed, class: Context }, found: Tag { number: 1, type: Constructed, class: Context } }"
+    )]
+    #[test]
+    fn test_parse_explicit_with_other_tag() {
+        Explicit::<Context, 0, Integer>::parse(&[0xa1, 0x3, 0x2, 0x1, 0x5])
+            .unwrap();
+    }
+
+    #[should_panic(expected = "NoAllDataConsumed")]
+    #[test]
+    fn test_parse_explicit_with_excesive_value() {
+        Explicit::<Context, 0, Integer>::parse(&[
+            0xa0, 0x4, 0x2, 0x1, 0x5, 0x0,
+        ])
+        .unwrap();
+    }
+
+    #[test]
+    fn test_build_implicit() {
+        assert_eq!(
+            vec![0x80, 0x1, 0x5],
+            Implicit::<Context, 0, Integer>::new(5).build()
+        );
+
+        let value: SequenceOf<Integer> = vec![1];
+        assert_eq!(
+            vec![0x61, 0x3, 0x2, 0x1, 0x1],
+            Implicit::<Application, 1, SequenceOf<Integer>>::new(value).build()
+        );
+    }
+
+    #[test]
+    fn test_parse_implicit() {
+        assert_eq!(
+            5,
+            *Implicit::<Context, 0, Integer>::parse(&[0x80, 0x1, 0x5])
+                .unwrap()
+                .1
+        );
+    }
+
+    #[should_panic(
+        expected = "UnmatchedTag { expected: Tag { number: 0, type: Primitive, class: Application }, found: Tag { number: 0, type: Primitive, class: Context } }"
+    )]
+    #[test]
+    fn test_parse_implicit_with_other_tag() {
+        Implicit::<Application, 0, Integer>::parse(&[0x80, 0x1, 0x5]).unwrap();
+    }
+
+    #[test]
+    fn test_build_and_parse_sequence_of_tagged() {
+        let values: SequenceOf<Explicit<Context, 0, Integer>> =
+            vec![Explicit::new(1), Explicit::new(2)];
+        let raw = vec![
+            0x30, 0xa, 0xa0, 0x3, 0x2, 0x1, 0x1, 0xa0, 0x3, 0x2, 0x1, 0x2,
+        ];
+
+        assert_eq!(raw, values.build());
+        assert_eq!(
+            values,
+            SequenceOf::<Explicit<Context, 0, Integer>>::parse(&raw)
+                .unwrap()
+                .1
+        );
+    }
+
+    #[test]
+    fn test_optional_implicit() {
+        assert_eq!(
+            None,
+            Option::<Implicit<Context, 1, Integer>>::parse(&[0x80, 0x1, 0x5])
+                .unwrap()
+                .1
+        );
+        assert_eq!(
+            Some(Implicit::new(5)),
+            Option::<Implicit<Context, 1, Integer>>::parse(&[0x81, 0x1, 0x5])
+                .unwrap()
+                .1
+        );
+    }
+}
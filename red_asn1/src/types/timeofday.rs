@@ -0,0 +1,146 @@
+use super::date::parse_digits;
+use crate::error as asn1err;
+use crate::tag::Tag;
+use crate::traits::Asn1Object;
+use chrono::prelude::*;
+use std::ops::{Deref, DerefMut};
+
+pub const TIME_OF_DAY_TAG_NUMBER: u8 = 0x20;
+
+/// Class to build/parse TIME-OF-DAY ASN1 (HHMMSS)
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct TimeOfDay {
+    pub time: NaiveTime,
+}
+
+impl Deref for TimeOfDay {
+    type Target = NaiveTime;
+    fn deref(&self) -> &Self::Target {
+        &self.time
+    }
+}
+
+impl DerefMut for TimeOfDay {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.time
+    }
+}
+
+impl Asn1Object for TimeOfDay {
+    const TAGS: &'static [Tag] =
+        &[Tag::new_primitive_universal(TIME_OF_DAY_TAG_NUMBER)];
+
+    fn tag() -> Tag {
+        return Tag::new_primitive_universal(TIME_OF_DAY_TAG_NUMBER);
+    }
+
+    fn build_value(&self) -> Vec<u8> {
+        return self.format("%H%M%S").to_string().into_bytes();
+    }
+
+    fn decode_value(raw: &[u8]) -> asn1err::Result<Self> {
+        if raw.len() != 6 {
+            return Err(asn1err::Error::IncorrectValue(format!(
+                "Invalid number of octets for TimeOfDay: {} octets",
+                raw.len()
+            )))?;
+        }
+
+        let hour = parse_digits(&raw[0..2])?;
+        let minute = parse_digits(&raw[2..4])?;
+        let second = parse_digits(&raw[4..6])?;
+
+        let time =
+            NaiveTime::from_hms_opt(hour, minute, second).ok_or_else(|| {
+                asn1err::Error::IncorrectValue(
+                    "Invalid time for TimeOfDay".to_string(),
+                )
+            })?;
+
+        return Ok(Self { time });
+    }
+}
+
+impl From<NaiveTime> for TimeOfDay {
+    fn from(time: NaiveTime) -> Self {
+        return Self { time };
+    }
+}
+
+impl From<TimeOfDay> for NaiveTime {
+    fn from(time: TimeOfDay) -> Self {
+        return time.time;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        assert_eq!(
+            vec![0x1f, 0x20, 0x06, 0x32, 0x31, 0x30, 0x36, 0x32, 0x37],
+            TimeOfDay::from(NaiveTime::from_hms_opt(21, 6, 27).unwrap())
+                .build()
+        );
+    }
+
+    #[test]
+    fn test_build_drops_fractions() {
+        assert_eq!(
+            vec![0x1f, 0x20, 0x06, 0x32, 0x31, 0x30, 0x36, 0x32, 0x37],
+            TimeOfDay::from(
+                NaiveTime::from_hms_milli_opt(21, 6, 27, 300).unwrap()
+            )
+            .build()
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            TimeOfDay::from(NaiveTime::from_hms_opt(21, 6, 27).unwrap()),
+            TimeOfDay::parse(&[
+                0x1f, 0x20, 0x06, 0x32, 0x31, 0x30, 0x36, 0x32, 0x37
+            ])
+            .unwrap()
+            .1
+        );
+    }
+
+    #[should_panic(
+        expected = "IncorrectValue(\"Invalid number of octets for TimeOfDay: 4 octets\")"
+    )]
+    #[test]
+    fn test_parse_without_enough_value_octets() {
+        TimeOfDay::parse(&[0x1f, 0x20, 0x04, 0x32, 0x31, 0x30, 0x36]).unwrap();
+    }
+
+    #[should_panic(expected = "IncorrectValue(\"Invalid time for TimeOfDay\")")]
+    #[test]
+    fn test_parse_invalid_time() {
+        TimeOfDay::parse(&[
+            0x1f, 0x20, 0x06, 0x32, 0x35, 0x30, 0x36, 0x32, 0x37,
+        ])
+        .unwrap();
+    }
+
+    #[should_panic(expected = "ParseIntError")]
+    #[test]
+    fn test_parse_with_no_number_characters() {
+        TimeOfDay::parse(&[
+            0x1f, 0x20, 0x06, 0x32, 0x31, 0x3a, 0x36, 0x32, 0x37,
+        ])
+        .unwrap();
+    }
+
+    #[should_panic(expected = "ParseIntError")]
+    #[test]
+    fn test_parse_signed_hour() {
+        TimeOfDay::parse(&[
+            0x1f, 0x20, 0x06, 0x2b, 0x31, 0x30, 0x36, 0x32, 0x37,
+        ])
+        .unwrap();
+    }
+}
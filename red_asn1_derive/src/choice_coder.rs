@@ -0,0 +1,291 @@
+use super::parse_definitions::ChoiceDefinition;
+use super::sequence_coder::code_crate_scope;
+use super::tag_coder::{
+    code_automatic_tag, code_distinct_tags_check, code_explicit_tag,
+    code_tag_class,
+};
+use proc_macro2::TokenStream;
+
+/// Function to write the code of the methods to build/parse a choice.
+/// The alternative is selected by the tag of the parsed value, so the
+/// build/parse methods of Asn1Object are replaced to handle the tag
+/// of each alternative.
+pub fn code_choice(choice: &ChoiceDefinition) -> TokenStream {
+    let name = &choice.name;
+    let name_str = name.to_string();
+
+    let mut build_arms = quote! {};
+    let mut build_value_arms = quote! {};
+    let mut matches_checks = quote! { false };
+    let mut parse_calls = quote! {};
+    let mut validate_arms = quote! {};
+    let mut alternatives_tags = Vec::new();
+
+    for alternative in &choice.alternatives {
+        let id = &alternative.id;
+        let id_str = id.to_string();
+        let kind = &alternative.kind;
+
+        // all the alternatives start at the tag of the choice
+        let map_alternative_error = quote! {
+            map_err(|error| {
+                __red_asn1::Error::ChoiceAlternativeError {
+                    choice: #name_str,
+                    alternative: #id_str,
+                    offset: 0,
+                    error: Box::new(error),
+                }
+            })
+        };
+
+        validate_arms = quote! {
+            #validate_arms
+            #name::#id(value) => __red_asn1::Asn1Object::validate(value)
+                .#map_alternative_error,
+        };
+
+        alternatives_tags.push(match &alternative.tag {
+            Some(tag) if alternative.automatic => {
+                let automatic_tag = code_automatic_tag(tag, kind);
+                quote! {&[#automatic_tag]}
+            }
+            Some(tag) => {
+                let explicit_tag = code_explicit_tag(tag);
+                quote! {&[#explicit_tag]}
+            }
+            None => quote! {<#kind as __red_asn1::Asn1Object>::TAGS},
+        });
+
+        match &alternative.tag {
+            // the automatic tag is explicit only for choices
+            Some(tag) if alternative.automatic => {
+                let number = tag.number;
+                let class = code_tag_class(tag.class);
+
+                build_arms = quote! {
+                    #build_arms
+                    #name::#id(value) => __red_asn1::build_automatic(
+                        value, #number, #class
+                    ),
+                };
+
+                build_value_arms = quote! {
+                    #build_value_arms
+                    #name::#id(value) => {
+                        match <#kind as __red_asn1::Asn1Object>::CHOICE {
+                            true => __red_asn1::Asn1Object::build(value),
+                            false => __red_asn1::Asn1Object::build_value(value),
+                        }
+                    }
+                };
+
+                matches_checks = quote! {
+                    #matches_checks
+                    || __red_asn1::is_automatic_tag::<#kind>(
+                        tag, #number, #class
+                    )
+                };
+
+                parse_calls = quote! {
+                    #parse_calls
+                    if __red_asn1::is_automatic_tag::<#kind>(
+                        &parsed_tag, #number, #class
+                    ) {
+                        let (raw, value) = __red_asn1::parse_automatic::<#kind>(
+                            raw, #number, #class
+                        ).#map_alternative_error?;
+                        return Ok((raw, #name::#id(value)));
+                    }
+                };
+            }
+            Some(tag) => {
+                let explicit_tag = code_explicit_tag(tag);
+                let number = tag.number;
+                let class = code_tag_class(tag.class);
+
+                build_arms = quote! {
+                    #build_arms
+                    #name::#id(value) => {
+                        let mut built_value = __red_asn1::Asn1Object::build(value);
+                        let mut built = #explicit_tag.build();
+                        built.append(&mut __red_asn1::build_length(built_value.len()));
+                        built.append(&mut built_value);
+                        built
+                    }
+                };
+
+                build_value_arms = quote! {
+                    #build_value_arms
+                    #name::#id(value) => __red_asn1::Asn1Object::build(value),
+                };
+
+                matches_checks = quote! {
+                    #matches_checks || *tag == #explicit_tag
+                };
+
+                parse_calls = quote! {
+                    #parse_calls
+                    if parsed_tag == #explicit_tag {
+                        let (raw, value) = __red_asn1::parse_explicit::<#kind>(
+                            raw, #number, #class
+                        ).#map_alternative_error?;
+                        return Ok((raw, #name::#id(value)));
+                    }
+                };
+            }
+            None => {
+                build_arms = quote! {
+                    #build_arms
+                    #name::#id(value) => __red_asn1::Asn1Object::build(value),
+                };
+
+                build_value_arms = quote! {
+                    #build_value_arms
+                    #name::#id(value) => {
+                        __red_asn1::Asn1Object::build_value(value)
+                    }
+                };
+
+                matches_checks = quote! {
+                    #matches_checks
+                    || <#kind as __red_asn1::Asn1Object>::matches_tag(tag)
+                };
+
+                parse_calls = quote! {
+                    #parse_calls
+                    if <#kind as __red_asn1::Asn1Object>::matches_tag(&parsed_tag) {
+                        let (raw, value) = <#kind as __red_asn1::Asn1Object>::parse(raw)
+                            .#map_alternative_error?;
+                        return Ok((raw, #name::#id(value)));
+                    }
+                };
+            }
+        }
+    }
+
+    let first_alternative = &choice.alternatives[0];
+    let first_kind = &first_alternative.kind;
+    let first_tag = match &first_alternative.tag {
+        Some(tag) if first_alternative.automatic => {
+            let number = tag.number;
+            let class = code_tag_class(tag.class);
+            quote! {
+                __red_asn1::Tag::new(
+                    #number,
+                    match <#first_kind as __red_asn1::Asn1Object>::CHOICE {
+                        true => __red_asn1::TagType::Constructed,
+                        false => <#first_kind as __red_asn1::Asn1Object>::tag().r#type,
+                    },
+                    #class
+                )
+            }
+        }
+        Some(tag) => code_explicit_tag(tag),
+        None => quote! {
+            <#first_kind as __red_asn1::Asn1Object>::tag()
+        },
+    };
+
+    // the alternatives must be distinguished by their tags
+    let mut tags_checks = quote! {};
+    for (i, alternative) in choice.alternatives.iter().enumerate() {
+        for j in (i + 1)..choice.alternatives.len() {
+            let other_alternative = &choice.alternatives[j];
+            let message = format!(
+                "alternatives {} and {} of {} can have the same tag, \
+                 so they cannot be distinguished",
+                alternative.id, other_alternative.id, name
+            );
+            let check = code_distinct_tags_check(
+                &alternatives_tags[i],
+                &alternatives_tags[j],
+                &message,
+                other_alternative.id.span(),
+            );
+            tags_checks = quote! {
+                #tags_checks
+                #check
+            };
+        }
+    }
+
+    let code = quote! {
+        const _: () = {
+            #tags_checks
+        };
+
+        impl __red_asn1::Asn1Object for #name {
+            /// Tags of all the alternatives
+            const TAGS: &'static [__red_asn1::Tag] = {
+                const TAGS_LISTS: &[&[__red_asn1::Tag]] = &[
+                    #(#alternatives_tags),*
+                ];
+                const UNION: [
+                    __red_asn1::Tag;
+                    __red_asn1::tags_union_len(TAGS_LISTS)
+                ] = __red_asn1::tags_union(TAGS_LISTS);
+                &UNION
+            };
+
+            const CHOICE: bool = true;
+
+            /// Tag of the first alternative, since a choice has
+            /// no tag of its own
+            fn tag() -> __red_asn1::Tag {
+                return #first_tag;
+            }
+
+            fn matches_tag(tag: &__red_asn1::Tag) -> bool {
+                return #matches_checks;
+            }
+
+            fn build(&self) -> Vec<u8> {
+                return match self {
+                    #build_arms
+                };
+            }
+
+            /// Value of the alternative, inside its tag
+            fn build_value(&self) -> Vec<u8> {
+                return match self {
+                    #build_value_arms
+                };
+            }
+
+            fn parse(raw: &[u8]) -> __red_asn1::Result<(&[u8], Self)> {
+                let (_, parsed_tag) = __red_asn1::Tag::parse(raw)
+                    .map_err(|error| {
+                        __red_asn1::Error::ChoiceError {
+                            choice: #name_str,
+                            offset: 0,
+                            error: Box::new(error),
+                        }
+                    })?;
+
+                #parse_calls
+
+                return Err(__red_asn1::Error::ChoiceError {
+                    choice: #name_str,
+                    offset: 0,
+                    error: Box::new(
+                        __red_asn1::Error::UnmatchedAlternative(parsed_tag)
+                    ),
+                });
+            }
+
+            fn decode_value(_raw: &[u8]) -> __red_asn1::Result<Self> {
+                return Err(__red_asn1::Error::ImplementationError(
+                    format!("{} must be parsed with its tag", #name_str)
+                ));
+            }
+
+            fn validate(&self) -> __red_asn1::Result<()> {
+                return match self {
+                    #validate_arms
+                };
+            }
+        }
+    };
+
+    return code_crate_scope(&choice.crate_path, code);
+}
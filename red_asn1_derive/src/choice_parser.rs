@@ -0,0 +1,176 @@
+use super::parse_definitions::{
+    AlternativeDefinition, ChoiceAttributes, ChoiceDefinition,
+    TagClassDefinition, TagDefinition,
+};
+use super::parse_error::{ParseError, ParseResult};
+use super::parser::{
+    default_crate_path, parse_attr_list, parse_path, parse_tag_attr,
+    AUTOMATIC_TAGS_ATTR, CRATE_ATTR,
+};
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Data, DataEnum, DeriveInput, Fields, Ident, Meta, NestedMeta,
+    Variant,
+};
+
+static ASN1_CHOICE_ATTR: &str = "choice";
+static ASN1_CHOICE_FIELD_ATTR: &str = "choice_field";
+
+/// Public method to parse the definition of an enum which
+/// derives Choice
+pub fn parse_choice(ast: DeriveInput) -> ParseResult<ChoiceDefinition> {
+    if let Data::Enum(data_enum) = ast.data {
+        let choice_attrs = parse_choice_attrs(&ast.attrs)?;
+        return parse_choice_enum(ast.ident, &choice_attrs, data_enum);
+    } else {
+        return Err(ParseError::NotEnum.at(ast.ident.span()));
+    }
+}
+
+/// Parse the choice attribute of an enum. In case the attribute
+/// is not specified, the default attributes are returned.
+fn parse_choice_attrs(attrs: &Vec<Attribute>) -> ParseResult<ChoiceAttributes> {
+    let mut choice_attrs = ChoiceAttributes::default();
+
+    for attr in attrs {
+        if attr.path.segments.is_empty()
+            || attr.path.segments[0].ident != ASN1_CHOICE_ATTR
+        {
+            continue;
+        }
+
+        for subattr in parse_attr_list(attr)? {
+            match subattr {
+                NestedMeta::Meta(Meta::Word(ref ident))
+                    if ident == AUTOMATIC_TAGS_ATTR =>
+                {
+                    choice_attrs.automatic_tags = true;
+                }
+                NestedMeta::Meta(Meta::NameValue(ref name_value))
+                    if name_value.ident == CRATE_ATTR =>
+                {
+                    choice_attrs.crate_path =
+                        Some(parse_path(attr, &name_value.lit)?);
+                }
+                NestedMeta::Meta(ref meta) => {
+                    return Err(ParseError::AttributeUnknown(
+                        meta.name().to_string(),
+                    )
+                    .at(meta.name().span()));
+                }
+                _ => {
+                    return Err(ParseError::AttributeInvalidFormat(
+                        attr.tts.to_string(),
+                    )
+                    .at(subattr.span()));
+                }
+            }
+        }
+    }
+
+    return Ok(choice_attrs);
+}
+
+fn parse_choice_enum(
+    name: Ident,
+    choice_attrs: &ChoiceAttributes,
+    data_enum: DataEnum,
+) -> ParseResult<ChoiceDefinition> {
+    let mut alternatives = Vec::new();
+
+    for (index, variant) in data_enum.variants.into_iter().enumerate() {
+        alternatives.push(parse_alternative(variant, index, choice_attrs)?);
+    }
+
+    if alternatives.is_empty() {
+        return Err(ParseError::EmptyChoice.at(name.span()));
+    }
+
+    return Ok(ChoiceDefinition {
+        name,
+        alternatives,
+        crate_path: choice_attrs
+            .crate_path
+            .clone()
+            .unwrap_or_else(default_crate_path),
+    });
+}
+
+/// Parse a variant of the enum. With automatic tagging, the variants
+/// without tag are tagged with the context tag of their index.
+fn parse_alternative(
+    variant: Variant,
+    index: usize,
+    choice_attrs: &ChoiceAttributes,
+) -> ParseResult<AlternativeDefinition> {
+    let kind = match variant.fields {
+        Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => {
+            fields.unnamed[0].ty.clone()
+        }
+        _ => {
+            return Err(ParseError::InvalidVariant(variant.ident.to_string())
+                .at(variant.ident.span()));
+        }
+    };
+
+    let mut tag = parse_alternative_attrs(&variant)?;
+    if tag.is_none() && choice_attrs.automatic_tags {
+        tag = Some(TagDefinition {
+            class: TagClassDefinition::Context,
+            number: index as u32,
+        });
+    }
+
+    return Ok(AlternativeDefinition {
+        id: variant.ident,
+        kind,
+        tag,
+        automatic: choice_attrs.automatic_tags,
+    });
+}
+
+fn parse_alternative_attrs(
+    variant: &Variant,
+) -> ParseResult<Option<TagDefinition>> {
+    let mut tag = None;
+
+    for attr in variant.attrs.iter() {
+        if attr.path.segments.is_empty()
+            || attr.path.segments[0].ident != ASN1_CHOICE_FIELD_ATTR
+        {
+            continue;
+        }
+
+        for subattr in parse_attr_list(attr)? {
+            match subattr {
+                NestedMeta::Meta(Meta::NameValue(ref name_value)) => {
+                    match parse_tag_attr(name_value)? {
+                        Some(tag_definition) => {
+                            tag = Some(tag_definition);
+                        }
+                        None => {
+                            return Err(ParseError::AttributeUnknown(
+                                name_value.ident.to_string(),
+                            )
+                            .at(name_value.ident.span()));
+                        }
+                    }
+                }
+                NestedMeta::Meta(ref meta) => {
+                    return Err(ParseError::AttributeUnknown(
+                        meta.name().to_string(),
+                    )
+                    .at(meta.name().span()));
+                }
+                _ => {
+                    return Err(ParseError::AttributeInvalidFormat(
+                        attr.tts.to_string(),
+                    )
+                    .at(subattr.span()));
+                }
+            }
+        }
+    }
+
+    return Ok(tag);
+}
@@ -1,6 +1,10 @@
-use crate::parse_definitions::{FieldCode, FieldDefinition};
+use crate::parse_definitions::{
+    ConstraintDefinition, FieldCode, FieldDefinition, TagDefinition,
+    TaggingDefinition,
+};
+use crate::tag_coder::{code_explicit_tag, code_tag_class};
 use proc_macro2::TokenStream;
-use syn::{Ident, PathSegment};
+use syn::Type;
 
 /// Method to create the code for the build/parse methods
 /// for a field of the structure
@@ -8,58 +12,238 @@ pub fn code_field(field: &FieldDefinition) -> FieldCode {
     return FieldCode {
         builder: code_field_builder(field),
         parser: code_field_parser(field),
+        checker: code_field_checker(field),
+    };
+}
+
+/// Method to create the code of the function which checks the
+/// constraints of a field. An absent optional field has no constraints
+/// to check.
+fn code_field_checker(field: &FieldDefinition) -> TokenStream {
+    if field.constraints.is_empty() {
+        return quote! {};
+    }
+
+    let checker_name = field.checker_name();
+    let field_name = &field.id;
+
+    let mut checks = quote! {};
+    for constraint in &field.constraints {
+        let check = match constraint {
+            ConstraintDefinition::Size(range, repr) => quote! {
+                __red_asn1::check_size(value, #range, #repr)?;
+            },
+            ConstraintDefinition::Range(range, repr) => quote! {
+                __red_asn1::check_range(value, #range, #repr)?;
+            },
+            ConstraintDefinition::Alphabet(alphabet) => quote! {
+                __red_asn1::check_alphabet(value, #alphabet)?;
+            },
+        };
+
+        checks = quote! {
+            #checks
+            #check
+        };
+    }
+
+    let field_checks = match field.optional {
+        true => quote! {
+            if let Some(value) = &self.#field_name {
+                #checks
+            }
+        },
+        false => quote! {
+            let value = &self.#field_name;
+            #checks
+        },
+    };
+
+    return quote! {
+        fn #checker_name(&self) -> __red_asn1::Result<()> {
+            #field_checks
+            return Ok(());
+        }
     };
 }
 
 /// Method to create the code for the parse method of a
 /// structure field
 fn code_field_parser(field: &FieldDefinition) -> TokenStream {
-    match field.context_tag_number {
-        Some(ctx_tag) => match field.optional {
-            true => code_optional_field_parser_with_context_tag(field, ctx_tag),
-            false => {
-                code_required_field_parser_with_context_tag(field, ctx_tag)
+    if field.unknown {
+        return code_unknown_field_parser(field);
+    }
+    if field.flatten {
+        return code_flatten_field_parser(field);
+    }
+
+    // fields with default value can be absent, as optional fields
+    let optional = field.optional || field.default.is_some();
+
+    match &field.tag {
+        Some(tag) => match (field.tagging, optional) {
+            (TaggingDefinition::Explicit, true) => {
+                code_optional_field_parser_with_explicit_tag(field, tag)
+            }
+            (TaggingDefinition::Explicit, false) => {
+                code_required_field_parser_with_explicit_tag(field, tag)
+            }
+            (_, true) => {
+                code_optional_field_parser_with_implicit_tag(field, tag)
+            }
+            (_, false) => {
+                code_required_field_parser_with_implicit_tag(field, tag)
             }
         },
 
-        None => code_field_parser_without_context_tag(field),
+        None => match &field.default {
+            Some(_) => code_default_field_parser_without_context_tag(field),
+            None => code_field_parser_without_context_tag(field),
+        },
     }
 }
 
-fn code_required_field_parser_with_context_tag(
-    field: &FieldDefinition,
-    context_tag_number: u8,
-) -> TokenStream {
+/// Write the header of the function which parses a field, returning
+/// the remaining data and the field value. Fields defined by other field
+/// also receive the value of that field.
+fn code_field_parser_signature(field: &FieldDefinition) -> TokenStream {
     let parser_name = field.parser_name();
-    let field_name = &field.id;
-    let field_type = compose_field_type(&field.kind, &field.sub_kinds);
+    let field_type = &field.kind;
+
+    let discriminant_param = match field.defined_by {
+        Some(_) => quote! {
+            , discriminant: &<#field_type as __red_asn1::Asn1OpenType>::Discriminant
+        },
+        None => quote! {},
+    };
 
     return quote! {
         fn #parser_name<'a>(
-            &mut self,
             raw: &'a [u8]
-        ) -> himmelblau_red_asn1::Result<&'a [u8]> {
+            #discriminant_param
+        ) -> __red_asn1::Result<(&'a [u8], #field_type)>
+    };
+}
+
+/// Write the functions to parse a field with an implicit tag and to
+/// check its tag. With automatic tagging, or the implicit tagging of the
+/// sequence, the functions tag the field explicitly in case it is a
+/// choice, as indicated by its type.
+fn code_implicit_tag_parsers(
+    field: &FieldDefinition,
+) -> (TokenStream, TokenStream) {
+    let field_type = &field.kind;
+
+    match field.tagging {
+        TaggingDefinition::Automatic => (
+            quote! {__red_asn1::parse_automatic::<#field_type>},
+            quote! {__red_asn1::is_automatic_tag::<#field_type>},
+        ),
+        _ => (
+            quote! {__red_asn1::parse_implicit::<#field_type>},
+            quote! {__red_asn1::is_implicit_tag::<#field_type>},
+        ),
+    }
+}
+
+/// Write the code for parse a field with an implicit tag,
+/// which replaces the tag of the field type
+fn code_required_field_parser_with_implicit_tag(
+    field: &FieldDefinition,
+    tag: &TagDefinition,
+) -> TokenStream {
+    let signature = code_field_parser_signature(field);
+    let (parse_tagged, _) = code_implicit_tag_parsers(field);
+    let tag_number = tag.number;
+    let tag_class = code_tag_class(tag.class);
+
+    return quote! {
+        #signature {
+            let (raw, field) = #parse_tagged(
+                raw,
+                #tag_number,
+                #tag_class
+            )?;
+            return Ok((raw, field));
+        }
+    };
+}
+
+/// Write the code for parse an optional field with an implicit tag.
+/// In case the tag doesn't match, the field is set to None (or its
+/// default value).
+fn code_optional_field_parser_with_implicit_tag(
+    field: &FieldDefinition,
+    tag: &TagDefinition,
+) -> TokenStream {
+    let signature = code_field_parser_signature(field);
+    let (parse_tagged, is_tag) = code_implicit_tag_parsers(field);
+    let absent_value = code_absent_value(field);
+    let default_check = code_default_check(field);
+    let tag_number = tag.number;
+    let tag_class = code_tag_class(tag.class);
+
+    return quote! {
+        #signature {
+            match __red_asn1::Tag::parse(raw) {
+                Ok((_, parsed_tag)) if #is_tag(
+                    &parsed_tag,
+                    #tag_number,
+                    #tag_class
+                ) => {}
+                _ => {
+                    return Ok((raw, #absent_value));
+                }
+            }
+
+            let (raw, field) = #parse_tagged(
+                raw,
+                #tag_number,
+                #tag_class
+            )?;
+            #default_check
+            return Ok((raw, field));
+        }
+    };
+}
+
+fn code_required_field_parser_with_explicit_tag(
+    field: &FieldDefinition,
+    tag: &TagDefinition,
+) -> TokenStream {
+    let signature = code_field_parser_signature(field);
+    let value_parse = code_field_value_parser(
+        field,
+        quote! {raw_value},
+        Some(quote! {header_length}),
+    );
+    let explicit_tag = code_explicit_tag(tag);
+    let tag_class = code_tag_class(tag.class);
+
+    return quote! {
+        #signature {
             let parsed_tag;
+            let raw_field = raw;
             let mut raw = raw;
 
-            match himmelblau_red_asn1::Tag::parse(raw) {
+            match __red_asn1::Tag::parse(raw) {
                 Ok((raw_tmp, tag)) => {
                     raw = raw_tmp;
                     parsed_tag = tag;
                 },
                 Err(error) => {
                     match error.clone() {
-                        himmelblau_red_asn1::Error::NotEnoughTagOctets(_) => {
+                        __red_asn1::Error::NotEnoughTagOctets(_) => {
                             return Err(
-                                himmelblau_red_asn1::Error::NotEnoughTagOctets(
-                                    himmelblau_red_asn1::TagClass::Context
+                                __red_asn1::Error::NotEnoughTagOctets(
+                                    #tag_class
                                 )
                             )?;
                         }
-                        himmelblau_red_asn1::Error::EmptyTag(_) => {
+                        __red_asn1::Error::EmptyTag(_) => {
                             return Err(
-                                himmelblau_red_asn1::Error::EmptyTag(
-                                    himmelblau_red_asn1::TagClass::Context
+                                __red_asn1::Error::EmptyTag(
+                                    #tag_class
                                 )
                             )?;
                         }
@@ -70,94 +254,95 @@ fn code_required_field_parser_with_context_tag(
                 }
             }
 
-            if parsed_tag != himmelblau_red_asn1::Tag::new(
-                #context_tag_number,
-                himmelblau_red_asn1::TagType::Constructed,
-                himmelblau_red_asn1::TagClass::Context
-            ) {
-                return Err(
-                    himmelblau_red_asn1::Error::UnmatchedTag(
-                        himmelblau_red_asn1::TagClass::Context
-                    )
-                )?;
+            if parsed_tag != #explicit_tag {
+                return Err(__red_asn1::Error::UnmatchedTag {
+                    expected: #explicit_tag,
+                    found: parsed_tag,
+                })?;
             }
 
-            let (raw, length) = himmelblau_red_asn1::parse_length(raw)?;
+            let (raw, length) = __red_asn1::parse_length(raw)?;
             if length > raw.len() {
-                return Err(himmelblau_red_asn1::Error::NoDataForLength)?;
+                return Err(__red_asn1::Error::NoDataForLength)?;
             }
 
+            let header_length = raw_field.len() - raw.len();
             let (raw_value, raw) = raw.split_at(length);
 
-            let (_, field) = #field_type::parse(raw_value)?;
-            self.#field_name = field;
+            let (_, field) = #value_parse;
 
-            return Ok(raw);
+            return Ok((raw, field));
         }
     };
 }
 
-/// Write the code for parse a field in case of having a context tag
+/// Write the code for parse a field in case of having an explicit tag
 /// and being optional. In this case the parse fails in case the
-/// context tag matchs but the type tag is incorrect, or the type
-/// data is invalid. However is context tag doesn't match, then,
-/// the field is set to None.
-fn code_optional_field_parser_with_context_tag(
+/// explicit tag matchs but the type tag is incorrect, or the type
+/// data is invalid. However is explicit tag doesn't match, then,
+/// the field is set to None (or its default value).
+fn code_optional_field_parser_with_explicit_tag(
     field: &FieldDefinition,
-    context_tag_number: u8,
+    tag: &TagDefinition,
 ) -> TokenStream {
-    let parser_name = field.parser_name();
-    let field_name = &field.id;
-    let field_type = compose_field_type(&field.kind, &field.sub_kinds);
+    let signature = code_field_parser_signature(field);
+    let field_type = code_asn1_object(&field.kind);
+    let value_parse = code_field_value_parser(
+        field,
+        quote! {raw_value},
+        Some(quote! {header_length}),
+    );
+    let explicit_tag = code_explicit_tag(tag);
+    let absent_value = code_absent_value(field);
+    let default_check = code_default_check(field);
+
+    // open types and values of custom codecs have no fixed tag to check
+    let type_tag_check = match (&field.defined_by, &field.with) {
+        (None, None) => quote! {
+            let (_, type_tag) = __red_asn1::Tag::parse(raw_value)?;
+            if !#field_type::matches_tag(&type_tag) {
+                return Err(__red_asn1::Error::UnmatchedTag {
+                    expected: #field_type::tag(),
+                    found: type_tag,
+                });
+            }
+        },
+        _ => quote! {},
+    };
 
     return quote! {
-        fn #parser_name<'a>(
-            &mut self,
-            raw: &'a [u8]
-        ) -> himmelblau_red_asn1::Result<&'a [u8]> {
+        #signature {
             let parsed_tag;
             let mut raw_local = raw;
 
-            match himmelblau_red_asn1::Tag::parse(raw) {
+            match __red_asn1::Tag::parse(raw) {
                 Ok((raw_tmp, tag)) => {
                     raw_local = raw_tmp;
                     parsed_tag = tag;
                 },
                 Err(error) => {
-                    self.#field_name = None;
-                    return Ok(raw);
+                    return Ok((raw, #absent_value));
                 }
             }
 
-            if parsed_tag != himmelblau_red_asn1::Tag::new(
-                #context_tag_number,
-                himmelblau_red_asn1::TagType::Constructed,
-                himmelblau_red_asn1::TagClass::Context
-            ) {
-                self.#field_name = None;
-                return Ok(raw);
+            if parsed_tag != #explicit_tag {
+                return Ok((raw, #absent_value));
             }
 
-            let (raw_local, length) = himmelblau_red_asn1::parse_length(raw_local)?;
-            if length > raw.len() {
-                return Err(himmelblau_red_asn1::Error::NoDataForLength)?;
+            let (raw_local, length) = __red_asn1::parse_length(raw_local)?;
+            if length > raw_local.len() {
+                return Err(__red_asn1::Error::NoDataForLength)?;
             }
 
+            let header_length = raw.len() - raw_local.len();
             let (raw_value, raw_local) = raw_local.split_at(length);
 
-            let (_, type_tag) = himmelblau_red_asn1::Tag::parse(raw_value)?;
-            if type_tag != #field_type::tag() {
-                return Err(
-                    himmelblau_red_asn1::Error::UnmatchedTag(
-                        himmelblau_red_asn1::TagClass::Universal
-                    )
-                );
-            }
+            #type_tag_check
 
-            let (_, field) = #field_type::parse(raw_value)?;
-            self.#field_name = field;
+            let (_, field) = #value_parse;
+            #default_check
 
-            return Ok(raw_local);
+            return Ok((raw_local, field));
         }
     };
 }
@@ -165,17 +350,73 @@ fn code_optional_field_parser_with_context_tag(
 fn code_field_parser_without_context_tag(
     field: &FieldDefinition,
 ) -> TokenStream {
-    let parser_name = field.parser_name();
-    let field_name = &field.id;
-    let field_type = compose_field_type(&field.kind, &field.sub_kinds);
+    let signature = code_field_parser_signature(field);
+    let value_parse = code_field_value_parser(field, quote! {raw}, None);
     return quote! {
-        fn #parser_name<'a>(
-            &mut self,
-            raw: &'a [u8]
-        ) -> himmelblau_red_asn1::Result<&'a [u8]> {
-            let (raw, field) = #field_type::parse(raw)?;
-            self.#field_name = field;
-            return Ok(raw);
+        #signature {
+            let (raw, field) = #value_parse;
+            return Ok((raw, field));
+        }
+    };
+}
+
+/// Write the code for parse the field which stores all the unknown
+/// trailing elements of the sequence, as Any
+fn code_unknown_field_parser(field: &FieldDefinition) -> TokenStream {
+    let signature = code_field_parser_signature(field);
+
+    return quote! {
+        #signature {
+            let mut raw = raw;
+            let mut elements = Vec::new();
+
+            while raw.len() > 0 {
+                let (raw_tmp, element) = __red_asn1::Any::parse(raw)?;
+                elements.push(element);
+                raw = raw_tmp;
+            }
+
+            return Ok((raw, elements));
+        }
+    };
+}
+
+/// Write the code for parse the components of a flattened field,
+/// which are not wrapped by the tag and length of the field type
+fn code_flatten_field_parser(field: &FieldDefinition) -> TokenStream {
+    let signature = code_field_parser_signature(field);
+    let field_type = &field.kind;
+
+    return quote! {
+        #signature {
+            return <#field_type as __red_asn1::Asn1Components>::parse_components(raw);
+        }
+    };
+}
+
+/// Write the code for parse a field with default value and without
+/// tag. In case the type tag doesn't match, the field is set to its
+/// default value.
+fn code_default_field_parser_without_context_tag(
+    field: &FieldDefinition,
+) -> TokenStream {
+    let signature = code_field_parser_signature(field);
+    let field_type = &field.kind;
+    let absent_value = code_absent_value(field);
+    let default_check = code_default_check(field);
+
+    return quote! {
+        #signature {
+            let (raw, field) = <::core::option::Option<#field_type> as __red_asn1::Asn1Object>::parse(raw)?;
+            match field {
+                Some(field) => {
+                    #default_check
+                    return Ok((raw, field));
+                }
+                None => {
+                    return Ok((raw, #absent_value));
+                }
+            }
         }
     };
 }
@@ -183,35 +424,104 @@ fn code_field_parser_without_context_tag(
 /// Method to create the code of the build method of a
 /// structure field
 fn code_field_builder(field: &FieldDefinition) -> TokenStream {
-    match field.context_tag_number {
-        Some(context_tag_number) => {
-            code_field_builder_with_context_tag(field, context_tag_number)
-        }
+    if field.unknown {
+        return code_unknown_field_builder(field);
+    }
+    if field.flatten {
+        return code_flatten_field_builder(field);
+    }
+
+    match &field.tag {
+        Some(tag) => match field.tagging {
+            TaggingDefinition::Explicit => {
+                code_field_builder_with_explicit_tag(field, tag)
+            }
+            _ => code_field_builder_with_implicit_tag(field, tag),
+        },
         None => code_field_builder_without_context_tag(field),
     }
 }
 
-fn code_field_builder_with_context_tag(
+/// Write the code for build the unknown elements of the sequence,
+/// which are built as they were parsed
+fn code_unknown_field_builder(field: &FieldDefinition) -> TokenStream {
+    let builder_name = field.builder_name();
+    let field_name = &field.id;
+
+    return quote! {
+        fn #builder_name (&self) -> Vec<u8> {
+            let mut built = Vec::new();
+            for element in self.#field_name.iter() {
+                built.append(&mut element.build());
+            }
+            return built;
+        }
+    };
+}
+
+/// Write the code for build the components of a flattened field,
+/// without the tag and length of the field type
+fn code_flatten_field_builder(field: &FieldDefinition) -> TokenStream {
+    let builder_name = field.builder_name();
+    let field_name = &field.id;
+
+    return quote! {
+        fn #builder_name (&self) -> Vec<u8> {
+            return __red_asn1::Asn1Components::build_components(
+                &self.#field_name
+            );
+        }
+    };
+}
+
+fn code_field_builder_with_implicit_tag(
     field: &FieldDefinition,
-    ctx_tag: u8,
+    tag: &TagDefinition,
 ) -> TokenStream {
     let builder_name = field.builder_name();
     let field_name = &field.id;
+    let tag_number = tag.number;
+    let tag_class = code_tag_class(tag.class);
+    let default_skip = code_default_skip(field);
+    let build_tagged = match field.tagging {
+        TaggingDefinition::Automatic => {
+            quote! {__red_asn1::build_automatic}
+        }
+        _ => quote! {__red_asn1::build_implicit},
+    };
+
+    return quote! {
+        fn #builder_name (&self) -> Vec<u8> {
+            #default_skip
+            return #build_tagged(
+                &self.#field_name,
+                #tag_number,
+                #tag_class
+            );
+        }
+    };
+}
+
+fn code_field_builder_with_explicit_tag(
+    field: &FieldDefinition,
+    tag: &TagDefinition,
+) -> TokenStream {
+    let builder_name = field.builder_name();
+    let value_build = code_field_value_builder(field);
+    let explicit_tag = code_explicit_tag(tag);
+    let default_skip = code_default_skip(field);
 
     return quote! {
         fn #builder_name (&self) -> Vec<u8> {
-            let mut built_value = self.#field_name.build();
+            #default_skip
+            let mut built_value = #value_build;
             if built_value.len() == 0 {
                 return built_value;
             }
 
-            let tag = himmelblau_red_asn1::Tag::new(
-                #ctx_tag,
-                himmelblau_red_asn1::TagType::Constructed,
-                himmelblau_red_asn1::TagClass::Context
-            );
+            let tag = #explicit_tag;
             let mut built = tag.build();
-            let mut built_length = himmelblau_red_asn1::build_length(built_value.len());
+            let mut built_length = __red_asn1::build_length(built_value.len());
 
             built.append(&mut built_length);
             built.append(&mut built_value);
@@ -225,29 +535,161 @@ fn code_field_builder_without_context_tag(
     field: &FieldDefinition,
 ) -> TokenStream {
     let builder_name = field.builder_name();
-    let field_name = &field.id;
+    let value_build = code_field_value_builder(field);
+    let default_skip = code_default_skip(field);
 
     return quote! {
         fn #builder_name (&self) -> Vec<u8> {
-            return self.#field_name.build();
+            #default_skip
+            return #value_build;
         }
     };
 }
 
-/// Function to compose the path to call Self functions. Simple types
-/// call this functions with Type::function(), but other types, like
-/// Option, required to call Self functions in the way
-/// Option::<SubType>::function().
-fn compose_field_type(
-    field_kind: &Ident,
-    field_sub_kinds: &Option<PathSegment>,
+/// Function to write the expression which parses the value of a field
+/// (including its own tag) from raw. Open types are parsed with the
+/// value of the field which defines their type.
+fn code_field_value_parser(
+    field: &FieldDefinition,
+    raw: TokenStream,
+    offset: Option<TokenStream>,
 ) -> TokenStream {
-    match field_sub_kinds {
-        Some(field_sub_types) => {
-            quote! {#field_kind::<#field_sub_types>}
+    let field_type = &field.kind;
+
+    // the errors of a value inside a tag are moved to the tag
+    let shift = match offset {
+        Some(offset) => quote! {.map_err(|error| error.shift(#offset))},
+        None => quote! {},
+    };
+
+    // the codec of an optional field handles the inner value
+    if let Some(with) = &field.with {
+        return match field.optional {
+            true => quote! {
+                {
+                    let (raw, value) = #with::parse(#raw)#shift?;
+                    (raw, Some(value))
+                }
+            },
+            false => quote! {#with::parse(#raw)#shift?},
+        };
+    }
+
+    match &field.defined_by {
+        Some(_) => quote! {
+            <#field_type as __red_asn1::Asn1OpenType>::parse_open(
+                discriminant,
+                #raw
+            )#shift?
+        },
+        None => {
+            let field_type = code_asn1_object(field_type);
+            quote! {
+                #field_type::parse(#raw)#shift?
+            }
         }
+    }
+}
+
+/// Function to write the expression which builds the value of a field
+/// (including its own tag)
+fn code_field_value_builder(field: &FieldDefinition) -> TokenStream {
+    let field_name = &field.id;
+
+    if let Some(with) = &field.with {
+        return match field.optional {
+            true => quote! {
+                match &self.#field_name {
+                    Some(value) => #with::build(value),
+                    None => Vec::new(),
+                }
+            },
+            false => quote! {#with::build(&self.#field_name)},
+        };
+    }
+
+    match field.defined_by {
+        Some(_) => quote! {
+            __red_asn1::Asn1OpenType::build_open(&self.#field_name)
+        },
+        None => quote! {
+            __red_asn1::Asn1Object::build(&self.#field_name)
+        },
+    }
+}
+
+/// Function to write the value of an optional field which is not
+/// encoded, that is None, or the default value of the field
+fn code_absent_value(field: &FieldDefinition) -> TokenStream {
+    match &field.default {
+        Some(default) => quote! {#default},
+        None => quote! {None},
+    }
+}
+
+/// Function to write the check of a parsed field with default value,
+/// which is only done in strict mode, since DER forbids to encode
+/// the default value
+fn code_default_check(field: &FieldDefinition) -> TokenStream {
+    match &field.default {
+        Some(default) if field.strict => quote! {
+            if field == #default {
+                return Err(__red_asn1::Error::IncorrectValue(
+                    "Encoded default value".to_string()
+                ));
+            }
+        },
+        _ => quote! {},
+    }
+}
+
+/// Function to write the check which skips the build of a field
+/// with its default value
+fn code_default_skip(field: &FieldDefinition) -> TokenStream {
+    let field_name = &field.id;
+
+    match &field.default {
+        Some(default) => quote! {
+            if self.#field_name == #default {
+                return Vec::new();
+            }
+        },
+        None => quote! {},
+    }
+}
+
+/// Function to write the list of possible tags of a field, used to check
+/// that the fields can be distinguished. The tags of a flattened field are
+/// the ones of its first component. The tags of open types, unknown
+/// elements and untagged fields with custom codec are unknown, so they
+/// are not checked.
+pub fn code_field_tags(field: &FieldDefinition) -> TokenStream {
+    if field.unknown || field.defined_by.is_some() {
+        return quote! {&[]};
+    }
+    if field.flatten {
+        let field_type = &field.kind;
+        return quote! {
+            <#field_type as __red_asn1::Asn1Components>::FIRST_TAGS
+        };
+    }
+
+    match &field.tag {
+        Some(tag) => {
+            let explicit_tag = code_explicit_tag(tag);
+            quote! {&[#explicit_tag]}
+        }
+        None if field.with.is_some() => quote! {&[]},
         None => {
-            quote! {#field_kind}
+            let field_type = code_asn1_object(&field.kind);
+            quote! {#field_type::TAGS}
         }
     }
 }
+
+/// Function to write the path to call the Asn1Object functions of
+/// a type, as `<Type as Asn1Object>`, which is valid for any type,
+/// including qualified paths and types with generic arguments
+fn code_asn1_object(kind: &Type) -> TokenStream {
+    return quote! {<#kind as __red_asn1::Asn1Object>};
+}
@@ -1,12 +1,147 @@
-//! Macro to create ASN1 sequence from Rust struct
+//! Macro to create ASN1 sequence from Rust struct, and choice from Rust enum.
+//! Also, newtypes can be created from tuple structs with one field
 //! # Attributes
 //!
 //! * seq: Define attributes for the struct definition. Has the following flags:
 //!     + application_tag : Number of application tag
+//!     + context_tag, private_tag, universal_tag: Number of the tag of the
+//!       struct in other class, used instead of application_tag
+//!     + tagging: Default tagging of the tags of the fields and the struct,
+//!       "explicit" (by default) or "implicit", as DEFINITIONS IMPLICIT TAGS
+//!       in a module. As in a module, the fields whose type is a choice are
+//!       tagged explicitly when tagging is "implicit"
+//!     + automatic_tags: Flag to tag the fields without tag with the
+//!       context tag of their position, starting at 0, as DEFINITIONS
+//!       AUTOMATIC TAGS in a module. The tags are implicit, except for
+//!       choices and open types, which are tagged explicitly
+//!     + implicit: Flag to indicate that the struct tag replaces the SEQUENCE
+//!       tag (IMPLICIT), instead of wrapping it, as in LDAP
+//!     + explicit: Flag to indicate that the struct tag wraps the SEQUENCE
+//!       tag (EXPLICIT), as in Kerberos
+//!     + strict: Flag to reject the fields with default value which
+//!       are encoded with the default value, as required by DER
+//!     + extensible: Flag to skip the unknown trailing elements in parse,
+//!       which could be added by newer versions of the type (`...`)
+//!     + validate: Path of a function `fn(&Self) -> Result<()>` to check
+//!       the constraints between fields, as a string
+//!     + post_parse: Path of a function `fn(&mut Self) -> Result<()>` to
+//!       fill the computed fields after parse, before the constraints are
+//!       checked, as a string
+//!     + crate: Path of the red_asn1 crate used by the generated code, as a
+//!       string, for crates which reexport it with other name. By default,
+//!       `himmelblau_red_asn1`
 //!
 //! * seq_field: Define attributes for a field of the struct. Has the following flags:
 //!     + context_tag: Number of context tag
-//!     + optional: Flag to indicate that is an optional field
+//!     + application_tag, private_tag, universal_tag: Number of the tag of
+//!       the field in other class, used instead of context_tag
+//!     + implicit: Flag to indicate that the field tag replaces the tag
+//!       of the field type (IMPLICIT), instead of wrapping it
+//!     + explicit: Flag to indicate that the field tag wraps the field
+//!       type (EXPLICIT), instead of replacing it
+//!     + optional: Flag to indicate that is an optional field. Fields of
+//!       type Option (or Optional), given by its name or its full path, as
+//!       `std::option::Option`, are optional without it, but it is
+//!       required for other names of Option, as type aliases. With
+//!       `optional = false`, the field is required, as for a type named
+//!       Option which is not the one of std
+//!     + defined_by: Name of a previous field which selects the type of
+//!       this field, that must implement Asn1OpenType (ANY DEFINED BY).
+//!       Without tag, an optional field is absent when there are no more
+//!       data, so it must be the last one
+//!     + default: Value of the field when it is not encoded (DEFAULT), as
+//!       literal or as an expression inside an string. The field is not
+//!       encoded when it has the default value
+//!     + unknown: Flag to indicate the last field, of type `Vec<Any>`, which
+//!       stores the unknown trailing elements, which are built again as
+//!       they were parsed
+//!     + skip: Flag to indicate a field which is not encoded, as cached
+//!       or computed data. It is ignored in build, and it is initialized
+//!       in parse with the default attribute, or with Default
+//!     + flatten: Flag to include the components of a field whose type
+//!       derives Sequence, without its tag and length (COMPONENTS OF).
+//!       The field cannot be tagged, optional or have other attributes,
+//!       it cannot be in a struct with automatic_tags and its type cannot
+//!       have an unknown field
+//!     + size: Range of the size of the field (SIZE), as a Rust range
+//!       or a value inside an string, as "1.." or "4". The size is the
+//!       number of items, characters or bits, as defined by Asn1Size
+//!     + range: Range of the value of the field, as a Rust range or a
+//!       value inside an string, as "0..=65535"
+//!     + alphabet: Permitted characters of a string field (FROM)
+//!     + with: Path of a module with the functions to build/parse the
+//!       field value, including its own tag, instead of Asn1Object, as a
+//!       string, which is useful for types that are not Asn1Object, or
+//!       with non standard encodings. The field tag is still applied,
+//!       but it must be explicit. For optional fields, the functions
+//!       handle the inner value:
+//!         - `fn build(value: &T) -> Vec<u8>`
+//!         - `fn parse(raw: &[u8]) -> Result<(&[u8], T)>`
+//!
+//! * open_type: Define attributes for an enum which derives OpenType:
+//!     + discriminant: Type of the field which selects the variant
+//!     + crate: Path of the red_asn1 crate, as in seq
+//!
+//! * open_value: Define attributes for a variant of an OpenType enum:
+//!     + id: Value of the discriminant for the variant, as integer or
+//!       as an expression inside an string
+//!     + unknown: Flag to indicate the variant which stores the values
+//!       with unknown discriminant, of type Any. Only one variant can be
+//!       unknown, and without it, an unknown discriminant is an error
+//!
+//! * choice: Define attributes for an enum which derives Choice:
+//!     + automatic_tags: Flag to tag the variants without tag with the
+//!       context tag of their position, starting at 0 (AUTOMATIC TAGS).
+//!       The tags of all the variants are implicit, except for choices
+//!     + crate: Path of the red_asn1 crate, as in seq
+//!
+//! * choice_field: Define attributes for a variant of a Choice enum. Without
+//!   attributes, the alternative is selected by the tag of its type:
+//!     + context_tag, application_tag, private_tag, universal_tag: Number of
+//!       the tag which wraps the alternative (explicit tagging)
+//!
+//! * newtype: Define attributes for a tuple struct which derives Newtype.
+//!   Without attributes, it is built/parsed as the inner type:
+//!     + application_tag, context_tag, private_tag, universal_tag: Number
+//!       of the tag of the newtype
+//!     + implicit: Flag to indicate that the tag replaces the tag of the
+//!       inner type (IMPLICIT), instead of wrapping it
+//!     + explicit: Flag to indicate that the tag wraps the inner type
+//!       (EXPLICIT), which is the default
+//!     + crate: Path of the red_asn1 crate, as in seq
+//!
+//! # Generics
+//!
+//! Structs with type parameters, lifetimes and where clauses can derive
+//! Sequence. The type parameters used in the types of the encoded fields
+//! are required to implement Asn1Object, but not the ones only used by
+//! skipped fields, as PhantomData, or by fields with a custom codec.
+//!
+//! # Constraints
+//!
+//! The constraints of the fields and the validate function are checked
+//! when the sequence is parsed, and by `validate` and `encode`, which
+//! also check the constraints of the inner objects. Violations are
+//! reported as `Error::ConstraintError`. The `build` function does not
+//! check them.
+//!
+//! # Generated code
+//!
+//! The functions and constants generated for the fields are prefixed with
+//! `__red_asn1_`, so they do not collide with the methods of the type.
+//!
+//! # Tags
+//!
+//! The tags of each series of optional fields (or with default value) of
+//! a sequence, and of the field which follows the series, must be distinct,
+//! as the tags of the alternatives of a choice, so the parsed values can be
+//! assigned to the right field. Otherwise, a compile error is reported. The
+//! possible tags are given by the `TAGS` constant of Asn1Object, including
+//! the tags of all the alternatives of nested choices, so types which do
+//! not define it are not checked. The components of flattened fields are
+//! checked with the `FIRST_TAGS` and `LAST_TAGS` constants of
+//! Asn1Components, which are not computed for generic structs. In generic
+//! structs, the check is done when they are parsed with concrete types.
 //!
 //! # Example
 //!
@@ -22,7 +157,7 @@
 //! use himmelblau_red_asn1::*;
 //! use himmelblau_red_asn1_derive::Sequence;
 //!
-//! #[derive(Sequence, Default)]
+//! #[derive(Sequence)]
 //! #[seq(application_tag = 1)]
 //! struct Person {
 //!     #[seq_field(context_tag = 0)]
@@ -61,6 +196,106 @@
 //!
 //! ```
 //!
+//! # Open types
+//!
+//! ```
+//! /*
+//! AlgorithmIdentifier ::= SEQUENCE {
+//!     algorithm   OBJECT IDENTIFIER,
+//!     parameters  ANY DEFINED BY algorithm OPTIONAL
+//! }
+//! */
+//!
+//! use himmelblau_red_asn1::*;
+//! use himmelblau_red_asn1_derive::{OpenType, Sequence};
+//!
+//! #[derive(OpenType, Debug, PartialEq)]
+//! #[open_type(discriminant = "Oid")]
+//! enum Parameters {
+//!     #[open_value(id = "Oid::new(1, 2, vec![840, 113549, 1, 1, 1])")]
+//!     Rsa(OctetString),
+//!     #[open_value(unknown)]
+//!     Unknown(Any),
+//! }
+//!
+//! #[derive(Sequence)]
+//! struct AlgorithmIdentifier {
+//!     algorithm: Oid,
+//!     #[seq_field(defined_by = "algorithm")]
+//!     parameters: Option<Parameters>,
+//! }
+//!
+//! let (_, alg_id) = AlgorithmIdentifier::parse(&[
+//!     0x30, 0x9, 0x6, 0x3, 0x2a, 0x3, 0x4, // 1.2.3.4
+//!     0x4, 0x2, 0x1, 0x2
+//! ]).unwrap();
+//!
+//! assert_eq!(
+//!     Some(Parameters::Unknown(Any::new(vec![0x4, 0x2, 0x1, 0x2]))),
+//!     alg_id.parameters
+//! );
+//! ```
+//!
+//! # Choices
+//!
+//! Each variant of a Choice enum must hold one value which implements
+//! Asn1Object.
+//!
+//! ```
+//! /*
+//! PrincipalName ::= CHOICE {
+//!     id      [0] INTEGER,
+//!     name    GeneralString
+//! }
+//! */
+//!
+//! use himmelblau_red_asn1::*;
+//! use himmelblau_red_asn1_derive::Choice;
+//!
+//! #[derive(Choice, Debug, PartialEq)]
+//! enum PrincipalName {
+//!     #[choice_field(context_tag = 0)]
+//!     Id(Integer),
+//!     Name(GeneralString),
+//! }
+//!
+//! assert_eq!(
+//!     vec![0xa0, 0x3, 0x2, 0x1, 0x7],
+//!     PrincipalName::Id(7).build()
+//! );
+//!
+//! let (_, name) = PrincipalName::parse(&[
+//!     0x1b, 0x4, 0x4a, 0x6f, 0x68, 0x6e // "John"
+//! ]).unwrap();
+//!
+//! assert_eq!(PrincipalName::Name("John".to_string()), name);
+//! ```
+//!
+//! # Newtypes
+//!
+//! ```
+//! /*
+//! KerberosString ::= GeneralString
+//! Realm ::= [APPLICATION 2] KerberosString
+//! */
+//!
+//! use himmelblau_red_asn1::*;
+//! use himmelblau_red_asn1_derive::Newtype;
+//!
+//! #[derive(Newtype, Debug, PartialEq)]
+//! struct KerberosString(GeneralString);
+//!
+//! #[derive(Newtype, Debug, PartialEq)]
+//! #[newtype(application_tag = 2)]
+//! struct Realm(KerberosString);
+//!
+//! let realm = Realm(KerberosString("EX".to_string()));
+//! let raw = vec![0x62, 0x4, 0x1b, 0x2, 0x45, 0x58];
+//!
+//! assert_eq!(raw, realm.build());
+//! assert_eq!(realm, Realm::parse(&raw).unwrap().1);
+//! ```
+//!
 
 #![recursion_limit = "256"]
 extern crate proc_macro;
@@ -72,12 +307,25 @@ extern crate quote;
 use proc_macro::TokenStream;
 use syn::{parse_macro_input, DeriveInput};
 
+mod choice_coder;
+mod choice_parser;
 mod field_coder;
+mod newtype_coder;
+mod newtype_parser;
+mod open_type_coder;
+mod open_type_parser;
 mod parse_definitions;
 mod parse_error;
 mod parser;
 mod sequence_coder;
+mod tag_coder;
 
+use choice_coder::code_choice;
+use choice_parser::parse_choice;
+use newtype_coder::code_newtype;
+use newtype_parser::parse_newtype;
+use open_type_coder::code_open_type;
+use open_type_parser::parse_open_type;
 use parser::parse_sequence;
 use sequence_coder::code_sequence;
 
@@ -85,8 +333,46 @@ use sequence_coder::code_sequence;
 pub fn sequence_macro_derive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
 
-    let sequence_definition = parse_sequence(ast).unwrap();
-    let sequence_code = code_sequence(&sequence_definition);
+    let sequence_code = match parse_sequence(ast) {
+        Ok(sequence_definition) => code_sequence(&sequence_definition),
+        Err(parse_error) => parse_error.to_compile_error(),
+    };
 
     return TokenStream::from(sequence_code);
 }
+
+#[proc_macro_derive(OpenType, attributes(open_type, open_value))]
+pub fn open_type_macro_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    let open_type_code = match parse_open_type(ast) {
+        Ok(open_type_definition) => code_open_type(&open_type_definition),
+        Err(parse_error) => parse_error.to_compile_error(),
+    };
+
+    return TokenStream::from(open_type_code);
+}
+
+#[proc_macro_derive(Choice, attributes(choice, choice_field))]
+pub fn choice_macro_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    let choice_code = match parse_choice(ast) {
+        Ok(choice_definition) => code_choice(&choice_definition),
+        Err(parse_error) => parse_error.to_compile_error(),
+    };
+
+    return TokenStream::from(choice_code);
+}
+
+#[proc_macro_derive(Newtype, attributes(newtype))]
+pub fn newtype_macro_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    let newtype_code = match parse_newtype(ast) {
+        Ok(newtype_definition) => code_newtype(&newtype_definition),
+        Err(parse_error) => parse_error.to_compile_error(),
+    };
+
+    return TokenStream::from(newtype_code);
+}
@@ -0,0 +1,166 @@
+use super::parse_definitions::NewtypeDefinition;
+use crate::sequence_coder::{code_crate_scope, code_generics_with_bounds};
+use crate::tag_coder::{code_explicit_tag, code_tag_class};
+use proc_macro2::TokenStream;
+use syn::spanned::Spanned;
+
+/// Function to write the code of the methods to build/parse a Newtype,
+/// which delegate in the inner type
+pub fn code_newtype(newtype: &NewtypeDefinition) -> TokenStream {
+    let name = &newtype.name;
+    let kind = &newtype.kind;
+    let inner = quote! {<#kind as __red_asn1::Asn1Object>};
+
+    let tagged_code = match &newtype.tag {
+        Some(outer_tag) => {
+            let number = outer_tag.number;
+            let class = code_tag_class(outer_tag.class);
+
+            if newtype.implicit {
+                quote! {
+                    const TAGS: &'static [__red_asn1::Tag] = match #inner::TAGS {
+                        [inner_tag, ..] => &[__red_asn1::Tag::new(
+                            #number,
+                            inner_tag.r#type,
+                            #class
+                        )],
+                        [] => &[],
+                    };
+
+                    /// Tag of the newtype, with the form (primitive or
+                    /// constructed) of the inner type
+                    fn tag() -> __red_asn1::Tag {
+                        return __red_asn1::Tag::new(
+                            #number,
+                            #inner::tag().r#type,
+                            #class
+                        );
+                    }
+
+                    fn matches_tag(tag: &__red_asn1::Tag) -> bool {
+                        return __red_asn1::is_implicit_tag::<#kind>(
+                            tag, #number, #class
+                        );
+                    }
+
+                    fn build_value(&self) -> Vec<u8> {
+                        return self.0.build_value();
+                    }
+
+                    fn decode_value(raw: &[u8]) -> __red_asn1::Result<Self> {
+                        return Ok(Self(#inner::decode_value(raw)?));
+                    }
+
+                    fn build(&self) -> Vec<u8> {
+                        return __red_asn1::build_implicit(
+                            &self.0, #number, #class
+                        );
+                    }
+
+                    fn parse(raw: &[u8]) -> __red_asn1::Result<(&[u8], Self)> {
+                        let (raw, value) = __red_asn1::parse_implicit(
+                            raw, #number, #class
+                        )?;
+                        return Ok((raw, Self(value)));
+                    }
+                }
+            } else {
+                let tag = code_explicit_tag(outer_tag);
+                quote! {
+                    const TAGS: &'static [__red_asn1::Tag] = &[#tag];
+
+                    fn tag() -> __red_asn1::Tag {
+                        return #tag;
+                    }
+
+                    fn build_value(&self) -> Vec<u8> {
+                        return self.0.build();
+                    }
+
+                    fn decode_value(raw: &[u8]) -> __red_asn1::Result<Self> {
+                        return Ok(Self(#inner::decode(raw)?));
+                    }
+
+                    fn build(&self) -> Vec<u8> {
+                        return __red_asn1::build_explicit(
+                            &self.0, #number, #class
+                        );
+                    }
+
+                    fn parse(raw: &[u8]) -> __red_asn1::Result<(&[u8], Self)> {
+                        let (raw, value) = __red_asn1::parse_explicit(
+                            raw, #number, #class
+                        )?;
+                        return Ok((raw, Self(value)));
+                    }
+                }
+            }
+        }
+        None => {
+            quote! {
+                const TAGS: &'static [__red_asn1::Tag] = #inner::TAGS;
+                const CHOICE: bool = #inner::CHOICE;
+
+                fn tag() -> __red_asn1::Tag {
+                    return #inner::tag();
+                }
+
+                fn matches_tag(tag: &__red_asn1::Tag) -> bool {
+                    return #inner::matches_tag(tag);
+                }
+
+                fn build_value(&self) -> Vec<u8> {
+                    return self.0.build_value();
+                }
+
+                fn decode_value(raw: &[u8]) -> __red_asn1::Result<Self> {
+                    return Ok(Self(#inner::decode_value(raw)?));
+                }
+
+                fn build(&self) -> Vec<u8> {
+                    return self.0.build();
+                }
+
+                fn parse(raw: &[u8]) -> __red_asn1::Result<(&[u8], Self)> {
+                    let (raw, value) = #inner::parse(raw)?;
+                    return Ok((raw, Self(value)));
+                }
+            }
+        }
+    };
+
+    // the tag of a choice selects its alternative, so X.680 does not
+    // allow to replace it. The generic newtypes are checked when built
+    // or parsed, since the check depends on the type parameters.
+    let implicit_check =
+        match newtype.implicit && newtype.generics.params.is_empty() {
+            true => {
+                let message = format!(
+                    "the inner type of {} is a choice, so it cannot be \
+                     implicitly tagged",
+                    name
+                );
+                quote_spanned! {kind.span()=>
+                    const _: () = assert!(!#inner::CHOICE, #message);
+                }
+            }
+            false => quote! {},
+        };
+
+    let generics = code_generics_with_bounds(&newtype.generics, &[kind]);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let code = quote! {
+        #implicit_check
+
+        impl #impl_generics __red_asn1::Asn1Object for #name #ty_generics #where_clause {
+            #tagged_code
+
+            fn validate(&self) -> __red_asn1::Result<()> {
+                return #inner::validate(&self.0);
+            }
+        }
+    };
+
+    return code_crate_scope(&newtype.crate_path, code);
+}
@@ -0,0 +1,112 @@
+use super::parse_definitions::{NewtypeDefinition, TagDefinition};
+use super::parse_error::{ParseError, ParseResult};
+use super::parser::{
+    default_crate_path, parse_attr_list, parse_path, parse_tag_attr, CRATE_ATTR,
+};
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Data, DataStruct, DeriveInput, Fields, Meta, NestedMeta, Path,
+};
+
+static ASN1_NEWTYPE_ATTR: &str = "newtype";
+static IMPLICIT_ATTR: &str = "implicit";
+static EXPLICIT_ATTR: &str = "explicit";
+
+/// Public method to parse the definition of a tuple struct which
+/// derives Newtype
+pub fn parse_newtype(ast: DeriveInput) -> ParseResult<NewtypeDefinition> {
+    if let Data::Struct(data_struct) = ast.data {
+        let kind = parse_newtype_kind(&ast.ident, data_struct)?;
+        let (tag, implicit, crate_path) = parse_newtype_attrs(&ast.attrs)?;
+
+        return Ok(NewtypeDefinition {
+            name: ast.ident,
+            generics: ast.generics,
+            kind,
+            tag,
+            implicit,
+            crate_path,
+        });
+    } else {
+        return Err(ParseError::NotStruct.at(ast.ident.span()));
+    }
+}
+
+fn parse_newtype_kind(
+    name: &syn::Ident,
+    data_struct: DataStruct,
+) -> ParseResult<syn::Type> {
+    match data_struct.fields {
+        Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => {
+            return Ok(fields.unnamed[0].ty.clone());
+        }
+        _ => {
+            return Err(ParseError::InvalidNewtype.at(name.span()));
+        }
+    }
+}
+
+/// Parse the outer tag of the newtype and its tagging, which
+/// is explicit by default, and the path of the red_asn1 crate
+fn parse_newtype_attrs(
+    attrs: &[Attribute],
+) -> ParseResult<(Option<TagDefinition>, bool, Path)> {
+    let mut tag = None;
+    let mut implicit = false;
+    let mut crate_path = default_crate_path();
+
+    for attr in attrs.iter() {
+        if attr.path.segments.is_empty()
+            || attr.path.segments[0].ident != ASN1_NEWTYPE_ATTR
+        {
+            continue;
+        }
+
+        for subattr in parse_attr_list(attr)? {
+            match subattr {
+                NestedMeta::Meta(Meta::NameValue(ref name_value)) => {
+                    match parse_tag_attr(name_value)? {
+                        Some(tag_definition) => {
+                            if tag.is_some() {
+                                return Err(ParseError::MultipleTags(
+                                    attr.tts.to_string(),
+                                )
+                                .at(name_value.ident.span()));
+                            }
+                            tag = Some(tag_definition);
+                        }
+                        None if name_value.ident == CRATE_ATTR => {
+                            crate_path = parse_path(attr, &name_value.lit)?;
+                        }
+                        None => {
+                            return Err(ParseError::AttributeUnknown(
+                                name_value.ident.to_string(),
+                            )
+                            .at(name_value.ident.span()));
+                        }
+                    }
+                }
+                NestedMeta::Meta(Meta::Word(ref ident)) => {
+                    if ident == IMPLICIT_ATTR {
+                        implicit = true;
+                    } else if ident == EXPLICIT_ATTR {
+                        implicit = false;
+                    } else {
+                        return Err(ParseError::AttributeUnknown(
+                            ident.to_string(),
+                        )
+                        .at(ident.span()));
+                    }
+                }
+                _ => {
+                    return Err(ParseError::AttributeInvalidFormat(
+                        attr.tts.to_string(),
+                    )
+                    .at(subattr.span()));
+                }
+            }
+        }
+    }
+
+    return Ok((tag, implicit, crate_path));
+}
@@ -0,0 +1,83 @@
+use super::parse_definitions::OpenTypeDefinition;
+use super::sequence_coder::code_crate_scope;
+use proc_macro2::TokenStream;
+use syn::spanned::Spanned;
+
+/// Function to write the code of the methods to build/parse an open
+/// type used by Asn1OpenType.
+pub fn code_open_type(open_type: &OpenTypeDefinition) -> TokenStream {
+    let name = &open_type.name;
+    let discriminant = &open_type.discriminant;
+    let name_str = name.to_string();
+
+    let mut build_arms = quote! {};
+    let mut parse_calls = quote! {};
+    let mut unknown_parse = quote! {
+        return Err(__red_asn1::Error::UnknownDiscriminant(#name_str));
+    };
+
+    for variant in &open_type.variants {
+        let id = &variant.id;
+        let kind = &variant.kind;
+
+        match &variant.discriminant_value {
+            Some(discriminant_value) => {
+                build_arms = quote! {
+                    #build_arms
+                    #name::#id(value) => {
+                        __red_asn1::Asn1Object::build(value)
+                    }
+                };
+
+                parse_calls = quote! {
+                    #parse_calls
+                    if *discriminant == #discriminant_value {
+                        let (raw, value) = <#kind as __red_asn1::Asn1Object>::parse(raw)?;
+                        return Ok((raw, #name::#id(value)));
+                    }
+                };
+            }
+            // the unknown values are stored as they were received, so
+            // the type of the variant must be Any
+            None => {
+                let build_any = quote_spanned! {kind.span()=>
+                    __red_asn1::Any::build(value)
+                };
+                build_arms = quote! {
+                    #build_arms
+                    #name::#id(value) => #build_any,
+                };
+
+                let parse_any = quote_spanned! {kind.span()=>
+                    __red_asn1::Any::parse(raw)?
+                };
+                unknown_parse = quote! {
+                    let (raw, value): (&[u8], #kind) = #parse_any;
+                    return Ok((raw, #name::#id(value)));
+                };
+            }
+        }
+    }
+
+    let code = quote! {
+        impl __red_asn1::Asn1OpenType for #name {
+            type Discriminant = #discriminant;
+
+            fn build_open(&self) -> Vec<u8> {
+                match self {
+                    #build_arms
+                }
+            }
+
+            fn parse_open<'a>(
+                discriminant: &Self::Discriminant,
+                raw: &'a [u8]
+            ) -> __red_asn1::Result<(&'a [u8], Self)> {
+                #parse_calls
+                #unknown_parse
+            }
+        }
+    };
+
+    return code_crate_scope(&open_type.crate_path, code);
+}
@@ -0,0 +1,195 @@
+use super::parse_definitions::{OpenTypeDefinition, OpenValueDefinition};
+use super::parse_error::{ParseError, ParseResult};
+use super::parser::{
+    default_crate_path, parse_attr_list, parse_expr, parse_path, CRATE_ATTR,
+};
+use proc_macro2::TokenStream;
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Data, DataEnum, DeriveInput, Fields, Ident, Lit, Meta,
+    NestedMeta, Path, Type, Variant,
+};
+
+static ASN1_OPEN_TYPE_ATTR: &str = "open_type";
+static ASN1_OPEN_VALUE_ATTR: &str = "open_value";
+static DISCRIMINANT_ATTR: &str = "discriminant";
+static ID_ATTR: &str = "id";
+static UNKNOWN_ATTR: &str = "unknown";
+
+/// Public method to parse the definition of an enum which
+/// derives OpenType
+pub fn parse_open_type(ast: DeriveInput) -> ParseResult<OpenTypeDefinition> {
+    if let Data::Enum(data_enum) = ast.data {
+        return parse_open_type_enum(ast.ident, &ast.attrs, data_enum);
+    } else {
+        return Err(ParseError::NotEnum.at(ast.ident.span()));
+    }
+}
+
+fn parse_open_type_enum(
+    name: Ident,
+    attrs: &Vec<Attribute>,
+    data_enum: DataEnum,
+) -> ParseResult<OpenTypeDefinition> {
+    let (discriminant, crate_path) = parse_open_type_attrs(&name, attrs)?;
+    let mut variants = Vec::new();
+
+    for variant in data_enum.variants {
+        let open_value = parse_open_value(variant)?;
+
+        // only one variant can store the unknown values
+        if open_value.discriminant_value.is_none()
+            && variants.iter().any(|previous: &OpenValueDefinition| {
+                previous.discriminant_value.is_none()
+            })
+        {
+            return Err(ParseError::MultipleUnknownValues(
+                open_value.id.to_string(),
+            )
+            .at(open_value.id.span()));
+        }
+
+        variants.push(open_value);
+    }
+
+    return Ok(OpenTypeDefinition {
+        name,
+        discriminant,
+        crate_path,
+        variants,
+    });
+}
+
+fn parse_open_type_attrs(
+    name: &Ident,
+    attrs: &Vec<Attribute>,
+) -> ParseResult<(Type, Path)> {
+    for attr in attrs {
+        if !attr.path.segments.is_empty()
+            && attr.path.segments[0].ident == ASN1_OPEN_TYPE_ATTR
+        {
+            return parse_open_type_attr(attr);
+        }
+    }
+    return Err(ParseError::NotFoundDiscriminant.at(name.span()));
+}
+
+/// Parse the discriminant type and the path of the red_asn1 crate
+fn parse_open_type_attr(attr: &Attribute) -> ParseResult<(Type, Path)> {
+    let mut discriminant = None;
+    let mut crate_path = default_crate_path();
+
+    for subattr in parse_attr_list(attr)? {
+        match subattr {
+            NestedMeta::Meta(Meta::NameValue(ref name_value))
+                if name_value.ident == DISCRIMINANT_ATTR =>
+            {
+                discriminant = Some(parse_type(attr, &name_value.lit)?);
+            }
+            NestedMeta::Meta(Meta::NameValue(ref name_value))
+                if name_value.ident == CRATE_ATTR =>
+            {
+                crate_path = parse_path(attr, &name_value.lit)?;
+            }
+            NestedMeta::Meta(ref meta) => {
+                return Err(ParseError::AttributeUnknown(
+                    meta.name().to_string(),
+                )
+                .at(meta.name().span()));
+            }
+            _ => {
+                return Err(ParseError::AttributeInvalidFormat(
+                    attr.tts.to_string(),
+                )
+                .at(subattr.span()));
+            }
+        }
+    }
+
+    let discriminant = discriminant
+        .ok_or_else(|| ParseError::NotFoundDiscriminant.at(attr.span()))?;
+    return Ok((discriminant, crate_path));
+}
+
+/// Parse a type, given as an string literal
+fn parse_type(attr: &Attribute, lit: &Lit) -> ParseResult<Type> {
+    if let Lit::Str(ref value) = lit {
+        if let Ok(kind) = value.parse::<Type>() {
+            return Ok(kind);
+        }
+    }
+
+    return Err(
+        ParseError::AttributeInvalidFormat(attr.tts.to_string()).at(lit.span())
+    );
+}
+
+fn parse_open_value(variant: Variant) -> ParseResult<OpenValueDefinition> {
+    let kind = match variant.fields {
+        Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => {
+            fields.unnamed[0].ty.clone()
+        }
+        _ => {
+            return Err(ParseError::InvalidVariant(variant.ident.to_string())
+                .at(variant.ident.span()));
+        }
+    };
+
+    let discriminant_value = parse_open_value_attrs(&variant)?;
+
+    return Ok(OpenValueDefinition {
+        id: variant.ident,
+        kind,
+        discriminant_value,
+    });
+}
+
+/// Parse the value of the discriminant for a variant. It can be
+/// an integer literal or an expression inside an string literal.
+/// In case of the unknown variant, None is returned.
+fn parse_open_value_attrs(
+    variant: &Variant,
+) -> ParseResult<Option<TokenStream>> {
+    let mut discriminant_value = None;
+
+    for attr in variant.attrs.iter() {
+        if attr.path.segments.is_empty()
+            || attr.path.segments[0].ident != ASN1_OPEN_VALUE_ATTR
+        {
+            continue;
+        }
+
+        for subattr in parse_attr_list(attr)? {
+            match subattr {
+                NestedMeta::Meta(Meta::Word(ref word))
+                    if word == UNKNOWN_ATTR =>
+                {
+                    discriminant_value = Some(None);
+                }
+                NestedMeta::Meta(Meta::NameValue(ref name_value))
+                    if name_value.ident == ID_ATTR =>
+                {
+                    discriminant_value =
+                        Some(Some(parse_expr(attr, &name_value.lit)?));
+                }
+                NestedMeta::Meta(ref meta) => {
+                    return Err(ParseError::AttributeUnknown(
+                        meta.name().to_string(),
+                    )
+                    .at(meta.name().span()));
+                }
+                _ => {
+                    return Err(ParseError::AttributeInvalidFormat(
+                        attr.tts.to_string(),
+                    )
+                    .at(subattr.span()));
+                }
+            }
+        }
+    }
+
+    return discriminant_value.ok_or_else(|| {
+        ParseError::NotFoundOpenValue(variant.ident.to_string())
+            .at(variant.ident.span())
+    });
+}
@@ -1,29 +1,143 @@
-use syn::{Ident, PathSegment};
+use syn::{Generics, Ident, Path, Type};
 use proc_macro2::TokenStream;
 
 pub struct SequenceDefinition {
     pub name: Ident,
-    pub application_tag_number: Option<u8>,
+    pub generics: Generics,
+    pub tag: Option<TagDefinition>,
+    pub implicit: bool,
+    /// Skip the unknown trailing elements in parse
+    pub extensible: bool,
+    /// Function to check the constraints of the whole sequence
+    pub validate: Option<Path>,
+    /// Function to fill the computed fields after parse
+    pub post_parse: Option<Path>,
+    /// Path of the red_asn1 crate used by the generated code
+    pub crate_path: Path,
     pub fields: Vec<FieldDefinition>
 }
 
+/// Values specified in the seq attribute
+#[derive(Default)]
+pub struct SequenceAttributes {
+    pub tag: Option<TagDefinition>,
+    pub tag_tagging: Option<TaggingDefinition>,
+    pub tagging: TaggingDefinition,
+    pub strict: bool,
+    pub extensible: bool,
+    pub validate: Option<Path>,
+    pub post_parse: Option<Path>,
+    pub crate_path: Option<Path>
+}
+
+/// Way of tagging the fields of a sequence, as the tagging
+/// of an ASN1 module (DEFINITIONS EXPLICIT/IMPLICIT/AUTOMATIC TAGS)
+#[derive(Clone, Copy, PartialEq)]
+pub enum TaggingDefinition {
+    Explicit,
+    Implicit,
+    /// Implicit, except for choices, which are tagged explicitly
+    Automatic
+}
+
+impl Default for TaggingDefinition {
+    fn default() -> Self {
+        return TaggingDefinition::Explicit;
+    }
+}
+
 pub struct FieldDefinition {
     pub id: Ident,
-    pub kind: Ident,
-    pub sub_kinds: Option<PathSegment>,
+    pub kind: Type,
     pub optional: bool,
-    pub context_tag_number: Option<u8>
+    pub tag: Option<TagDefinition>,
+    pub tagging: TaggingDefinition,
+    pub defined_by: Option<Ident>,
+    /// Value of the field when it is not encoded (DEFAULT)
+    pub default: Option<TokenStream>,
+    /// Reject the default value when it is encoded
+    pub strict: bool,
+    /// Field which stores the unknown trailing elements
+    pub unknown: bool,
+    /// Field whose components are included inline (COMPONENTS OF)
+    pub flatten: bool,
+    /// Module with the functions to build/parse the field value
+    pub with: Option<Path>,
+    /// Field which is not encoded, initialized with the default
+    /// expression, or Default, when it is parsed
+    pub skip: bool,
+    pub constraints: Vec<ConstraintDefinition>
+}
+
+/// Values specified in the seq_field attribute
+#[derive(Default)]
+pub struct FieldAttributes {
+    pub tag: Option<TagDefinition>,
+    pub tagging: Option<TaggingDefinition>,
+    pub defined_by: Option<Ident>,
+    pub default: Option<TokenStream>,
+    pub unknown: bool,
+    /// The field is optional or not, regardless of its type
+    pub optional: Option<bool>,
+    pub flatten: bool,
+    pub with: Option<Path>,
+    pub skip: bool,
+    pub constraints: Vec<ConstraintDefinition>
+}
+
+/// Constraint of the value of a field, checked in parse and validate
+pub enum ConstraintDefinition {
+    /// Range of sizes (SIZE), with its representation for errors
+    Size(TokenStream, String),
+    /// Range of values, with its representation for errors
+    Range(TokenStream, String),
+    /// Permitted characters (FROM)
+    Alphabet(String)
 }
 
 
+/// Prefix of the names of the generated functions, to avoid
+/// collisions with the functions defined by the user
+static GENERATED_PREFIX: &str = "__red_asn1_";
+
 impl FieldDefinition {
+    /// Name of the field, without the prefix of raw identifiers,
+    /// as `type` for `r#type`
+    pub fn name(&self) -> String {
+        let name = self.id.to_string();
+        return name.trim_start_matches("r#").to_string();
+    }
+
     pub fn parser_name(&self) -> Ident {
-        let concatenated = format!("parse_{}", self.id);
+        let concatenated =
+            format!("{}parse_{}", GENERATED_PREFIX, self.name());
+        return Ident::new(&concatenated, self.id.span());
+    }
+
+    /// Name of the local variable which stores the parsed field value
+    pub fn value_name(&self) -> Ident {
+        let concatenated =
+            format!("{}field_{}", GENERATED_PREFIX, self.name());
+        return Ident::new(&concatenated, self.id.span());
+    }
+
+    /// Name of the local variable which stores the offset of the
+    /// parsed field
+    pub fn offset_name(&self) -> Ident {
+        let concatenated =
+            format!("{}offset_{}", GENERATED_PREFIX, self.name());
+        return Ident::new(&concatenated, self.id.span());
+    }
+
+    pub fn checker_name(&self) -> Ident {
+        let concatenated =
+            format!("{}check_{}", GENERATED_PREFIX, self.name());
         return Ident::new(&concatenated, self.id.span());
     }
 
     pub fn builder_name(&self) -> Ident {
-        let concatenated = format!("build_{}", self.id);
+        let concatenated =
+            format!("{}build_{}", GENERATED_PREFIX, self.name());
         return Ident::new(&concatenated, self.id.span());
     }
 
@@ -31,14 +145,88 @@ impl FieldDefinition {
 
 pub struct FieldCode {
     pub builder: TokenStream,
-    pub parser: TokenStream
+    pub parser: TokenStream,
+    /// Function to check the constraints of the field, if any
+    pub checker: TokenStream
 }
 
 
 pub struct SequenceInnerCallsCode {
     pub build_calls: TokenStream,
     pub parse_calls: TokenStream,
+    pub field_values: TokenStream,
+    /// Calls to check the constraints of the fields
+    pub check_calls: TokenStream,
+    /// Calls to check the constraints of the parsed fields, whose
+    /// errors are reported at the offsets of the fields
+    pub parse_check_calls: TokenStream,
+    /// Calls to validate the inner objects of the fields
+    pub validate_calls: TokenStream,
     pub components_unit_functions: TokenStream
 }
 
 
+
+pub struct OpenTypeDefinition {
+    pub name: Ident,
+    pub discriminant: Type,
+    pub crate_path: Path,
+    pub variants: Vec<OpenValueDefinition>
+}
+
+pub struct OpenValueDefinition {
+    pub id: Ident,
+    pub kind: Type,
+    /// Value of the discriminant for this variant, None for
+    /// the variant which stores the unknown values
+    pub discriminant_value: Option<TokenStream>
+}
+
+/// Definition of a tuple struct with one field, which is built/parsed
+/// as the inner type, with an optional outer tag
+pub struct NewtypeDefinition {
+    pub name: Ident,
+    pub generics: Generics,
+    pub kind: Type,
+    pub tag: Option<TagDefinition>,
+    pub implicit: bool,
+    pub crate_path: Path
+}
+
+/// Class of a tag specified in an attribute
+#[derive(Clone, Copy, PartialEq)]
+pub enum TagClassDefinition {
+    Universal,
+    Application,
+    Context,
+    Private
+}
+
+/// Tag specified in an attribute, which wraps the value
+#[derive(Clone, Copy)]
+pub struct TagDefinition {
+    pub class: TagClassDefinition,
+    pub number: u32
+}
+
+pub struct ChoiceDefinition {
+    pub name: Ident,
+    pub alternatives: Vec<AlternativeDefinition>,
+    pub crate_path: Path
+}
+
+/// Values specified in the choice attribute
+#[derive(Default)]
+pub struct ChoiceAttributes {
+    /// Tag the alternatives by their order (AUTOMATIC TAGS)
+    pub automatic_tags: bool,
+    pub crate_path: Option<Path>
+}
+
+pub struct AlternativeDefinition {
+    pub id: Ident,
+    pub kind: Type,
+    pub tag: Option<TagDefinition>,
+    /// The tag is automatic, so it is explicit only for choices
+    pub automatic: bool
+}
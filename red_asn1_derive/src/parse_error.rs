@@ -1,23 +1,223 @@
+use proc_macro2::{Span, TokenStream};
 use std::fmt;
 
-pub type ParseResult<T> = Result<T, ParseError>;
+pub type ParseResult<T> = Result<T, SpannedParseError>;
 
 
 #[derive(Clone, Debug)]
 pub enum ParseError {
 
-    /// The field has not attribute tag seq_field
-    NotFoundAttributeTag,
+    /// The tag number is not an integer or is out of range
     InvalidTagNumberValue,
 
     AttributeInvalidFormat(String),
     AttributeUnknown(String),
-    /// The data type with [derive(Sequence)] it is not an struct
-    NotStruct
+    /// The data type with [derive(Sequence)] or [derive(Newtype)]
+    /// it is not an struct
+    NotStruct,
+    /// The struct with [derive(Sequence)] has not named fields
+    NotNamedFields,
+    /// The struct with [derive(Newtype)] has not one unnamed field
+    InvalidNewtype,
+    /// The data type with [derive(OpenType)] or [derive(Choice)]
+    /// it is not an enum
+    NotEnum,
+    /// The field referenced by defined_by is not a previous field
+    DefinedByUnknownField(String),
+    /// The enum with [derive(OpenType)] has no discriminant type
+    NotFoundDiscriminant,
+    /// The variant of an open type or choice has not one unnamed field
+    InvalidVariant(String),
+    /// The variant of an open type has not id or unknown flag
+    NotFoundOpenValue(String),
+    /// More than one variant of an open type has the unknown flag
+    MultipleUnknownValues(String),
+    /// The optional field defined by other field has no tag and it is
+    /// not the last one, so its absence cannot be detected
+    UntaggedOptionalOpenTypeNotLast(String),
+    /// The enum with [derive(Choice)] has no variants
+    EmptyChoice,
+    /// The tagging is not "explicit" or "implicit"
+    InvalidTagging(String),
+    /// The field defined by other field is tagged as implicit,
+    /// but an open type requires explicit tagging
+    ImplicitOpenType(String),
+    /// More than one tag is specified for the same field
+    MultipleTags(String),
+    /// An optional field has a default value
+    OptionalDefault(String),
+    /// The field which stores the unknown elements is not the last one
+    UnknownFieldNotLast(String),
+    /// The range of a constraint is not a range or a value
+    InvalidConstraint(String),
+    /// The flattened field has attributes of a component, as a tag,
+    /// but it has no encoding of its own
+    InvalidFlatten(String),
+    /// The field is flattened in a sequence with automatic tagging, whose
+    /// tags would be numbered without its components
+    AutomaticFlatten(String),
+    /// The field with a custom codec is tagged as implicit, but the
+    /// tag of the value built by the codec is unknown
+    ImplicitWith(String),
+    /// The field with a custom codec is optional (or has default value)
+    /// without tag, but the tag of the value built by the codec is unknown
+    UntaggedOptionalWith(String),
+    /// The field with a custom codec is defined by other field or unknown
+    IncompatibleWith(String),
+    /// The skipped field has attributes of a component, as a tag,
+    /// but it is not encoded
+    InvalidSkip(String)
+}
+
+impl ParseError {
+    /// Set the location of the code which produces the error
+    pub fn at(self, span: Span) -> SpannedParseError {
+        return SpannedParseError { error: self, span };
+    }
 }
 
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Display::fmt(&self, f)
+        match self {
+            ParseError::InvalidTagNumberValue => write!(
+                f,
+                "invalid tag number, expected an integer between 0 and {}",
+                u32::MAX
+            ),
+            ParseError::AttributeInvalidFormat(attr) => {
+                write!(f, "invalid format of attribute: {}", attr)
+            }
+            ParseError::AttributeUnknown(name) => {
+                write!(f, "unknown attribute: {}", name)
+            }
+            ParseError::NotStruct => write!(f, "expected a struct"),
+            ParseError::NotNamedFields => write!(
+                f,
+                "expected a struct with named fields, \
+                 tuple structs must derive Newtype"
+            ),
+            ParseError::InvalidNewtype => {
+                write!(f, "expected a tuple struct with one field")
+            }
+            ParseError::NotEnum => write!(f, "expected an enum"),
+            ParseError::DefinedByUnknownField(name) => write!(
+                f,
+                "defined_by must reference a previous field, \
+                 but {} is not",
+                name
+            ),
+            ParseError::NotFoundDiscriminant => write!(
+                f,
+                "missing discriminant type, \
+                 as #[open_type(discriminant = \"Type\")]"
+            ),
+            ParseError::InvalidVariant(name) => write!(
+                f,
+                "variant {} must have one unnamed field",
+                name
+            ),
+            ParseError::NotFoundOpenValue(name) => write!(
+                f,
+                "variant {} requires #[open_value(id = ...)] \
+                 or #[open_value(unknown)]",
+                name
+            ),
+            ParseError::MultipleUnknownValues(name) => write!(
+                f,
+                "variant {} cannot be unknown, \
+                 there is already an unknown variant",
+                name
+            ),
+            ParseError::UntaggedOptionalOpenTypeNotLast(name) => write!(
+                f,
+                "field {} is optional and defined by other field, \
+                 so it must be tagged or be the last one",
+                name
+            ),
+            ParseError::EmptyChoice => {
+                write!(f, "a choice requires at least one variant")
+            }
+            ParseError::InvalidTagging(tagging) => write!(
+                f,
+                "invalid tagging {}, expected \"explicit\" or \"implicit\"",
+                tagging
+            ),
+            ParseError::ImplicitOpenType(name) => write!(
+                f,
+                "field {} is defined by other field, \
+                 so it cannot be implicitly tagged",
+                name
+            ),
+            ParseError::MultipleTags(attr) => {
+                write!(f, "only one tag can be specified: {}", attr)
+            }
+            ParseError::OptionalDefault(name) => write!(
+                f,
+                "optional field {} cannot have a default value",
+                name
+            ),
+            ParseError::UnknownFieldNotLast(name) => write!(
+                f,
+                "field {} stores the unknown elements, \
+                 so it must be the last one",
+                name
+            ),
+            ParseError::InvalidConstraint(range) => write!(
+                f,
+                "invalid constraint {}, expected a range as \"1..\" \
+                 or \"0..=65535\", or a value",
+                range
+            ),
+            ParseError::InvalidFlatten(name) => write!(
+                f,
+                "field {} is flattened, so it cannot be tagged, optional, \
+                 with default value, defined by other field, unknown \
+                 or constrained",
+                name
+            ),
+            ParseError::AutomaticFlatten(name) => write!(
+                f,
+                "field {} is flattened, so it cannot be in a sequence \
+                 with automatic tags",
+                name
+            ),
+            ParseError::ImplicitWith(name) => write!(
+                f,
+                "field {} has a custom codec, \
+                 so it cannot be implicitly tagged",
+                name
+            ),
+            ParseError::UntaggedOptionalWith(name) => write!(
+                f,
+                "field {} has a custom codec, so it must be tagged \
+                 to be optional or have a default value",
+                name
+            ),
+            ParseError::IncompatibleWith(name) => write!(
+                f,
+                "field {} has a custom codec, so it cannot be \
+                 defined by other field or unknown",
+                name
+            ),
+            ParseError::InvalidSkip(name) => write!(
+                f,
+                "field {} is skipped, so it only accepts a default value",
+                name
+            ),
+        }
+    }
+}
+
+/// Error with the location of the code which produces it, to be
+/// reported by the compiler
+#[derive(Clone, Debug)]
+pub struct SpannedParseError {
+    pub error: ParseError,
+    pub span: Span
+}
+
+impl SpannedParseError {
+    pub fn to_compile_error(&self) -> TokenStream {
+        return syn::Error::new(self.span, &self.error).to_compile_error();
     }
 }
@@ -1,24 +1,59 @@
-use super::parse_definitions::{FieldDefinition, SequenceDefinition};
+use super::parse_definitions::{
+    ConstraintDefinition, FieldAttributes, FieldDefinition, SequenceAttributes, SequenceDefinition,
+    TagClassDefinition, TagDefinition, TaggingDefinition,
+};
 use super::parse_error::{ParseError, ParseResult};
+use proc_macro2::TokenStream;
+use syn::spanned::Spanned;
 use syn::{
-    Attribute, Data, DataStruct, DeriveInput, Field, Fields, FieldsNamed,
-    GenericArgument, Ident, Meta, PathArguments, PathSegment, Type,
+    parse_quote, Attribute, Data, DataStruct, DeriveInput, Field, Fields,
+    FieldsNamed, Generics, Ident, Meta, NestedMeta, Path, Type,
 };
 
 static OPTIONAL_TYPE: &str = "Optional";
-static OPTION_TYPE: &str = "Option";
+static OPTION_PATHS: &[&[&str]] = &[
+    &["Option"],
+    &["std", "option", "Option"],
+    &["core", "option", "Option"],
+];
 static ASN1_SEQ_ATTR: &str = "seq";
 static ASN1_SEQ_FIELD_ATTR: &str = "seq_field";
 static TAG_NUMBER_ATTR: &str = "context_tag";
 static APPLICATION_TAG_ATTR: &str = "application_tag";
+static PRIVATE_TAG_ATTR: &str = "private_tag";
+static UNIVERSAL_TAG_ATTR: &str = "universal_tag";
+static DEFINED_BY_ATTR: &str = "defined_by";
+static TAGGING_ATTR: &str = "tagging";
+static IMPLICIT_ATTR: &str = "implicit";
+static EXPLICIT_ATTR: &str = "explicit";
+static DEFAULT_ATTR: &str = "default";
+static STRICT_ATTR: &str = "strict";
+static EXTENSIBLE_ATTR: &str = "extensible";
+static UNKNOWN_ATTR: &str = "unknown";
+static OPTIONAL_ATTR: &str = "optional";
+static SIZE_ATTR: &str = "size";
+static RANGE_ATTR: &str = "range";
+static ALPHABET_ATTR: &str = "alphabet";
+static VALIDATE_ATTR: &str = "validate";
+pub static AUTOMATIC_TAGS_ATTR: &str = "automatic_tags";
+static FLATTEN_ATTR: &str = "flatten";
+static WITH_ATTR: &str = "with";
+static SKIP_ATTR: &str = "skip";
+static POST_PARSE_ATTR: &str = "post_parse";
+pub static CRATE_ATTR: &str = "crate";
 
 /// Public method to parse thre definition of an struct which
 /// derives Sequence
 pub fn parse_sequence(ast: DeriveInput) -> ParseResult<SequenceDefinition> {
     if let Data::Struct(data_struct) = ast.data {
-        return parse_sequence_struct(ast.ident, &ast.attrs, data_struct);
+        return parse_sequence_struct(
+            ast.ident,
+            ast.generics,
+            &ast.attrs,
+            data_struct,
+        );
     } else {
-        return Err(ParseError::NotStruct);
+        return Err(ParseError::NotStruct.at(ast.ident.span()));
     }
 }
 
@@ -26,55 +61,115 @@ pub fn parse_sequence(ast: DeriveInput) -> ParseResult<SequenceDefinition> {
 /// it is an struct.
 fn parse_sequence_struct(
     seq_name: Ident,
+    seq_generics: Generics,
     seq_attrs: &Vec<Attribute>,
     data_struct: DataStruct,
 ) -> ParseResult<SequenceDefinition> {
-    let fields = parse_sequence_fields(data_struct)?;
-    let mut application_tag_number: Option<u8> = None;
+    let sequence_attrs = parse_sequence_attrs(seq_attrs)?;
+    let fields = parse_sequence_fields(data_struct, &sequence_attrs)?;
 
-    match parse_sequence_attrs(seq_attrs) {
-        Ok(tag_number) => {
-            application_tag_number = tag_number;
-        }
-        Err(parse_error) => match parse_error {
-            ParseError::NotFoundAttributeTag => {}
-            _ => {
-                return Err(parse_error);
-            }
-        },
-    }
+    // the tag of the struct follows the tagging of the fields, which
+    // is implicit with automatic tagging, unless it is specified
+    let implicit = sequence_attrs.tag_tagging.unwrap_or(sequence_attrs.tagging)
+        != TaggingDefinition::Explicit;
 
     return Ok(SequenceDefinition {
         name: seq_name,
-        application_tag_number: application_tag_number,
+        generics: seq_generics,
+        tag: sequence_attrs.tag,
+        implicit,
+        extensible: sequence_attrs.extensible,
+        validate: sequence_attrs.validate,
+        post_parse: sequence_attrs.post_parse,
+        crate_path: sequence_attrs
+            .crate_path
+            .unwrap_or_else(default_crate_path),
         fields,
     });
 }
 
 fn parse_sequence_fields(
     data_struct: DataStruct,
+    sequence_attrs: &SequenceAttributes,
 ) -> ParseResult<Vec<FieldDefinition>> {
-    if let Fields::Named(fields_named) = data_struct.fields {
-        return parse_structure_fields(fields_named);
+    match data_struct.fields {
+        Fields::Named(fields_named) => {
+            return parse_structure_fields(fields_named, sequence_attrs);
+        }
+        // tuple structs must derive Newtype
+        Fields::Unnamed(fields_unnamed) => {
+            return Err(ParseError::NotNamedFields.at(fields_unnamed.span()));
+        }
+        Fields::Unit => {
+            return Err(
+                ParseError::NotNamedFields.at(data_struct.struct_token.span)
+            );
+        }
     }
-
-    // all fields of an struct are named
-    unreachable!()
 }
 
 fn parse_structure_fields(
     fields: FieldsNamed,
+    sequence_attrs: &SequenceAttributes,
 ) -> ParseResult<Vec<FieldDefinition>> {
     let mut fields_defs: Vec<FieldDefinition> = Vec::new();
 
     for field in fields.named {
-        fields_defs.push(parse_structure_field(field)?);
+        // the skipped fields are not components of the sequence
+        let index = fields_defs.iter().filter(|field| !field.skip).count();
+        let field_def = parse_structure_field(field, index, sequence_attrs)?;
+
+        if let Some(defined_by) = &field_def.defined_by {
+            if !fields_defs.iter().any(|previous| &previous.id == defined_by)
+            {
+                return Err(ParseError::DefinedByUnknownField(
+                    defined_by.to_string(),
+                )
+                .at(defined_by.span()));
+            }
+        }
+
+        // the unknown elements are the trailing ones
+        if let Some(previous) = fields_defs.iter().find(|field| field.unknown)
+        {
+            if !field_def.skip {
+                return Err(ParseError::UnknownFieldNotLast(
+                    previous.id.to_string(),
+                )
+                .at(previous.id.span()));
+            }
+        }
+
+        fields_defs.push(field_def);
+    }
+
+    // an untagged optional open type is absent when there are no more
+    // data, so it must be the last component
+    let encoded_fields: Vec<&FieldDefinition> =
+        fields_defs.iter().filter(|field| !field.skip).collect();
+    if let Some((_, previous_fields)) = encoded_fields.split_last() {
+        if let Some(field) = previous_fields.iter().find(|field| {
+            field.defined_by.is_some() && field.optional && field.tag.is_none()
+        }) {
+            return Err(ParseError::UntaggedOptionalOpenTypeNotLast(
+                field.id.to_string(),
+            )
+            .at(field.id.span()));
+        }
     }
 
     return Ok(fields_defs);
 }
 
-fn parse_structure_field(field: Field) -> ParseResult<FieldDefinition> {
+/// Parse a field of the structure. The tagging of the sequence is used
+/// in case the field does not specify if its tag is explicit or implicit.
+/// With automatic tagging, the fields without tag are tagged with the
+/// context tag of their index, which does not count the skipped fields.
+fn parse_structure_field(
+    field: Field,
+    index: usize,
+    sequence_attrs: &SequenceAttributes,
+) -> ParseResult<FieldDefinition> {
     let field_name;
     if let Some(name) = field.ident {
         field_name = name;
@@ -83,178 +178,492 @@ fn parse_structure_field(field: Field) -> ParseResult<FieldDefinition> {
         unreachable!();
     }
 
-    let (field_type, field_sub_types) = parse_field_type(&field.ty);
-    let mut context_tag_number = None;
-    let optional = is_field_optional(&field_type);
+    let field_attrs = parse_field_attrs(&field.attrs)?;
+    let crate_path = sequence_attrs
+        .crate_path
+        .clone()
+        .unwrap_or_else(default_crate_path);
+    let optional = field_attrs
+        .optional
+        .unwrap_or(is_field_optional(&field.ty, &crate_path));
+
+    // skipped fields are not encoded, so they only have an initial value
+    if field_attrs.skip
+        && (field_attrs.optional.is_some()
+            || field_attrs.tag.is_some()
+            || field_attrs.tagging.is_some()
+            || field_attrs.defined_by.is_some()
+            || field_attrs.unknown
+            || field_attrs.flatten
+            || field_attrs.with.is_some()
+            || !field_attrs.constraints.is_empty())
+    {
+        return Err(ParseError::InvalidSkip(field_name.to_string())
+            .at(field_name.span()));
+    }
 
-    match parse_field_attrs(&field.attrs) {
-        Ok(tag_number) => {
-            context_tag_number = tag_number;
+    // open types are always explicitly tagged, as ANY in
+    // an IMPLICIT TAGS module, and so are choices, which are
+    // known by their type in the generated code, as with automatic tags
+    let tagging = match (field_attrs.tagging, &field_attrs.defined_by) {
+        (Some(TaggingDefinition::Implicit), Some(_)) => {
+            return Err(ParseError::ImplicitOpenType(field_name.to_string())
+                .at(field_name.span()));
         }
-        Err(parse_error) => match parse_error {
-            ParseError::NotFoundAttributeTag => {}
-            _ => {
-                return Err(parse_error);
-            }
+        (_, Some(_)) => TaggingDefinition::Explicit,
+        (Some(field_tagging), None) => field_tagging,
+        (None, None) => match sequence_attrs.tagging {
+            TaggingDefinition::Implicit => TaggingDefinition::Automatic,
+            tagging => tagging,
         },
+    };
+
+    let tag = match field_attrs.tag {
+        None if sequence_attrs.tagging == TaggingDefinition::Automatic
+            && !field_attrs.unknown
+            && !field_attrs.skip =>
+        {
+            Some(TagDefinition {
+                class: TagClassDefinition::Context,
+                number: index as u32,
+            })
+        }
+        tag => tag,
+    };
+
+    // the components of a flattened field are the ones encoded
+    if field_attrs.flatten
+        && (optional
+            || field_attrs.tag.is_some()
+            || field_attrs.tagging.is_some()
+            || field_attrs.default.is_some()
+            || field_attrs.defined_by.is_some()
+            || field_attrs.unknown
+            || field_attrs.with.is_some()
+            || !field_attrs.constraints.is_empty())
+    {
+        return Err(ParseError::InvalidFlatten(field_name.to_string())
+            .at(field_name.span()));
+    }
+
+    // the automatic tags are numbered by the components, which include
+    // the ones of the flattened type
+    if field_attrs.flatten
+        && sequence_attrs.tagging == TaggingDefinition::Automatic
+    {
+        return Err(ParseError::AutomaticFlatten(field_name.to_string())
+            .at(field_name.span()));
+    }
+
+    // the tag of a value built by a custom codec is unknown
+    if field_attrs.with.is_some() {
+        if field_attrs.defined_by.is_some() || field_attrs.unknown {
+            return Err(ParseError::IncompatibleWith(field_name.to_string())
+                .at(field_name.span()));
+        }
+        if tag.is_some() && tagging != TaggingDefinition::Explicit {
+            return Err(ParseError::ImplicitWith(field_name.to_string())
+                .at(field_name.span()));
+        }
+        if tag.is_none() && (optional || field_attrs.default.is_some()) {
+            return Err(ParseError::UntaggedOptionalWith(
+                field_name.to_string(),
+            )
+            .at(field_name.span()));
+        }
+    }
+
+    if optional && field_attrs.default.is_some() && !field_attrs.skip {
+        return Err(ParseError::OptionalDefault(field_name.to_string())
+            .at(field_name.span()));
     }
 
     return Ok(FieldDefinition {
         id: field_name,
-        kind: field_type,
-        sub_kinds: field_sub_types,
+        kind: field.ty,
         optional,
-        context_tag_number,
+        tag,
+        tagging,
+        defined_by: field_attrs.defined_by,
+        default: field_attrs.default,
+        strict: sequence_attrs.strict,
+        unknown: field_attrs.unknown,
+        flatten: field_attrs.flatten,
+        with: field_attrs.with,
+        skip: field_attrs.skip,
+        constraints: field_attrs.constraints,
     });
 }
 
-fn parse_field_type(field_type: &Type) -> (Ident, Option<PathSegment>) {
-    if let Type::Path(path) = &field_type {
-        let field_kind = path.path.segments[0].ident.clone();
-        let field_sub_kinds =
-            parse_field_sub_types(&path.path.segments[0].arguments);
-        return (field_kind, field_sub_kinds);
+/// Check if a sequence field is optional based on its type. The type
+/// is optional if its path is the one of Option, as `Option<T>` or
+/// `std::option::Option<T>`, or of Optional, as `Optional<T>` or
+/// `red_asn1::Optional<T>`. Other types named Option are not optional,
+/// and other names, as aliases of Option, require the optional flag.
+fn is_field_optional(field_type: &Type, crate_path: &Path) -> bool {
+    let path = match field_type {
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return false,
+    };
+
+    let segments: Vec<String> = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+
+    let mut optional_path: Vec<String> = crate_path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    optional_path.push(OPTIONAL_TYPE.to_string());
+
+    return OPTION_PATHS.iter().any(|option_path| *option_path == &segments[..])
+        || segments == [OPTIONAL_TYPE]
+        || segments == optional_path;
+}
+
+/// Parse the seq_field attribute of a field. In case the attribute
+/// is not specified, the default attributes are returned.
+fn parse_field_attrs(attrs: &Vec<Attribute>) -> ParseResult<FieldAttributes> {
+    for attr in attrs {
+        if !attr.path.segments.is_empty()
+            && attr.path.segments[0].ident == ASN1_SEQ_FIELD_ATTR
+        {
+            return parse_field_attr(attr);
+        }
     }
-    unreachable!();
+    return Ok(FieldAttributes::default());
 }
 
-fn parse_field_sub_types(arguments: &PathArguments) -> Option<PathSegment> {
-    if let PathArguments::AngleBracketed(brack_argument) = arguments {
-        if let GenericArgument::Type(ty) = &brack_argument.args[0] {
-            if let Type::Path(path) = ty {
-                return Some(path.path.segments[0].clone());
+fn parse_field_attr(attr: &Attribute) -> ParseResult<FieldAttributes> {
+    let mut field_attrs = FieldAttributes::default();
+
+    for subattr in parse_attr_list(attr)? {
+        match subattr {
+            NestedMeta::Meta(Meta::NameValue(ref name_value)) => {
+                if let Some(tag) = parse_tag_attr(name_value)? {
+                    if field_attrs.tag.is_some() {
+                        return Err(ParseError::MultipleTags(
+                            attr.tts.to_string(),
+                        )
+                        .at(name_value.ident.span()));
+                    }
+                    field_attrs.tag = Some(tag);
+                } else if name_value.ident == DEFINED_BY_ATTR {
+                    field_attrs.defined_by =
+                        Some(parse_ident(attr, &name_value.lit)?);
+                } else if name_value.ident == DEFAULT_ATTR {
+                    field_attrs.default =
+                        Some(parse_expr(attr, &name_value.lit)?);
+                } else if name_value.ident == OPTIONAL_ATTR {
+                    field_attrs.optional =
+                        Some(parse_bool(attr, &name_value.lit)?);
+                } else if name_value.ident == WITH_ATTR {
+                    field_attrs.with =
+                        Some(parse_path(attr, &name_value.lit)?);
+                } else if name_value.ident == SIZE_ATTR {
+                    let (range, repr) = parse_range(&name_value.lit)?;
+                    field_attrs
+                        .constraints
+                        .push(ConstraintDefinition::Size(range, repr));
+                } else if name_value.ident == RANGE_ATTR {
+                    let (range, repr) = parse_range(&name_value.lit)?;
+                    field_attrs
+                        .constraints
+                        .push(ConstraintDefinition::Range(range, repr));
+                } else if name_value.ident == ALPHABET_ATTR {
+                    field_attrs.constraints.push(
+                        ConstraintDefinition::Alphabet(parse_alphabet(
+                            attr,
+                            &name_value.lit,
+                        )?),
+                    );
+                } else {
+                    return Err(ParseError::AttributeUnknown(
+                        name_value.ident.to_string(),
+                    )
+                    .at(name_value.ident.span()));
+                }
             }
+            NestedMeta::Meta(Meta::Word(ref ident)) => {
+                if ident == UNKNOWN_ATTR {
+                    field_attrs.unknown = true;
+                } else if ident == OPTIONAL_ATTR {
+                    field_attrs.optional = Some(true);
+                } else if ident == FLATTEN_ATTR {
+                    field_attrs.flatten = true;
+                } else if ident == SKIP_ATTR {
+                    field_attrs.skip = true;
+                } else if ident == IMPLICIT_ATTR {
+                    field_attrs.tagging = Some(TaggingDefinition::Implicit);
+                } else if ident == EXPLICIT_ATTR {
+                    field_attrs.tagging = Some(TaggingDefinition::Explicit);
+                } else {
+                    return Err(ParseError::AttributeUnknown(
+                        ident.to_string(),
+                    )
+                    .at(ident.span()));
+                }
+            }
+            _ => {
+                return Err(ParseError::AttributeInvalidFormat(
+                    attr.tts.to_string(),
+                )
+                .at(subattr.span()));
+            }
+        };
+    }
+
+    return Ok(field_attrs);
+}
+
+/// Parse the items of an attribute, given as a list, such
+/// as `#[seq_field(context_tag = 0, optional)]`
+pub fn parse_attr_list(attr: &Attribute) -> ParseResult<Vec<NestedMeta>> {
+    match attr.parse_meta() {
+        Ok(Meta::List(meta)) => {
+            return Ok(meta.nested.into_iter().collect());
+        }
+        _ => {
+            let path = &attr.path;
+            return Err(ParseError::AttributeInvalidFormat(format!(
+                "#[{} {}]",
+                quote! {#path},
+                attr.tts
+            ))
+            .at(attr.path.span()));
         }
     }
+}
+
+/// Parse a tag of any class, given as `<class>_tag = <number>`.
+/// In case the name is not a tag attribute, None is returned.
+pub fn parse_tag_attr(
+    name_value: &syn::MetaNameValue,
+) -> ParseResult<Option<TagDefinition>> {
+    let class = if name_value.ident == TAG_NUMBER_ATTR {
+        TagClassDefinition::Context
+    } else if name_value.ident == APPLICATION_TAG_ATTR {
+        TagClassDefinition::Application
+    } else if name_value.ident == PRIVATE_TAG_ATTR {
+        TagClassDefinition::Private
+    } else if name_value.ident == UNIVERSAL_TAG_ATTR {
+        TagClassDefinition::Universal
+    } else {
+        return Ok(None);
+    };
 
-    return None;
+    return Ok(Some(TagDefinition {
+        class,
+        number: parse_tag_number(&name_value.lit)?,
+    }));
 }
 
-/// Check if a sequence field is optional based on its type.
-/// If type is "Option" or "Optional", then the field is optional.
-fn is_field_optional(field_type: &Ident) -> bool {
-    if field_type == OPTION_TYPE {
-        return true;
+/// Parse the number of a tag, given as an integer literal
+fn parse_tag_number(lit: &syn::Lit) -> ParseResult<u32> {
+    match lit {
+        syn::Lit::Int(ref value) => {
+            let int_value = value.value();
+            if int_value > u32::MAX as u64 {
+                return Err(ParseError::InvalidTagNumberValue.at(lit.span()));
+            }
+            return Ok(int_value as u32);
+        }
+        _ => {
+            return Err(ParseError::InvalidTagNumberValue.at(lit.span()));
+        }
     }
+}
+
+/// Parse an expression, given as an string literal, or a literal
+pub fn parse_expr(
+    attr: &Attribute,
+    lit: &syn::Lit,
+) -> ParseResult<TokenStream> {
+    if let syn::Lit::Str(ref value) = lit {
+        if let Ok(expr) = value.parse::<syn::Expr>() {
+            return Ok(quote! {#expr});
+        }
 
-    if field_type == OPTIONAL_TYPE {
-        return true;
+        return Err(ParseError::AttributeInvalidFormat(attr.tts.to_string())
+            .at(lit.span()));
     }
 
-    return false;
+    return Ok(quote! {#lit});
 }
 
-fn parse_field_attrs(attrs: &Vec<Attribute>) -> ParseResult<Option<u8>> {
-    for attr in attrs {
-        if attr.path.segments.len() > 0
-            && attr.path.segments[0].ident == ASN1_SEQ_FIELD_ATTR
-        {
-            return parse_field_attr(attr);
+/// Parse an identifier, given as an string literal
+fn parse_ident(attr: &Attribute, lit: &syn::Lit) -> ParseResult<Ident> {
+    if let syn::Lit::Str(ref value) = lit {
+        if let Ok(ident) = value.parse::<Ident>() {
+            return Ok(ident);
         }
     }
-    return Err(ParseError::NotFoundAttributeTag);
+
+    return Err(
+        ParseError::AttributeInvalidFormat(attr.tts.to_string()).at(lit.span())
+    );
 }
 
-fn parse_field_attr(attr: &Attribute) -> ParseResult<Option<u8>> {
-    let mut tag_number = None;
-
-    if let Ok(Meta::List(ref meta)) = attr.parse_meta() {
-        let subattrs: Vec<syn::NestedMeta> =
-            meta.nested.iter().cloned().collect();
-
-        for subattr in subattrs {
-            if let syn::NestedMeta::Meta(ref a) = subattr {
-                match a {
-                    Meta::NameValue(name_value) => {
-                        if name_value.ident == TAG_NUMBER_ATTR {
-                            match name_value.lit {
-                                syn::Lit::Int(ref value) => {
-                                    let int_value = value.value();
-                                    if int_value >= 256 {
-                                        return Err(
-                                            ParseError::InvalidTagNumberValue,
-                                        );
-                                    }
-                                    tag_number = Some(int_value as u8);
-                                }
-                                _ => {
-                                    return Err(
-                                        ParseError::InvalidTagNumberValue,
-                                    );
-                                }
-                            }
-                        } else {
-                            return Err(ParseError::AttributeUnknown(
-                                name_value.ident.to_string(),
-                            ));
-                        }
-                    }
-                    _ => {
-                        return Err(ParseError::AttributeInvalidFormat(
-                            attr.tts.to_string()
-                        ));
-                    }
-                };
+/// Parse a boolean, given as a literal, as `optional = false`
+fn parse_bool(attr: &Attribute, lit: &syn::Lit) -> ParseResult<bool> {
+    if let syn::Lit::Bool(ref value) = lit {
+        return Ok(value.value);
+    }
+
+    return Err(
+        ParseError::AttributeInvalidFormat(attr.tts.to_string()).at(lit.span())
+    );
+}
+
+/// Parse a path, given as an string literal
+pub fn parse_path(attr: &Attribute, lit: &syn::Lit) -> ParseResult<Path> {
+    if let syn::Lit::Str(ref value) = lit {
+        if let Ok(path) = value.parse::<Path>() {
+            return Ok(path);
+        }
+    }
+
+    return Err(
+        ParseError::AttributeInvalidFormat(attr.tts.to_string()).at(lit.span())
+    );
+}
+
+/// Path of the red_asn1 crate used by the generated code, in case
+/// it is not specified with the crate attribute
+pub fn default_crate_path() -> Path {
+    return parse_quote! {himmelblau_red_asn1};
+}
+
+/// Parse a range of a constraint, given as an string literal with
+/// a Rust range, as "1..", "0..=65535" or "..10", or a single value,
+/// as "4", which is the range with only that value. The range is
+/// returned with its representation, to be used in the errors.
+fn parse_range(lit: &syn::Lit) -> ParseResult<(TokenStream, String)> {
+    if let syn::Lit::Str(ref value) = lit {
+        match value.parse::<syn::Expr>() {
+            Ok(syn::Expr::Range(range)) => {
+                return Ok((quote! {#range}, value.value()));
+            }
+            Ok(syn::Expr::Lit(_)) | Ok(syn::Expr::Unary(_)) => {
+                let single = value.parse::<syn::Expr>().unwrap();
+                return Ok((quote! {#single..=#single}, value.value()));
             }
+            _ => {}
         }
     }
 
-    return Ok(tag_number);
+    return Err(
+        ParseError::InvalidConstraint(quote! {#lit}.to_string()).at(lit.span())
+    );
+}
+
+/// Parse the permitted alphabet of a string, given as an string literal
+fn parse_alphabet(attr: &Attribute, lit: &syn::Lit) -> ParseResult<String> {
+    if let syn::Lit::Str(ref value) = lit {
+        return Ok(value.value());
+    }
+
+    return Err(
+        ParseError::AttributeInvalidFormat(attr.tts.to_string()).at(lit.span())
+    );
 }
 
-fn parse_sequence_attrs(attrs: &Vec<Attribute>) -> ParseResult<Option<u8>> {
+/// Parse the seq attribute of a struct. In case the attribute
+/// is not specified, the default attributes are returned.
+fn parse_sequence_attrs(
+    attrs: &Vec<Attribute>,
+) -> ParseResult<SequenceAttributes> {
     for attr in attrs {
-        if attr.path.segments.len() > 0
+        if !attr.path.segments.is_empty()
             && attr.path.segments[0].ident == ASN1_SEQ_ATTR
         {
             return parse_seq_attr(attr);
         }
     }
-    return Err(ParseError::NotFoundAttributeTag);
+    return Ok(SequenceAttributes::default());
 }
 
-fn parse_seq_attr(attr: &Attribute) -> ParseResult<Option<u8>> {
-    let mut tag_number = None;
-
-    if let Ok(Meta::List(ref meta)) = attr.parse_meta() {
-        let subattrs: Vec<syn::NestedMeta> =
-            meta.nested.iter().cloned().collect();
-        for subattr in subattrs {
-            if let syn::NestedMeta::Meta(ref a) = subattr {
-                match a {
-                    Meta::NameValue(name_value) => {
-                        if name_value.ident == APPLICATION_TAG_ATTR {
-                            match name_value.lit {
-                                syn::Lit::Int(ref value) => {
-                                    let int_value = value.value();
-                                    if int_value >= 256 {
-                                        return Err(
-                                            ParseError::InvalidTagNumberValue,
-                                        );
-                                    }
-                                    tag_number = Some(int_value as u8);
-                                }
-                                _ => {
-                                    return Err(
-                                        ParseError::InvalidTagNumberValue,
-                                    );
-                                }
-                            }
-                        } else {
-                            return Err(ParseError::AttributeUnknown(
-                                name_value.ident.to_string(),
-                            ));
-                        }
-                    }
-                    _ => {
-                        return Err(ParseError::AttributeInvalidFormat(
-                            attr.tts.to_string()
-                        ));
+fn parse_seq_attr(attr: &Attribute) -> ParseResult<SequenceAttributes> {
+    let mut sequence_attrs = SequenceAttributes::default();
+
+    for subattr in parse_attr_list(attr)? {
+        match subattr {
+            NestedMeta::Meta(Meta::NameValue(ref name_value)) => {
+                if let Some(tag) = parse_tag_attr(name_value)? {
+                    if sequence_attrs.tag.is_some() {
+                        return Err(ParseError::MultipleTags(
+                            attr.tts.to_string(),
+                        )
+                        .at(name_value.ident.span()));
                     }
-                };
+                    sequence_attrs.tag = Some(tag);
+                } else if name_value.ident == TAGGING_ATTR {
+                    sequence_attrs.tagging = parse_tagging(&name_value.lit)?;
+                } else if name_value.ident == VALIDATE_ATTR {
+                    sequence_attrs.validate =
+                        Some(parse_path(attr, &name_value.lit)?);
+                } else if name_value.ident == POST_PARSE_ATTR {
+                    sequence_attrs.post_parse =
+                        Some(parse_path(attr, &name_value.lit)?);
+                } else if name_value.ident == CRATE_ATTR {
+                    sequence_attrs.crate_path =
+                        Some(parse_path(attr, &name_value.lit)?);
+                } else {
+                    return Err(ParseError::AttributeUnknown(
+                        name_value.ident.to_string(),
+                    )
+                    .at(name_value.ident.span()));
+                }
+            }
+            NestedMeta::Meta(Meta::Word(ref ident)) => {
+                if ident == STRICT_ATTR {
+                    sequence_attrs.strict = true;
+                } else if ident == EXTENSIBLE_ATTR {
+                    sequence_attrs.extensible = true;
+                } else if ident == AUTOMATIC_TAGS_ATTR {
+                    sequence_attrs.tagging = TaggingDefinition::Automatic;
+                } else if ident == IMPLICIT_ATTR {
+                    sequence_attrs.tag_tagging =
+                        Some(TaggingDefinition::Implicit);
+                } else if ident == EXPLICIT_ATTR {
+                    sequence_attrs.tag_tagging =
+                        Some(TaggingDefinition::Explicit);
+                } else {
+                    return Err(ParseError::AttributeUnknown(
+                        ident.to_string(),
+                    )
+                    .at(ident.span()));
+                }
             }
+            _ => {
+                return Err(ParseError::AttributeInvalidFormat(
+                    attr.tts.to_string(),
+                )
+                .at(subattr.span()));
+            }
+        };
+    }
+
+    return Ok(sequence_attrs);
+}
+
+/// Parse the tagging of a sequence, given as an string literal
+fn parse_tagging(lit: &syn::Lit) -> ParseResult<TaggingDefinition> {
+    if let syn::Lit::Str(ref value) = lit {
+        let tagging = value.value();
+        if tagging == IMPLICIT_ATTR {
+            return Ok(TaggingDefinition::Implicit);
+        } else if tagging == EXPLICIT_ATTR {
+            return Ok(TaggingDefinition::Explicit);
         }
+        return Err(ParseError::InvalidTagging(format!("\"{}\"", tagging))
+            .at(lit.span()));
     }
 
-    return Ok(tag_number);
+    return Err(
+        ParseError::InvalidTagging(quote! {#lit}.to_string()).at(lit.span())
+    );
 }
@@ -1,7 +1,11 @@
-use super::parse_definitions::{SequenceDefinition, SequenceInnerCallsCode};
-use crate::field_coder::code_field;
-use proc_macro2::TokenStream;
-use syn::Ident;
+use super::parse_definitions::{
+    FieldDefinition, SequenceDefinition, SequenceInnerCallsCode,
+    TaggingDefinition,
+};
+use crate::field_coder::{code_field, code_field_tags};
+use crate::tag_coder::{code_distinct_tags_check, code_explicit_tag};
+use proc_macro2::{TokenStream, TokenTree};
+use syn::{parse_quote, Generics, Ident, Path, Type};
 
 /// Function to write the code of the methods to build/parse a Sequence
 /// used by Asn1Object.
@@ -11,81 +15,424 @@ pub fn code_sequence(sequence: &SequenceDefinition) -> TokenStream {
     let seq_inner_calls = code_sequence_inner_calls(sequence);
     let build_calls = &seq_inner_calls.build_calls;
     let parse_calls = &seq_inner_calls.parse_calls;
+    let parse_check_calls = &seq_inner_calls.parse_check_calls;
+    let field_values = &seq_inner_calls.field_values;
+    let check_calls = &seq_inner_calls.check_calls;
+    let validate_calls = &seq_inner_calls.validate_calls;
     let components_unit_functions = &seq_inner_calls.components_unit_functions;
 
+    let sequence_tag = quote! {
+        __red_asn1::Tag::new_constructed_universal(
+            __red_asn1::SEQUENCE_TAG_NUMBER
+        )
+    };
+
     let build_value = code_build_value(build_calls);
-    let parse_value = code_parse_value(parse_calls, seq_name);
-    let inner_build = code_inner_build();
-    let mut inner_parse = code_inner_parse(seq_name);
+    let check_constraints =
+        code_check_constraints(check_calls, seq_name, &sequence.validate);
+    let mut tags_checks = code_tags_checks(sequence);
+    tags_checks.extend(code_implicit_tags_checks(sequence));
+    tags_checks.extend(code_flatten_checks(sequence));
+    let has_tags_checks = !tags_checks.is_empty();
+    let has_generics = !sequence.generics.params.is_empty();
+
+    // the checks of generic structs are evaluated when they are used,
+    // since they depend on the type parameters
+    let tags_check_call = match has_tags_checks && has_generics {
+        true => quote! {let _ = Self::__RED_ASN1_CHECK_TAGS;},
+        false => quote! {},
+    };
 
+    let components_tags = code_components_tags(sequence);
+    let decode_value = code_decode_value(seq_name, sequence.extensible);
+    let parse_components = code_parse_components(
+        parse_calls,
+        parse_check_calls,
+        field_values,
+        seq_name,
+        &sequence.post_parse,
+        &tags_check_call,
+    );
+
+    let tag;
+    let inner_build;
+    let mut inner_parse;
     let build;
     let parse;
 
-    if let Some(app_tag_number) = sequence.application_tag_number {
-        build = code_build_with_application_tag(app_tag_number);
-        inner_parse = quote! {
-            #inner_parse
+    match &sequence.tag {
+        Some(outer_tag) if !sequence.implicit => {
+            tag = code_explicit_tag(outer_tag);
 
-            fn _parse_application_tag<'a>(
-                &self,
-                raw: &'a [u8]
-            ) -> himmelblau_red_asn1::Result<&'a [u8]> {
-                let (raw, parsed_tag) = himmelblau_red_asn1::Tag::parse(raw)?;
-
-                if parsed_tag != himmelblau_red_asn1::Tag::new(
-                    #app_tag_number,
-                    himmelblau_red_asn1::TagType::Constructed,
-                    himmelblau_red_asn1::TagClass::Application
-                ) {
-                    return Err(himmelblau_red_asn1::Error::UnmatchedTag(
-                        himmelblau_red_asn1::TagClass::Application
-                    ))?;
-                }
+            inner_build = code_inner_build(&sequence_tag);
+            inner_parse = code_inner_parse(seq_name, &sequence_tag);
+            inner_parse = quote! {
+                #inner_parse
 
-                return Ok(raw);
-            }
-        };
+                fn __red_asn1_parse_outer_tag<'a>(
+                    raw: &'a [u8]
+                ) -> __red_asn1::Result<&'a [u8]> {
+                    let (raw, parsed_tag) = __red_asn1::Tag::parse(raw)?;
 
-        parse = code_parse_with_application_tag(seq_name);
-    } else {
-        build = quote! {
-            fn build(&self) -> Vec<u8> {
-                return self._inner_build();
-            }
-        };
+                    if parsed_tag != <Self as __red_asn1::Asn1Object>::tag() {
+                        return Err(__red_asn1::Error::UnmatchedTag {
+                            expected: <Self as __red_asn1::Asn1Object>::tag(),
+                            found: parsed_tag,
+                        })?;
+                    }
 
-        parse = quote! {
-            fn parse(raw: &[u8]) -> himmelblau_red_asn1::Result<(&[u8], Self)> {
-                let mut sequence = Self::default();
-                let raw = sequence._inner_parse(raw)?;
-                return Ok((raw, sequence));
+                    return Ok(raw);
+                }
+            };
+
+            build = code_build_with_explicit_tag();
+            parse = code_parse_with_explicit_tag(seq_name);
+        }
+        _ => {
+            // the implicit tag replaces the SEQUENCE tag
+            tag = match &sequence.tag {
+                Some(outer_tag) => code_explicit_tag(outer_tag),
+                None => sequence_tag,
+            };
+
+            let self_tag = quote! {<Self as __red_asn1::Asn1Object>::tag()};
+            inner_build = code_inner_build(&self_tag);
+            inner_parse = code_inner_parse(seq_name, &self_tag);
+
+            build = quote! {
+                fn build(&self) -> Vec<u8> {
+                    return self.__red_asn1_inner_build();
+                }
+            };
+
+            parse = quote! {
+                fn parse(raw: &[u8]) -> __red_asn1::Result<(&[u8], Self)> {
+                    return Self::__red_asn1_inner_parse(raw);
+                }
             }
         }
     }
 
+    // the types of the fields with a custom codec are not built/parsed
+    // as Asn1Object
+    let encoded_types: Vec<&Type> = sequence
+        .fields
+        .iter()
+        .filter(|field| !field.skip && field.with.is_none())
+        .map(|field| &field.kind)
+        .collect();
+    let generics =
+        code_generics_with_bounds(&sequence.generics, &encoded_types);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let (check_tags, check_tags_eval) = match has_tags_checks {
+        true => (
+            quote! {
+                /// Check that the fields can be distinguished by their tags
+                /// and that the implicitly tagged ones are not choices
+                const __RED_ASN1_CHECK_TAGS: () = {
+                    #tags_checks
+                };
+            },
+            match has_generics {
+                true => quote! {},
+                false => quote! {
+                    const _: () = #seq_name::__RED_ASN1_CHECK_TAGS;
+                },
+            },
+        ),
+        false => (quote! {}, quote! {}),
+    };
+
     let total_exp = quote! {
-        impl himmelblau_red_asn1::Asn1Object for #seq_name {
-            fn tag() -> himmelblau_red_asn1::Tag {
-                return himmelblau_red_asn1::Tag::new_constructed_universal(
-                    himmelblau_red_asn1::SEQUENCE_TAG_NUMBER
-                );
+        impl #impl_generics __red_asn1::Asn1Object for #seq_name #ty_generics #where_clause {
+            const TAGS: &'static [__red_asn1::Tag] = &[#tag];
+
+            /// Outer tag of the sequence, which is the tag of
+            /// the struct in case of being defined
+            fn tag() -> __red_asn1::Tag {
+                return #tag;
             }
 
             #build
             #parse
 
             #build_value
-            #parse_value
+            #decode_value
+
+            fn validate(&self) -> __red_asn1::Result<()> {
+                #validate_calls
+                return self.__red_asn1_check_constraints();
+            }
         }
 
-        impl #seq_name {
+        impl #impl_generics __red_asn1::Asn1Components for #seq_name #ty_generics #where_clause {
+            #components_tags
+            #parse_components
+        }
+
+        impl #impl_generics #seq_name #ty_generics #where_clause {
+            #check_tags
             #components_unit_functions
+            #check_constraints
             #inner_build
             #inner_parse
         }
+
+        #check_tags_eval
+    };
+
+    return code_crate_scope(&sequence.crate_path, total_exp);
+}
+
+/// Function to write the generated code in an anonymous scope which
+/// imports the red_asn1 crate as `__red_asn1`, so the code works with
+/// any path of the crate, as a renamed dependency or a reexport
+pub fn code_crate_scope(crate_path: &Path, code: TokenStream) -> TokenStream {
+    return quote! {
+        const _: () = {
+            use #crate_path as __red_asn1;
+            #code
+        };
+    };
+}
+
+/// Function to write an error of the sequence, which wraps the given
+/// error with the name of the sequence and the offset where it happened
+fn code_sequence_error(
+    seq_name: &Ident,
+    offset: TokenStream,
+    error: TokenStream,
+) -> TokenStream {
+    return quote! {
+        __red_asn1::Error::SequenceError {
+            sequence: stringify!(#seq_name),
+            offset: #offset,
+            error: Box::new(#error),
+        }
     };
+}
+
+/// Function to write the checks that the fields can be distinguished by
+/// their tags, as required by X.680: the tags of each series of fields
+/// which can be absent (optional or with default value), and of the
+/// field which follows the series, must be distinct. The skipped fields
+/// are not encoded, so they are ignored. The trailing components of a
+/// flattened field which can be absent also start a series.
+fn code_tags_checks(sequence: &SequenceDefinition) -> TokenStream {
+    let mut checks = quote! {};
+    for (i, field) in sequence.fields.iter().enumerate() {
+        let tags = match field.flatten {
+            true => {
+                let field_type = &field.kind;
+                quote! {
+                    <#field_type as __red_asn1::Asn1Components>::LAST_TAGS
+                }
+            }
+            false if can_be_absent(field) => code_field_tags(field),
+            false => continue,
+        };
 
-    return total_exp;
+        for next_field in &sequence.fields[(i + 1)..] {
+            if next_field.unknown {
+                break;
+            }
+            if next_field.skip {
+                continue;
+            }
+
+            let message = format!(
+                "fields {} and {} of {} can have the same tag, \
+                 so they cannot be distinguished",
+                field.id, next_field.id, sequence.name
+            );
+            let check = code_distinct_tags_check(
+                &tags,
+                &code_field_tags(next_field),
+                &message,
+                next_field.id.span(),
+            );
+            checks = quote! {
+                #checks
+                #check
+            };
+
+            if !can_be_absent(next_field) {
+                break;
+            }
+        }
+    }
+
+    return checks;
+}
+
+/// The field can be absent from the encoded components, since it is
+/// optional or has a default value
+fn can_be_absent(field: &FieldDefinition) -> bool {
+    return (field.optional || field.default.is_some())
+        && !field.unknown
+        && !field.skip;
+}
+
+/// Function to write the constants of Asn1Components with the tags of
+/// the first and last components, used to check the fields of the
+/// sequences which include them. The components of a flattened field
+/// are considered to be present. In generic structs the tags can depend
+/// on the type parameters, so they are not computed.
+fn code_components_tags(sequence: &SequenceDefinition) -> TokenStream {
+    let unknown = match sequence.fields.iter().any(|field| field.unknown) {
+        true => quote! {const UNKNOWN: bool = true;},
+        false => quote! {},
+    };
+    if !sequence.generics.params.is_empty() {
+        return unknown;
+    }
+
+    let encoded_fields: Vec<&FieldDefinition> =
+        sequence.fields.iter().filter(|field| !field.skip).collect();
+
+    let mut first_tags = Vec::new();
+    for field in &encoded_fields {
+        first_tags.push(code_field_tags(field));
+        if !can_be_absent(field) {
+            break;
+        }
+    }
+
+    let mut last_tags = Vec::new();
+    for field in encoded_fields.iter().rev() {
+        if field.flatten {
+            let field_type = &field.kind;
+            last_tags.push(quote! {
+                <#field_type as __red_asn1::Asn1Components>::LAST_TAGS
+            });
+        }
+        if !can_be_absent(field) {
+            break;
+        }
+        last_tags.push(code_field_tags(field));
+    }
+
+    let first_union = code_tags_union(&first_tags);
+    let last_union = code_tags_union(&last_tags);
+
+    return quote! {
+        #unknown
+        const FIRST_TAGS: &'static [__red_asn1::Tag] = #first_union;
+        const LAST_TAGS: &'static [__red_asn1::Tag] = #last_union;
+    };
+}
+
+/// Function to write the union of several lists of possible tags
+fn code_tags_union(tags_lists: &[TokenStream]) -> TokenStream {
+    return quote! {
+        {
+            const TAGS_LISTS: &[&[__red_asn1::Tag]] = &[
+                #(#tags_lists),*
+            ];
+            const UNION: [
+                __red_asn1::Tag;
+                __red_asn1::tags_union_len(TAGS_LISTS)
+            ] = __red_asn1::tags_union(TAGS_LISTS);
+            &UNION
+        }
+    };
+}
+
+/// Function to write the checks that the flattened fields do not take
+/// the unknown trailing elements, which would include the following
+/// components of the sequence
+fn code_flatten_checks(sequence: &SequenceDefinition) -> TokenStream {
+    let mut checks = quote! {};
+    for field in sequence.fields.iter().filter(|field| field.flatten) {
+        let kind = &field.kind;
+        let message = format!(
+            "field {} of {} is flattened, so its type cannot have \
+             an unknown field",
+            field.id, sequence.name
+        );
+        let check = quote_spanned! {field.id.span()=>
+            assert!(
+                !<#kind as __red_asn1::Asn1Components>::UNKNOWN,
+                #message
+            );
+        };
+        checks = quote! {
+            #checks
+            #check
+        };
+    }
+
+    return checks;
+}
+
+/// Function to write the checks that the fields set as implicit are not
+/// choices, since the tag of a choice selects its alternative, so X.680
+/// does not allow to replace it
+fn code_implicit_tags_checks(sequence: &SequenceDefinition) -> TokenStream {
+    let mut checks = quote! {};
+    for field in &sequence.fields {
+        if field.tag.is_none() || field.tagging != TaggingDefinition::Implicit {
+            continue;
+        }
+
+        let kind = &field.kind;
+        let message = format!(
+            "field {} of {} is a choice, so it cannot be implicitly tagged",
+            field.id, sequence.name
+        );
+        let check = quote_spanned! {field.id.span()=>
+            assert!(
+                !<#kind as __red_asn1::Asn1Object>::CHOICE,
+                #message
+            );
+        };
+        checks = quote! {
+            #checks
+            #check
+        };
+    }
+
+    return checks;
+}
+
+/// Function to add the Asn1Object bound to the type parameters of
+/// the struct which appear in the types of the encoded fields, since
+/// they are built/parsed as the fields. The parameters only used by
+/// other fields, as skipped ones with PhantomData, are not bound.
+pub fn code_generics_with_bounds(
+    generics: &Generics,
+    encoded_types: &[&Type],
+) -> Generics {
+    let mut generics = generics.clone();
+    let type_params: Vec<Ident> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .filter(|param| {
+            encoded_types
+                .iter()
+                .any(|kind| tokens_contain_ident(quote! {#kind}, param))
+        })
+        .collect();
+
+    let where_clause = generics.make_where_clause();
+    for type_param in type_params {
+        where_clause.predicates.push(parse_quote! {
+            #type_param: __red_asn1::Asn1Object
+        });
+    }
+
+    return generics;
+}
+
+/// Checks if the tokens contain the identifier, including the ones
+/// inside groups, as the generic arguments of a type
+fn tokens_contain_ident(tokens: TokenStream, ident: &Ident) -> bool {
+    return tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(token_ident) => token_ident == *ident,
+        TokenTree::Group(group) => tokens_contain_ident(group.stream(), ident),
+        _ => false,
+    });
 }
 
 fn code_build_value(build_calls: &TokenStream) -> TokenStream {
@@ -98,37 +445,144 @@ fn code_build_value(build_calls: &TokenStream) -> TokenStream {
     };
 }
 
-/// To write the `parse_value` function of Asn1Object for Sequence.
-/// In `parse_value` all the parse functions of the members of
-/// the Sequence are called.
-fn code_parse_value(
+/// To write the `decode_value` function of Asn1Object for Sequence,
+/// which parses the components and checks that all the octets of
+/// the value are consumed.
+fn code_decode_value(seq_name: &Ident, extensible: bool) -> TokenStream {
+    let offset = quote! {raw_value.len() - raw.len()};
+
+    // unknown trailing elements of an extensible sequence are skipped
+    let skip_unknown = match extensible {
+        true => {
+            let unknown_error =
+                code_sequence_error(seq_name, offset.clone(), quote! {error});
+            quote! {
+                let mut raw = raw;
+                while raw.len() > 0 {
+                    let (raw_tmp, _) = __red_asn1::Any::parse(raw)
+                        .or_else(|error| Err(#unknown_error))?;
+                    raw = raw_tmp;
+                }
+            }
+        }
+        false => quote! {},
+    };
+    let unconsumed_error = code_sequence_error(
+        seq_name,
+        offset,
+        quote! {__red_asn1::Error::NoAllDataConsumed},
+    );
+
+    return quote! {
+        fn decode_value(raw_value: &[u8]) -> __red_asn1::Result<Self> {
+            let (raw, sequence) =
+                <Self as __red_asn1::Asn1Components>::parse_components(
+                    raw_value
+                )?;
+            #skip_unknown
+
+            if raw.len() > 0 {
+                return Err(#unconsumed_error)?;
+            }
+
+            return Ok(sequence);
+        }
+    };
+}
+
+/// To write the `parse_components` function of Asn1Components for
+/// Sequence, which is used to include the components in other sequence.
+/// In `parse_components` all the parse functions of the members of the
+/// Sequence are called, and the struct is created from the parsed values.
+/// Then, the post parse function fills the computed fields, before the
+/// constraints are checked.
+fn code_parse_components(
     parse_calls: &TokenStream,
+    parse_check_calls: &TokenStream,
+    field_values: &TokenStream,
     seq_name: &Ident,
+    post_parse: &Option<Path>,
+    tags_check_call: &TokenStream,
 ) -> TokenStream {
+    let (sequence_binding, post_parse_call) = match post_parse {
+        Some(post_parse) => {
+            let post_parse_error =
+                code_sequence_error(seq_name, quote! {0}, quote! {error});
+            (
+                quote! {let mut sequence},
+                quote! {
+                    #post_parse(&mut sequence)
+                        .or_else(|error| Err(#post_parse_error))?;
+                },
+            )
+        }
+        None => (quote! {let sequence}, quote! {}),
+    };
+
+    // the offsets of the fields are relative to the first component
     return quote! {
-        fn parse_value(&mut self, raw: &[u8]) -> himmelblau_red_asn1::Result<()> {
+        fn parse_components(
+            raw: &[u8]
+        ) -> __red_asn1::Result<(&[u8], Self)> {
+            #tags_check_call
+            let __red_asn1_raw = raw;
             #parse_calls
 
-            if raw.len() > 0 {
-                return Err(himmelblau_red_asn1::Error::SequenceError(
-                    stringify!(#seq_name).to_string(),
-                    Box::new(himmelblau_red_asn1::Error::from(
-                        himmelblau_red_asn1::Error::NoAllDataConsumed
-                    ))
-                ))?;
+            #sequence_binding = Self {
+                #field_values
+            };
+            #post_parse_call
+            #parse_check_calls
+            sequence.__red_asn1_check_validate()?;
+
+            return Ok((raw, sequence));
+        }
+    };
+}
+
+/// Function to write the `__red_asn1_check_constraints` function of the
+/// structure, which checks the constraints of the fields and the validate
+/// function of the structure, called by `__red_asn1_check_validate`. The
+/// inner objects are not checked, since they check their own constraints
+/// when they are parsed.
+fn code_check_constraints(
+    check_calls: &TokenStream,
+    seq_name: &Ident,
+    validate: &Option<Path>,
+) -> TokenStream {
+    let validate_call = match validate {
+        Some(validate) => {
+            let validate_error =
+                code_sequence_error(seq_name, quote! {0}, quote! {error});
+            quote! {
+                #validate(self).or_else(|error| Err(#validate_error))?;
             }
+        }
+        None => quote! {},
+    };
 
+    return quote! {
+        fn __red_asn1_check_constraints(&self) -> __red_asn1::Result<()> {
+            #check_calls
+            return self.__red_asn1_check_validate();
+        }
+
+        fn __red_asn1_check_validate(&self) -> __red_asn1::Result<()> {
+            #validate_call
             return Ok(());
         }
     };
 }
 
-fn code_inner_build() -> TokenStream {
+/// Function to write the `__red_asn1_inner_build` function of the
+/// structure, which builds the fields with the given tag (the SEQUENCE
+/// tag, or the implicit tag of the struct)
+fn code_inner_build(inner_tag: &TokenStream) -> TokenStream {
     return quote! {
-        fn _inner_build(&self) -> Vec<u8> {
-            let mut built = Self::tag().build();
-            let mut built_value = self.build_value();
-            let mut built_length = himmelblau_red_asn1::build_length(built_value.len());
+        fn __red_asn1_inner_build(&self) -> Vec<u8> {
+            let mut built = #inner_tag.build();
+            let mut built_value = __red_asn1::Asn1Object::build_value(self);
+            let mut built_length = __red_asn1::build_length(built_value.len());
 
             built.append(&mut built_length);
             built.append(&mut built_value);
@@ -138,69 +592,69 @@ fn code_inner_build() -> TokenStream {
     };
 }
 
-/// Function to write the `_inner_parse` function (called from `parse`) of
-/// the structure, which parses the structure tag and length, and calls
-/// parse_value. In case of an application tag in the structure, this
-/// is parsed in the `parse` function
-fn code_inner_parse(seq_name: &Ident) -> TokenStream {
+/// Function to write the `__red_asn1_inner_parse` function (called from
+/// `parse`) of the structure, which enters a level of nesting, parses the
+/// structure tag and length, and calls decode_value. In case of an
+/// explicit tag in the structure, this is parsed in the `parse` function
+fn code_inner_parse(seq_name: &Ident, inner_tag: &TokenStream) -> TokenStream {
+    let header_error =
+        code_sequence_error(seq_name, quote! {0}, quote! {error});
+    let unmatched_tag_error = code_sequence_error(
+        seq_name,
+        quote! {0},
+        quote! {
+            __red_asn1::Error::UnmatchedTag {
+                expected: #inner_tag,
+                found: parsed_tag,
+            }
+        },
+    );
+    let no_data_error = code_sequence_error(
+        seq_name,
+        quote! {0},
+        quote! {__red_asn1::Error::NoDataForLength},
+    );
+
     return quote! {
-        fn _inner_parse<'a>(
-            &mut self,
+        fn __red_asn1_inner_parse<'a>(
             raw: &'a [u8]
-        ) -> himmelblau_red_asn1::Result<&'a [u8]> {
-            let (raw, parsed_tag) = himmelblau_red_asn1::Tag::parse(raw).or_else( |error|
-                Err(himmelblau_red_asn1::Error::SequenceError(
-                    stringify!(#seq_name).to_string(),
-                    Box::new(error.clone())
-                ))
-            )?;
-
-            if parsed_tag != Self::tag() {
-                return Err(himmelblau_red_asn1::Error::SequenceError(
-                    stringify!(#seq_name).to_string(),
-                    Box::new(
-                        himmelblau_red_asn1::Error::UnmatchedTag(
-                            himmelblau_red_asn1::TagClass::Universal
-                        )
-                    )
-                ))
+        ) -> __red_asn1::Result<(&'a [u8], Self)> {
+            let _nesting = __red_asn1::enter_nesting()
+                .or_else(|error| Err(#header_error))?;
+            let (raw_length, parsed_tag) = __red_asn1::Tag::parse(raw)
+                .or_else(|error| Err(#header_error))?;
+
+            if parsed_tag != #inner_tag {
+                return Err(#unmatched_tag_error);
             }
 
-            let (raw, length) = himmelblau_red_asn1::parse_length(raw).or_else( |error|
-                Err(himmelblau_red_asn1::Error::SequenceError(
-                    stringify!(#seq_name).to_string(),
-                    Box::new(error.clone())
-                ))
-            )?;
-
-            if length > raw.len() {
-                return Err(himmelblau_red_asn1::Error::SequenceError(
-                    stringify!(#seq_name).to_string(),
-                    Box::new(himmelblau_red_asn1::Error::from(himmelblau_red_asn1::Error::NoDataForLength))
-                ))?;
+            let (raw_value, length) = __red_asn1::parse_length(raw_length)
+                .or_else(|error| Err(#header_error))?;
+
+            if length > raw_value.len() {
+                return Err(#no_data_error)?;
             }
 
-            let (raw_value, raw) = raw.split_at(length);
-            self.parse_value(raw_value)?;
+            let header_length = raw.len() - raw_value.len();
+            let (raw_value, raw) = raw_value.split_at(length);
+            let sequence =
+                <Self as __red_asn1::Asn1Object>::decode_value(raw_value)
+                    .map_err(|error| error.shift(header_length))?;
 
-            return Ok(raw);
+            return Ok((raw, sequence));
         }
     };
 }
 
 /// Function to write the code of the Asn1Object `build` function for Sequence
-/// in case of having an application tag defined by the seq tag
-fn code_build_with_application_tag(app_tag_number: u8) -> TokenStream {
+/// in case of having an explicit tag defined by the seq attribute
+fn code_build_with_explicit_tag() -> TokenStream {
     return quote! {
         fn build(&self) -> Vec<u8> {
-            let mut built = himmelblau_red_asn1::Tag::new(
-                #app_tag_number,
-                himmelblau_red_asn1::TagType::Constructed,
-                himmelblau_red_asn1::TagClass::Application
-            ).build();
+            let mut built = Self::tag().build();
 
-            let mut built_value = self._inner_build();
-            let mut built_length = himmelblau_red_asn1::build_length(built_value.len());
+            let mut built_value = self.__red_asn1_inner_build();
+            let mut built_length = __red_asn1::build_length(built_value.len());
 
             built.append(&mut built_length);
             built.append(&mut built_value);
@@ -211,36 +665,34 @@ fn code_build_with_application_tag(app_tag_number: u8) -> TokenStream {
 }
 
 /// Function to write the code of the Asn1Object parse function for Sequence
-/// in case of having an application tag defined by the seq tag
-fn code_parse_with_application_tag(seq_name: &Ident) -> TokenStream {
+/// in case of having an explicit tag defined by the seq attribute
+fn code_parse_with_explicit_tag(seq_name: &Ident) -> TokenStream {
+    let header_error =
+        code_sequence_error(seq_name, quote! {0}, quote! {error});
+    let no_data_error = code_sequence_error(
+        seq_name,
+        quote! {0},
+        quote! {__red_asn1::Error::NoDataForLength},
+    );
+
     return quote! {
-        fn parse(raw: &[u8]) -> himmelblau_red_asn1::Result<(&[u8], Self)> {
-            let mut sequence = Self::default();
-            let raw = sequence._parse_application_tag(raw).or_else(
-                |error|
-                Err(himmelblau_red_asn1::Error::SequenceError(
-                    stringify!(#seq_name).to_string(),
-                    Box::new(error.clone())
-                ))
-            )?;
-
-            let (raw, length) = himmelblau_red_asn1::parse_length(raw).or_else(
-                |error|
-                Err(himmelblau_red_asn1::Error::SequenceError(
-                    stringify!(#seq_name).to_string(),
-                    Box::new(error.clone())
-                ))
-            )?;
-
-            if length > raw.len() {
-                return Err(himmelblau_red_asn1::Error::SequenceError(
-                    stringify!(#seq_name).to_string(),
-                    Box::new(himmelblau_red_asn1::Error::from(himmelblau_red_asn1::Error::NoDataForLength))
-                ))?;
+        fn parse(raw: &[u8]) -> __red_asn1::Result<(&[u8], Self)> {
+            let _nesting = __red_asn1::enter_nesting()
+                .or_else(|error| Err(#header_error))?;
+            let raw_length = Self::__red_asn1_parse_outer_tag(raw)
+                .or_else(|error| Err(#header_error))?;
+
+            let (raw_value, length) = __red_asn1::parse_length(raw_length)
+                .or_else(|error| Err(#header_error))?;
+
+            if length > raw_value.len() {
+                return Err(#no_data_error)?;
             }
 
-            let (raw_value, raw) = raw.split_at(length);
-            sequence._inner_parse(raw_value)?;
+            let header_length = raw.len() - raw_value.len();
+            let (raw_value, raw) = raw_value.split_at(length);
+            let (_, sequence) = Self::__red_asn1_inner_parse(raw_value)
+                .map_err(|error| error.shift(header_length))?;
             return Ok((raw, sequence));
         }
     };
@@ -252,43 +704,148 @@ pub fn code_sequence_inner_calls(
     let mut components_unit_functions = quote! {};
     let mut build_calls = quote! {};
     let mut parse_calls = quote! {};
+    let mut field_values = quote! {};
+    let mut check_calls = quote! {};
+    let mut parse_check_calls = quote! {};
+    let mut validate_calls = quote! {};
     let seq_name = &sequence.name;
 
     for field in &sequence.fields {
         let builder_name = field.builder_name();
         let parser_name = field.parser_name();
         let field_name = &field.id;
+        let field_name_str = field.name();
+        let value_name = field.value_name();
+
+        // skipped fields are not encoded, so they are just initialized
+        if field.skip {
+            let initial_value = match &field.default {
+                Some(default) => quote! {#default},
+                None => quote! {Default::default()},
+            };
+
+            parse_calls = quote! {
+                #parse_calls
+                let #value_name = #initial_value;
+            };
+
+            field_values = quote! {
+                #field_values
+                #field_name: #value_name,
+            };
+            continue;
+        }
+
+        // open types are parsed with the value of their discriminant field
+        let discriminant_arg = match &field.defined_by {
+            Some(discriminant) => {
+                let discriminant = sequence
+                    .fields
+                    .iter()
+                    .find(|field| &field.id == discriminant)
+                    .map(|field| field.value_name());
+                quote! {, &#discriminant}
+            }
+            None => quote! {},
+        };
 
         build_calls = quote! {
             #build_calls
             value.append(&mut self.#builder_name());
         };
 
+        // the offset of the field is kept to report the errors of its
+        // constraints, which are checked after the sequence is parsed
+        if !field.constraints.is_empty() {
+            let offset_name = field.offset_name();
+            parse_calls = quote! {
+                #parse_calls
+                let #offset_name = __red_asn1_raw.len() - raw.len();
+            };
+        }
+
+        // the errors of the field value are relative to the field
         parse_calls = quote! {
             #parse_calls
-            let raw = self.#parser_name(raw).or_else(
-                |error| Err(himmelblau_red_asn1::Error::SequenceFieldError(
-                    stringify!(#seq_name).to_string(),
-                    stringify!(#field_name).to_string(),
-                    Box::new(error.clone())
-                )))?;
+            let (raw, #value_name) = Self::#parser_name(raw #discriminant_arg).or_else(
+                |error| {
+                    let offset = __red_asn1_raw.len() - raw.len();
+                    Err(__red_asn1::Error::SequenceFieldError {
+                        sequence: stringify!(#seq_name),
+                        field: #field_name_str,
+                        offset,
+                        error: Box::new(error.shift(offset)),
+                    })
+                })?;
+        };
+
+        field_values = quote! {
+            #field_values
+            #field_name: #value_name,
+        };
+
+        // the constraints are checked out of the octets
+        let field_error = quote! {
+            |error| Err(__red_asn1::Error::SequenceFieldError {
+                sequence: stringify!(#seq_name),
+                field: #field_name_str,
+                offset: 0,
+                error: Box::new(error),
+            })
         };
 
+        if !field.constraints.is_empty() {
+            let checker_name = field.checker_name();
+            let offset_name = field.offset_name();
+            check_calls = quote! {
+                #check_calls
+                self.#checker_name().or_else(#field_error)?;
+            };
+            parse_check_calls = quote! {
+                #parse_check_calls
+                sequence.#checker_name().or_else(|error| {
+                    Err(__red_asn1::Error::SequenceFieldError {
+                        sequence: stringify!(#seq_name),
+                        field: #field_name_str,
+                        offset: #offset_name,
+                        error: Box::new(error),
+                    })
+                })?;
+            };
+        }
+
+        // open types, unknown elements and values of custom
+        // codecs are not Asn1Object
+        if field.defined_by.is_none() && !field.unknown && field.with.is_none()
+        {
+            validate_calls = quote! {
+                #validate_calls
+                __red_asn1::Asn1Object::validate(&self.#field_name)
+                    .or_else(#field_error)?;
+            };
+        }
+
         let field_code = code_field(field);
         let builder = &field_code.builder;
         let parser = &field_code.parser;
+        let checker = &field_code.checker;
 
         components_unit_functions = quote! {
             #components_unit_functions
 
             #builder
             #parser
+            #checker
         };
     }
 
     return SequenceInnerCallsCode {
         build_calls,
         parse_calls,
+        field_values,
+        check_calls,
+        parse_check_calls,
+        validate_calls,
         components_unit_functions,
     };
 }
@@ -0,0 +1,73 @@
+use super::parse_definitions::{TagClassDefinition, TagDefinition};
+use proc_macro2::{Span, TokenStream};
+use syn::Type;
+
+/// Function to write the expression of the class of a tag
+pub fn code_tag_class(class: TagClassDefinition) -> TokenStream {
+    match class {
+        TagClassDefinition::Universal => {
+            quote! {__red_asn1::TagClass::Universal}
+        }
+        TagClassDefinition::Application => {
+            quote! {__red_asn1::TagClass::Application}
+        }
+        TagClassDefinition::Context => {
+            quote! {__red_asn1::TagClass::Context}
+        }
+        TagClassDefinition::Private => {
+            quote! {__red_asn1::TagClass::Private}
+        }
+    }
+}
+
+/// Function to write the expression of a constructed tag, used
+/// to wrap a value with an explicit tag
+pub fn code_explicit_tag(tag: &TagDefinition) -> TokenStream {
+    let number = tag.number;
+    let class = code_tag_class(tag.class);
+    return quote! {
+        __red_asn1::Tag::new(
+            #number,
+            __red_asn1::TagType::Constructed,
+            #class
+        )
+    };
+}
+
+/// Function to write the constant expression of the automatic tag of a
+/// type, which is constructed for choices (explicit tagging), or has the
+/// form of the type tag (implicit tagging). In case the form is unknown,
+/// the tag is considered constructed.
+pub fn code_automatic_tag(tag: &TagDefinition, kind: &Type) -> TokenStream {
+    let number = tag.number;
+    let class = code_tag_class(tag.class);
+    return quote! {
+        __red_asn1::Tag::new(
+            #number,
+            match (
+                <#kind as __red_asn1::Asn1Object>::CHOICE,
+                <#kind as __red_asn1::Asn1Object>::TAGS
+            ) {
+                (false, [inner_tag, ..]) => inner_tag.r#type,
+                _ => __red_asn1::TagType::Constructed,
+            },
+            #class
+        )
+    };
+}
+
+/// Function to write the compile time check that two lists of possible
+/// tags are distinct, which fails with the given message
+pub fn code_distinct_tags_check(
+    tags: &TokenStream,
+    other_tags: &TokenStream,
+    message: &str,
+    span: Span,
+) -> TokenStream {
+    return quote_spanned! {span=>
+        assert!(
+            __red_asn1::tags_are_distinct(#tags, #other_tags),
+            #message
+        );
+    };
+}
@@ -0,0 +1,206 @@
+use himmelblau_red_asn1::{
+    Asn1Object, GeneralString, Integer, SequenceOf, Tag, TagClass, TagType,
+};
+use himmelblau_red_asn1_derive::{Choice, Sequence};
+
+/*
+Value ::= CHOICE {
+    number  INTEGER,
+    text    GeneralString
+}
+*/
+#[derive(Choice, Debug, PartialEq)]
+#[choice(automatic_tags)]
+enum Value {
+    Number(Integer),
+    Text(GeneralString),
+}
+
+/*
+Entry ::= CHOICE {
+    value   Value,
+    count   INTEGER
+}
+*/
+#[derive(Choice, Debug, PartialEq)]
+#[choice(automatic_tags)]
+enum Entry {
+    Value(Value),
+    Count(Integer),
+}
+
+/*
+Record ::= SEQUENCE {
+    id      INTEGER,
+    name    GeneralString OPTIONAL,
+    value   Value,
+    flag    [5] BOOLEAN
+}
+*/
+#[derive(Sequence, Debug, PartialEq)]
+#[seq(automatic_tags)]
+struct Record {
+    id: Integer,
+    name: Option<GeneralString>,
+    value: Value,
+    #[seq_field(context_tag = 5)]
+    flag: bool,
+}
+
+/*
+Batch ::= [APPLICATION 3] SEQUENCE {
+    ids     SEQUENCE OF INTEGER,
+    entry   [APPLICATION 1] EXPLICIT Entry OPTIONAL
+}
+*/
+#[derive(Sequence, Debug, PartialEq)]
+#[seq(application_tag = 3, automatic_tags)]
+struct Batch {
+    ids: SequenceOf<Integer>,
+    #[seq_field(application_tag = 1, explicit)]
+    entry: Option<Entry>,
+}
+
+/*
+Counter ::= SEQUENCE {
+    count   INTEGER,
+    step    INTEGER
+}
+*/
+#[derive(Sequence, Debug, PartialEq)]
+#[seq(automatic_tags)]
+struct Counter {
+    #[seq_field(skip)]
+    cached: Option<Integer>,
+    count: Integer,
+    step: Integer,
+}
+
+#[test]
+fn test_build_automatic_tagged_sequence() {
+    let record = Record {
+        id: 1,
+        name: None,
+        value: Value::Number(5),
+        flag: true,
+    };
+
+    assert_eq!(
+        vec![
+            0x30, 0xb, 0x80, 0x1, 0x1, 0xa2, 0x3, 0x80, 0x1, 0x5, 0x85, 0x1,
+            0xff
+        ],
+        record.build()
+    );
+}
+
+#[test]
+fn test_parse_automatic_tagged_sequence() {
+    let record = Record::decode(&[
+        0x30, 0x10, 0x80, 0x1, 0x1, 0x81, 0x2, 0x61, 0x62, 0xa2, 0x4, 0x81,
+        0x2, 0x63, 0x64, 0x85, 0x1, 0x0,
+    ])
+    .unwrap();
+
+    assert_eq!(
+        Record {
+            id: 1,
+            name: Some("ab".to_string()),
+            value: Value::Text("cd".to_string()),
+            flag: false,
+        },
+        record
+    );
+}
+
+#[test]
+fn test_build_and_parse_automatic_tagged_choice() {
+    let raw = vec![0xa0, 0x3, 0x81, 0x1, 0x61];
+    let entry = Entry::Value(Value::Text("a".to_string()));
+
+    assert_eq!(raw, entry.build());
+    assert_eq!(entry, Entry::decode(&raw).unwrap());
+
+    let raw = vec![0x81, 0x1, 0x7];
+
+    assert_eq!(raw, Entry::Count(7).build());
+    assert_eq!(Entry::Count(7), Entry::decode(&raw).unwrap());
+}
+
+#[test]
+fn test_tags_of_automatic_tagged_choice() {
+    assert_eq!(
+        &[
+            Tag::new(0, TagType::Primitive, TagClass::Context),
+            Tag::new(1, TagType::Primitive, TagClass::Context)
+        ],
+        Value::TAGS
+    );
+    assert_eq!(
+        Tag::new(0, TagType::Primitive, TagClass::Context),
+        Value::tag()
+    );
+    assert_eq!(
+        Tag::new(0, TagType::Constructed, TagClass::Context),
+        Entry::tag()
+    );
+    assert_eq!(
+        &[
+            Tag::new(0, TagType::Constructed, TagClass::Context),
+            Tag::new(1, TagType::Primitive, TagClass::Context)
+        ],
+        Entry::TAGS
+    );
+}
+
+#[test]
+fn test_build_and_parse_automatic_tagged_sequence_with_tag() {
+    let raw = vec![
+        0x63, 0xc, 0xa0, 0x3, 0x2, 0x1, 0x1, 0x61, 0x5, 0xa0, 0x3, 0x80, 0x1,
+        0x2,
+    ];
+    let batch = Batch {
+        ids: vec![1],
+        entry: Some(Entry::Value(Value::Number(2))),
+    };
+
+    assert_eq!(raw, batch.build());
+    assert_eq!(batch, Batch::decode(&raw).unwrap());
+}
+
+#[test]
+fn test_parse_automatic_tagged_sequence_without_optional() {
+    let batch = Batch::decode(&[0x63, 0x2, 0xa0, 0x0]).unwrap();
+
+    assert_eq!(Vec::<Integer>::new(), batch.ids);
+    assert_eq!(None, batch.entry);
+}
+
+#[should_panic(expected = "SequenceFieldError { sequence: \"Record\", field: \"value\"")]
+#[test]
+fn test_parse_automatic_tagged_choice_field_with_implicit_tag() {
+    Record::decode(&[
+        0x30, 0x9, 0x80, 0x1, 0x1, 0x82, 0x1, 0x5, 0x85, 0x1, 0x0,
+    ])
+    .unwrap();
+}
+
+#[test]
+fn test_automatic_tags_do_not_count_skipped_fields() {
+    let counter = Counter {
+        cached: Some(1),
+        count: 2,
+        step: 3,
+    };
+    let raw = vec![0x30, 0x6, 0x80, 0x1, 0x2, 0x81, 0x1, 0x3];
+
+    assert_eq!(raw, counter.build());
+    assert_eq!(
+        Counter {
+            cached: None,
+            count: 2,
+            step: 3,
+        },
+        Counter::decode(&raw).unwrap()
+    );
+}
@@ -0,0 +1,235 @@
+use himmelblau_red_asn1::{
+    Asn1Object, GeneralString, Integer, OctetString, Tag, TagClass, TagType,
+};
+use himmelblau_red_asn1_derive::{Choice, Sequence};
+
+#[derive(Choice, Debug, PartialEq)]
+enum Name {
+    Number(Integer),
+    Text(GeneralString),
+}
+
+impl Default for Name {
+    fn default() -> Self {
+        return Self::Number(0);
+    }
+}
+
+#[derive(Choice, Debug, PartialEq)]
+enum Key {
+    #[choice_field(context_tag = 0)]
+    Plain(OctetString),
+    #[choice_field(application_tag = 1)]
+    Secret(OctetString),
+}
+
+impl Default for Key {
+    fn default() -> Self {
+        return Self::Plain(OctetString::default());
+    }
+}
+
+#[derive(Sequence, Default)]
+struct Ticket {
+    name: Name,
+    key: Option<Key>,
+    #[seq_field(context_tag = 1)]
+    alias: Option<Name>,
+}
+
+#[test]
+fn test_build_untagged_alternatives() {
+    assert_eq!(vec![0x2, 0x1, 0x5], Name::Number(5).build());
+    assert_eq!(
+        vec![0x1b, 0x2, 0x61, 0x62],
+        Name::Text("ab".to_string()).build()
+    );
+}
+
+#[test]
+fn test_parse_untagged_alternatives() {
+    assert_eq!(Name::Number(5), Name::parse(&[0x2, 0x1, 0x5]).unwrap().1);
+    assert_eq!(
+        Name::Text("ab".to_string()),
+        Name::parse(&[0x1b, 0x2, 0x61, 0x62]).unwrap().1
+    );
+}
+
+#[test]
+fn test_build_tagged_alternatives() {
+    assert_eq!(
+        vec![0xa0, 0x4, 0x4, 0x2, 0x1, 0x2],
+        Key::Plain(vec![0x1, 0x2]).build()
+    );
+    assert_eq!(
+        vec![0x61, 0x4, 0x4, 0x2, 0x1, 0x2],
+        Key::Secret(vec![0x1, 0x2]).build()
+    );
+}
+
+#[test]
+fn test_parse_tagged_alternatives() {
+    assert_eq!(
+        Key::Plain(vec![0x1, 0x2]),
+        Key::parse(&[0xa0, 0x4, 0x4, 0x2, 0x1, 0x2]).unwrap().1
+    );
+    assert_eq!(
+        Key::Secret(vec![0x1, 0x2]),
+        Key::parse(&[0x61, 0x4, 0x4, 0x2, 0x1, 0x2]).unwrap().1
+    );
+}
+
+#[test]
+fn test_parse_with_excesive_bytes() {
+    let rest: &[u8] = &[0x22];
+    assert_eq!(
+        (rest, Key::Secret(vec![0x1])),
+        Key::parse(&[0x61, 0x3, 0x4, 0x1, 0x1, 0x22]).unwrap()
+    );
+}
+
+#[should_panic(
+    expected = "ChoiceAlternativeError { choice: \"Key\", alternative: \"Secret\", offset: 0, error: NoAllDataConsumed }"
+)]
+#[test]
+fn test_parse_tagged_alternative_with_excesive_inner_bytes() {
+    Key::parse(&[0x61, 0x4, 0x4, 0x1, 0x1, 0x22]).unwrap();
+}
+
+#[test]
+fn test_build_value_of_alternatives() {
+    assert_eq!(vec![0x5], Name::Number(5).build_value());
+    assert_eq!(
+        vec![0x4, 0x2, 0x1, 0x2],
+        Key::Plain(vec![0x1, 0x2]).build_value()
+    );
+}
+
+#[test]
+fn test_matches_tag() {
+    assert!(Name::matches_tag(&Integer::tag()));
+    assert!(Name::matches_tag(&GeneralString::tag()));
+    assert!(!Name::matches_tag(&OctetString::tag()));
+    assert!(!Key::matches_tag(&OctetString::tag()));
+}
+
+#[test]
+fn test_tags_of_alternatives() {
+    assert_eq!(&[Integer::tag(), GeneralString::tag()], Name::TAGS);
+    assert_eq!(
+        &[
+            Tag::new(0, TagType::Constructed, TagClass::Context),
+            Tag::new(1, TagType::Constructed, TagClass::Application)
+        ],
+        Key::TAGS
+    );
+    assert_eq!(&[Ticket::tag()], Ticket::TAGS);
+}
+
+#[test]
+fn test_build_choices_in_sequence() {
+    let ticket = Ticket {
+        name: Name::Number(5),
+        key: Some(Key::Secret(vec![0x1, 0x2])),
+        alias: Some(Name::Text("a".to_string())),
+    };
+
+    assert_eq!(
+        vec![
+            0x30, 0xe, 0x2, 0x1, 0x5, 0x61, 0x4, 0x4, 0x2, 0x1, 0x2, 0xa1, 0x3,
+            0x1b, 0x1, 0x61
+        ],
+        ticket.build()
+    );
+}
+
+#[test]
+fn test_parse_choices_in_sequence() {
+    let (_, ticket) = Ticket::parse(&[
+        0x30, 0xe, 0x2, 0x1, 0x5, 0x61, 0x4, 0x4, 0x2, 0x1, 0x2, 0xa1, 0x3,
+        0x1b, 0x1, 0x61,
+    ])
+    .unwrap();
+
+    assert_eq!(Name::Number(5), ticket.name);
+    assert_eq!(Some(Key::Secret(vec![0x1, 0x2])), ticket.key);
+    assert_eq!(Some(Name::Text("a".to_string())), ticket.alias);
+}
+
+#[test]
+fn test_parse_sequence_without_optional_choices() {
+    let (_, ticket) =
+        Ticket::parse(&[0x30, 0x4, 0x1b, 0x2, 0x61, 0x62]).unwrap();
+
+    assert_eq!(Name::Text("ab".to_string()), ticket.name);
+    assert_eq!(None, ticket.key);
+    assert_eq!(None, ticket.alias);
+}
+
+#[test]
+fn test_build_and_parse_choices_in_implicit_sequence() {
+    #[derive(Sequence, Debug, PartialEq)]
+    #[seq(tagging = "implicit")]
+    struct Account {
+        #[seq_field(context_tag = 0)]
+        id: Integer,
+        #[seq_field(context_tag = 3)]
+        name: Name,
+    }
+
+    let account = Account {
+        id: 5,
+        name: Name::Number(7),
+    };
+
+    let raw = [0x30, 0x8, 0x80, 0x1, 0x5, 0xa3, 0x3, 0x2, 0x1, 0x7];
+    assert_eq!(raw.to_vec(), account.build());
+    assert_eq!(account, Account::parse(&raw).unwrap().1);
+}
+
+#[should_panic(
+    expected = "ChoiceError { choice: \"Name\", offset: 0, error: UnmatchedAlternative(Tag { number: 4, type: Primitive, class: Universal }) }"
+)]
+#[test]
+fn test_parse_unknown_alternative() {
+    Name::parse(&[0x4, 0x0]).unwrap();
+}
+
+#[test]
+fn test_parse_unknown_alternative_class() {
+    match Key::parse(&[0x4, 0x0]) {
+        Err(himmelblau_red_asn1::Error::ChoiceError {
+            choice,
+            offset,
+            error,
+        }) => {
+            assert_eq!("Key", choice);
+            assert_eq!(0, offset);
+            assert_eq!(
+                himmelblau_red_asn1::Error::UnmatchedAlternative(Tag::new(
+                    4,
+                    TagType::Primitive,
+                    TagClass::Universal
+                )),
+                *error
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[should_panic(
+    expected = "ChoiceAlternativeError { choice: \"Key\", alternative: \"Plain\", offset: 0, error: UnmatchedTag { expected: Tag { number: 4, type: Primitive, class: Universal }, found: Tag { number: 2, type: Primitive, class: Universal } } }"
+)]
+#[test]
+fn test_parse_invalid_alternative_value() {
+    Key::parse(&[0xa0, 0x3, 0x2, 0x1, 0x5]).unwrap();
+}
+
+#[should_panic(
+    expected = "ChoiceError { choice: \"Key\", offset: 0, error: EmptyTag(Universal) }"
+)]
+#[test]
+fn test_parse_empty() {
+    Key::parse(&[]).unwrap();
+}
@@ -0,0 +1,108 @@
+use himmelblau_red_asn1::{Asn1Object, Error};
+use himmelblau_red_asn1_derive::Sequence;
+
+/// OCTET STRING of 16 octets, as an UUID
+mod fixed_octets {
+    use himmelblau_red_asn1::{Asn1Object, Error, OctetString, Result};
+    use std::convert::TryInto;
+
+    pub fn build(value: &[u8; 16]) -> Vec<u8> {
+        return OctetString::from(value.to_vec()).build();
+    }
+
+    pub fn parse(raw: &[u8]) -> Result<(&[u8], [u8; 16])> {
+        let (raw, octets) = OctetString::parse(raw)?;
+        let value = octets.try_into().map_err(|_| {
+            Error::IncorrectValue("Expected 16 octets".to_string())
+        })?;
+        return Ok((raw, value));
+    }
+}
+
+/// Boolean encoded as an INTEGER, 0 or 1
+mod integer_flag {
+    use himmelblau_red_asn1::{Asn1Object, Error, Integer, Result};
+
+    pub fn build(value: &bool) -> Vec<u8> {
+        return Integer::from(*value as i64).build();
+    }
+
+    pub fn parse(raw: &[u8]) -> Result<(&[u8], bool)> {
+        let (raw, value) = Integer::parse(raw)?;
+        match value {
+            0 => return Ok((raw, false)),
+            1 => return Ok((raw, true)),
+            _ => {
+                return Err(Error::IncorrectValue(format!(
+                    "Invalid flag {}",
+                    value
+                )))
+            }
+        }
+    }
+}
+
+#[derive(Sequence, Debug, PartialEq)]
+struct Device {
+    #[seq_field(context_tag = 0, with = "fixed_octets")]
+    id: [u8; 16],
+    #[seq_field(with = "integer_flag")]
+    enabled: bool,
+    #[seq_field(context_tag = 1, with = "integer_flag")]
+    backup: Option<bool>,
+    #[seq_field(context_tag = 2, with = "integer_flag", default = "false")]
+    locked: bool,
+}
+
+#[test]
+fn test_build_fields_with_codec() {
+    let device = Device {
+        id: [0xaa; 16],
+        enabled: true,
+        backup: None,
+        locked: false,
+    };
+
+    let mut raw = vec![0x30, 0x17, 0xa0, 0x12, 0x4, 0x10];
+    raw.extend_from_slice(&[0xaa; 16]);
+    raw.extend_from_slice(&[0x2, 0x1, 0x1]);
+
+    assert_eq!(raw, device.build());
+    assert_eq!(device, Device::decode(&raw).unwrap());
+}
+
+#[test]
+fn test_parse_optional_fields_with_codec() {
+    let mut raw = vec![0x30, 0x21, 0xa0, 0x12, 0x4, 0x10];
+    raw.extend_from_slice(&[0xaa; 16]);
+    raw.extend_from_slice(&[
+        0x2, 0x1, 0x0, 0xa1, 0x3, 0x2, 0x1, 0x0, 0xa2, 0x3, 0x2, 0x1, 0x1,
+    ]);
+
+    let device = Device::decode(&raw).unwrap();
+
+    assert_eq!([0xaa; 16], device.id);
+    assert!(!device.enabled);
+    assert_eq!(Some(false), device.backup);
+    assert!(device.locked);
+    assert_eq!(raw, device.build());
+}
+
+#[test]
+fn test_parse_field_with_codec_error() {
+    let mut raw = vec![0x30, 0xb, 0xa0, 0x6, 0x4, 0x4];
+    raw.extend_from_slice(&[0xaa; 4]);
+    raw.extend_from_slice(&[0x2, 0x1, 0x1]);
+
+    assert_eq!(
+        Err(Error::SequenceFieldError {
+            sequence: "Device",
+            field: "id",
+            offset: 2,
+            error: Box::new(Error::IncorrectValue(
+                "Expected 16 octets".to_string()
+            ))
+        }),
+        Device::decode(&raw)
+    );
+}
@@ -0,0 +1,208 @@
+use himmelblau_red_asn1::{
+    Asn1Object, Error, GeneralString, IA5String, Integer, SequenceOf,
+};
+use himmelblau_red_asn1_derive::{Choice, Newtype, Sequence};
+use std::str::FromStr;
+
+#[derive(Sequence, Debug, PartialEq)]
+struct PrincipalName {
+    #[seq_field(context_tag = 0, range = "-2147483648..=2147483647")]
+    name_type: Integer,
+    #[seq_field(context_tag = 1, size = "1..")]
+    name_string: SequenceOf<GeneralString>,
+}
+
+#[derive(Sequence, Debug, PartialEq)]
+struct Pin {
+    #[seq_field(context_tag = 0, size = "4", alphabet = "0123456789")]
+    code: IA5String,
+    #[seq_field(context_tag = 1, size = "..=8")]
+    hint: Option<GeneralString>,
+}
+
+#[derive(Sequence, Debug, PartialEq)]
+#[seq(validate = "check_interval")]
+struct Interval {
+    #[seq_field(context_tag = 0)]
+    start: Integer,
+    #[seq_field(context_tag = 1)]
+    end: Integer,
+}
+
+fn check_interval(interval: &Interval) -> himmelblau_red_asn1::Result<()> {
+    if interval.start > interval.end {
+        return Err(Error::ConstraintError("Start after end".to_string()));
+    }
+    return Ok(());
+}
+
+#[derive(Sequence, Debug, PartialEq)]
+struct Ticket {
+    #[seq_field(context_tag = 0)]
+    sname: PrincipalName,
+    #[seq_field(context_tag = 1)]
+    interval: Option<Interval>,
+}
+
+#[derive(Newtype, Debug, PartialEq)]
+struct Principals(SequenceOf<PrincipalName>);
+
+#[derive(Choice, Debug, PartialEq)]
+enum Validity {
+    #[choice_field(context_tag = 0)]
+    Interval(Interval),
+    #[choice_field(context_tag = 1)]
+    Forever(Integer),
+}
+
+fn principal_name(names: &[&str]) -> PrincipalName {
+    return PrincipalName {
+        name_type: 1,
+        name_string: names.iter().map(|name| name.to_string()).collect(),
+    };
+}
+
+#[test]
+fn test_parse_and_encode_with_constraints() {
+    let raw = vec![
+        0x30, 0xc, 0xa0, 0x3, 0x2, 0x1, 0x1, 0xa1, 0x5, 0x30, 0x3, 0x1b, 0x1,
+        0x61,
+    ];
+    let name = principal_name(&["a"]);
+
+    assert_eq!(name, PrincipalName::decode(&raw).unwrap());
+    assert_eq!(raw, name.encode().unwrap());
+}
+
+#[should_panic(
+    expected = "SequenceFieldError { sequence: \"PrincipalName\", field: \"name_string\", offset: 7, error: ConstraintError(\"Size 0 out of range 1..\") }"
+)]
+#[test]
+fn test_parse_with_size_out_of_range() {
+    PrincipalName::decode(&[
+        0x30, 0x9, 0xa0, 0x3, 0x2, 0x1, 0x1, 0xa1, 0x2, 0x30, 0x0,
+    ])
+    .unwrap();
+}
+
+#[should_panic(
+    expected = "SequenceFieldError { sequence: \"PrincipalName\", field: \"name_type\", offset: 2, error: ConstraintError(\"Value out of range -2147483648..=2147483647\") }"
+)]
+#[test]
+fn test_parse_with_value_out_of_range() {
+    PrincipalName::decode(&[
+        0x30, 0x10, 0xa0, 0x7, 0x2, 0x5, 0x0, 0x80, 0x0, 0x0, 0x0, 0xa1, 0x5,
+        0x30, 0x3, 0x1b, 0x1, 0x61,
+    ])
+    .unwrap();
+}
+
+#[test]
+fn test_parse_with_alphabet_and_fixed_size() {
+    let pin =
+        Pin::decode(&[0x30, 0x8, 0xa0, 0x6, 0x16, 0x4, 0x31, 0x32, 0x33, 0x34])
+            .unwrap();
+
+    assert_eq!(IA5String::from_str("1234").unwrap(), pin.code);
+    assert_eq!(None, pin.hint);
+}
+
+#[should_panic(
+    expected = "SequenceFieldError { sequence: \"Pin\", field: \"code\", offset: 2, error: ConstraintError(\"Character 'a' not in alphabet \\\"0123456789\\\"\") }"
+)]
+#[test]
+fn test_parse_with_character_out_of_alphabet() {
+    Pin::decode(&[0x30, 0x8, 0xa0, 0x6, 0x16, 0x4, 0x31, 0x32, 0x61, 0x34])
+        .unwrap();
+}
+
+#[should_panic(
+    expected = "SequenceFieldError { sequence: \"Pin\", field: \"code\", offset: 2, error: ConstraintError(\"Size 3 out of range 4\") }"
+)]
+#[test]
+fn test_parse_with_invalid_fixed_size() {
+    Pin::decode(&[0x30, 0x7, 0xa0, 0x5, 0x16, 0x3, 0x31, 0x32, 0x33]).unwrap();
+}
+
+#[should_panic(
+    expected = "SequenceFieldError { sequence: \"Pin\", field: \"hint\", offset: 0, error: ConstraintError(\"Size 9 out of range ..=8\") }"
+)]
+#[test]
+fn test_encode_with_optional_size_out_of_range() {
+    let pin = Pin {
+        code: IA5String::from_str("1234").unwrap(),
+        hint: Some("birthdate".to_string()),
+    };
+    pin.encode().unwrap();
+}
+
+#[should_panic(
+    expected = "SequenceError { sequence: \"Interval\", offset: 2, error: ConstraintError(\"Start after end\") }"
+)]
+#[test]
+fn test_parse_with_invalid_validate() {
+    Interval::decode(&[
+        0x30, 0xa, 0xa0, 0x3, 0x2, 0x1, 0x2, 0xa1, 0x3, 0x2, 0x1, 0x1,
+    ])
+    .unwrap();
+}
+
+#[test]
+fn test_build_does_not_check_constraints() {
+    let interval = Interval { start: 2, end: 1 };
+
+    assert_eq!(
+        vec![0x30, 0xa, 0xa0, 0x3, 0x2, 0x1, 0x2, 0xa1, 0x3, 0x2, 0x1, 0x1],
+        interval.build()
+    );
+    assert_eq!(
+        Err(Error::SequenceError {
+            sequence: "Interval",
+            offset: 0,
+            error: Box::new(Error::ConstraintError(
+                "Start after end".to_string()
+            ))
+        }),
+        interval.validate()
+    );
+}
+
+#[should_panic(
+    expected = "SequenceFieldError { sequence: \"Ticket\", field: \"sname\", offset: 0, error: SequenceFieldError { sequence: \"PrincipalName\", field: \"name_string\", offset: 0, error: ConstraintError(\"Size 0 out of range 1..\") } }"
+)]
+#[test]
+fn test_encode_with_inner_constraint_error() {
+    let ticket = Ticket {
+        sname: principal_name(&[]),
+        interval: Some(Interval { start: 1, end: 2 }),
+    };
+    ticket.encode().unwrap();
+}
+
+#[should_panic(
+    expected = "SequenceFieldError { sequence: \"Ticket\", field: \"interval\", offset: 0, error: SequenceError { sequence: \"Interval\", offset: 0, error: ConstraintError(\"Start after end\") } }"
+)]
+#[test]
+fn test_encode_with_inner_optional_validate_error() {
+    let ticket = Ticket {
+        sname: principal_name(&["a"]),
+        interval: Some(Interval { start: 2, end: 1 }),
+    };
+    ticket.encode().unwrap();
+}
+
+#[test]
+fn test_validate_newtype() {
+    assert!(Principals(vec![principal_name(&["a"])]).validate().is_ok());
+    assert!(Principals(vec![principal_name(&[])]).validate().is_err());
+}
+
+#[should_panic(
+    expected = "ChoiceAlternativeError { choice: \"Validity\", alternative: \"Interval\", offset: 0, error: SequenceError { sequence: \"Interval\", offset: 0, error: ConstraintError(\"Start after end\") } }"
+)]
+#[test]
+fn test_encode_choice_with_invalid_alternative() {
+    Validity::Interval(Interval { start: 2, end: 1 })
+        .encode()
+        .unwrap();
+}
@@ -0,0 +1,136 @@
+use himmelblau_red_asn1_derive::{Choice, Newtype, OpenType, Sequence};
+
+/// Facade which reexports the crate with other name
+mod facade {
+    pub use himmelblau_red_asn1 as asn1;
+}
+
+use facade::asn1::{Any, Asn1Object, Integer, Oid};
+
+#[derive(Sequence, Debug, PartialEq)]
+#[seq(crate = "crate::facade::asn1")]
+struct Version {
+    #[seq_field(context_tag = 0)]
+    major: Integer,
+    #[seq_field(context_tag = 1)]
+    minor: Option<Integer>,
+}
+
+#[derive(Choice, Debug, PartialEq)]
+#[choice(crate = "crate::facade::asn1")]
+enum Release {
+    #[choice_field(context_tag = 0)]
+    Version(Version),
+    #[choice_field(context_tag = 1)]
+    Build(Integer),
+}
+
+#[derive(Newtype, Debug, PartialEq)]
+#[newtype(application_tag = 1, crate = "crate::facade::asn1")]
+struct Build(Integer);
+
+#[derive(OpenType, Debug, PartialEq)]
+#[open_type(discriminant = "Oid", crate = "crate::facade::asn1")]
+enum Parameters {
+    #[open_value(id = "Oid::new(1, 2, vec![3])")]
+    Build(Integer),
+    #[open_value(unknown)]
+    Unknown(Any),
+}
+
+#[derive(Sequence, Debug, PartialEq)]
+#[seq(crate = "crate::facade::asn1")]
+struct Algorithm {
+    algorithm: Oid,
+    #[seq_field(defined_by = "algorithm")]
+    parameters: Option<Parameters>,
+}
+
+#[test]
+fn test_derive_with_crate_path() {
+    let version = Version {
+        major: 1,
+        minor: None,
+    };
+    let raw = vec![0x30, 0x5, 0xa0, 0x3, 0x2, 0x1, 0x1];
+
+    assert_eq!(raw, version.build());
+    assert_eq!(version, Version::decode(&raw).unwrap());
+
+    let release = Release::Build(2);
+    let raw = vec![0xa1, 0x3, 0x2, 0x1, 0x2];
+
+    assert_eq!(raw, release.build());
+    assert_eq!(release, Release::decode(&raw).unwrap());
+
+    let build = Build(2);
+    let raw = vec![0x61, 0x3, 0x2, 0x1, 0x2];
+
+    assert_eq!(raw, build.build());
+    assert_eq!(build, Build::decode(&raw).unwrap());
+
+    let algorithm = Algorithm {
+        algorithm: Oid::new(1, 2, vec![3]),
+        parameters: Some(Parameters::Build(2)),
+    };
+    let raw = vec![0x30, 0x7, 0x6, 0x2, 0x2a, 0x3, 0x2, 0x1, 0x2];
+
+    assert_eq!(raw, algorithm.build());
+    assert_eq!(algorithm, Algorithm::decode(&raw).unwrap());
+}
+
+/// Struct with functions and fields whose names were used by
+/// the generated functions
+#[derive(Sequence, Debug, PartialEq)]
+struct Record {
+    #[seq_field(context_tag = 0)]
+    r#type: Integer,
+    #[seq_field(context_tag = 1, range = "0..")]
+    value: Integer,
+    #[seq_field(context_tag = 2)]
+    raw: Option<Integer>,
+}
+
+impl Record {
+    fn parse_type(&self) -> bool {
+        return self.r#type > 0;
+    }
+
+    fn build_value(&self) -> String {
+        return self.value.to_string();
+    }
+
+    fn check_value(&self) -> bool {
+        return self.value >= 0;
+    }
+
+    fn _inner_parse(&self) -> Option<Integer> {
+        return self.raw;
+    }
+}
+
+#[test]
+fn test_derive_without_collisions() {
+    let record = Record {
+        r#type: 1,
+        value: 2,
+        raw: Some(3),
+    };
+    let raw = vec![
+        0x30, 0xf, 0xa0, 0x3, 0x2, 0x1, 0x1, 0xa1, 0x3, 0x2, 0x1, 0x2, 0xa2,
+        0x3, 0x2, 0x1, 0x3,
+    ];
+
+    assert_eq!(raw, record.build());
+    assert_eq!(record, Record::decode(&raw).unwrap());
+    assert!(record.parse_type());
+    assert_eq!("2", record.build_value());
+    assert!(record.check_value());
+    assert_eq!(Some(3), record._inner_parse());
+}
+
+#[should_panic(expected = "SequenceFieldError { sequence: \"Record\", field: \"type\"")]
+#[test]
+fn test_error_with_raw_field_name() {
+    Record::decode(&[0x30, 0x5, 0xa0, 0x3, 0x4, 0x1, 0x1]).unwrap();
+}
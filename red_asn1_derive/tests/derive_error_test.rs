@@ -0,0 +1,192 @@
+use himmelblau_red_asn1::{
+    Asn1Object, DecodeOptions, Error, Integer, OctetString, SequenceOf, Tag,
+    TagClass, TagType,
+};
+use himmelblau_red_asn1_derive::Sequence;
+use std::error::Error as StdError;
+
+/*
+EncryptedData ::= SEQUENCE {
+    etype   [0] INTEGER,
+    cipher  [2] OCTET STRING
+}
+*/
+#[derive(Sequence, Debug, PartialEq)]
+struct EncryptedData {
+    #[seq_field(context_tag = 0)]
+    etype: Integer,
+    #[seq_field(context_tag = 2)]
+    cipher: OctetString,
+}
+
+/*
+AsRep ::= [APPLICATION 11] SEQUENCE {
+    pvno     [0] INTEGER,
+    enc_part [1] EncryptedData
+}
+*/
+#[derive(Sequence, Debug, PartialEq)]
+#[seq(application_tag = 11)]
+struct AsRep {
+    #[seq_field(context_tag = 0)]
+    pvno: Integer,
+    #[seq_field(context_tag = 1)]
+    enc_part: EncryptedData,
+}
+
+/*
+Tickets ::= SEQUENCE {
+    items [0] SEQUENCE OF EncryptedData
+}
+*/
+#[derive(Sequence, Debug, PartialEq)]
+struct Tickets {
+    #[seq_field(context_tag = 0)]
+    items: SequenceOf<EncryptedData>,
+}
+
+/*
+Ticket ::= SEQUENCE {
+    data [40] IMPLICIT EncryptedData
+}
+*/
+#[derive(Sequence, Debug, PartialEq)]
+struct Ticket {
+    #[seq_field(context_tag = 40, implicit)]
+    data: EncryptedData,
+}
+
+/*
+Node ::= SEQUENCE {
+    children [0] SEQUENCE OF Node
+}
+*/
+#[derive(Sequence, Debug, PartialEq)]
+struct Node {
+    #[seq_field(context_tag = 0)]
+    children: SequenceOf<Node>,
+}
+
+fn nested_nodes(depth: usize) -> Node {
+    let mut node = Node { children: vec![] };
+    for _ in 1..depth {
+        node = Node {
+            children: vec![node],
+        };
+    }
+    return node;
+}
+
+fn unmatched_cipher_tag() -> Error {
+    return Error::UnmatchedTag {
+        expected: Tag::new(2, TagType::Constructed, TagClass::Context),
+        found: Tag::new(3, TagType::Constructed, TagClass::Context),
+    };
+}
+
+#[test]
+fn test_error_path_and_offset_of_nested_field() {
+    let error = AsRep::decode(&[
+        0x6b, 0x15, 0x30, 0x13, 0xa0, 0x3, 0x2, 0x1, 0x5, 0xa1, 0xc, 0x30, 0xa,
+        0xa0, 0x3, 0x2, 0x1, 0x12, 0xa3, 0x3, 0x4, 0x1, 0xff,
+    ])
+    .unwrap_err();
+
+    assert_eq!("AsRep.enc_part.cipher", error.path());
+    assert_eq!(Some(18), error.offset());
+    assert_eq!(&unmatched_cipher_tag(), error.root_cause());
+}
+
+#[test]
+fn test_error_offset_of_sequence_of_item() {
+    let error = Tickets::decode(&[
+        0x30, 0x1c, 0xa0, 0x1a, 0x30, 0x18, 0x30, 0xa, 0xa0, 0x3, 0x2, 0x1,
+        0x12, 0xa2, 0x3, 0x4, 0x1, 0xff, 0x30, 0xa, 0xa0, 0x3, 0x2, 0x1, 0x12,
+        0xa3, 0x3, 0x4, 0x1, 0xff,
+    ])
+    .unwrap_err();
+
+    assert_eq!("Tickets.items.cipher", error.path());
+    assert_eq!(Some(25), error.offset());
+    assert_eq!(&unmatched_cipher_tag(), error.root_cause());
+}
+
+#[test]
+fn test_error_offset_of_implicit_field() {
+    let error = Ticket::decode(&[
+        0x30, 0xd, 0xbf, 0x28, 0xa, 0xa0, 0x3, 0x2, 0x1, 0x12, 0xa3, 0x3, 0x4,
+        0x1, 0xff,
+    ])
+    .unwrap_err();
+
+    assert_eq!("Ticket.data.cipher", error.path());
+    assert_eq!(Some(10), error.offset());
+}
+
+#[test]
+fn test_display_and_source_of_error() {
+    let error = AsRep::decode(&[
+        0x6b, 0x15, 0x30, 0x13, 0xa0, 0x3, 0x2, 0x1, 0x5, 0xa1, 0xc, 0x30, 0xa,
+        0xa0, 0x3, 0x2, 0x1, 0x12, 0xa3, 0x3, 0x4, 0x1, 0xff,
+    ])
+    .unwrap_err();
+
+    assert_eq!(
+        "AsRep.enc_part.cipher at offset 18: expected tag \
+         [context 2] constructed, found [context 3] constructed",
+        error.to_string()
+    );
+
+    let source = error.source().unwrap();
+    assert_eq!(
+        "EncryptedData.cipher at offset 18: expected tag \
+         [context 2] constructed, found [context 3] constructed",
+        source.to_string()
+    );
+    assert!(source.source().unwrap().source().is_none());
+}
+
+#[test]
+fn test_error_offset_of_sequence_tag() {
+    let error =
+        EncryptedData::decode(&[0x31, 0x3, 0xa0, 0x1, 0x0]).unwrap_err();
+
+    assert_eq!("EncryptedData", error.path());
+    assert_eq!(Some(0), error.offset());
+    assert_eq!(
+        "EncryptedData at offset 0: expected tag [universal 16] \
+         constructed, found [universal 17] constructed",
+        error.to_string()
+    );
+}
+
+#[test]
+fn test_decode_with_nesting_depth() {
+    let options = DecodeOptions {
+        max_depth: 8,
+        ..DecodeOptions::default()
+    };
+
+    let node = nested_nodes(4);
+    assert_eq!(node, Node::decode_with(&node.build(), &options).unwrap());
+
+    let error =
+        Node::decode_with(&nested_nodes(5).build(), &options).unwrap_err();
+    assert_eq!("Node.children.children.children.children", error.path());
+    assert_eq!(
+        &Error::LimitError("nesting depth over 8".to_string()),
+        error.root_cause()
+    );
+}
+
+#[test]
+fn test_decode_deeply_nested_input() {
+    let raw = nested_nodes(100).build();
+
+    assert_eq!(
+        &Error::LimitError("nesting depth over 64".to_string()),
+        Node::decode_with(&raw, &DecodeOptions::default())
+            .unwrap_err()
+            .root_cause()
+    );
+}
@@ -0,0 +1,160 @@
+use himmelblau_red_asn1::{
+    Asn1Object, GeneralString, Integer, Raw, SequenceOf, Tag, TagClass, TagType,
+};
+use himmelblau_red_asn1_derive::{Newtype, Sequence};
+
+#[derive(Newtype, Default, Debug, PartialEq)]
+struct KerberosString(GeneralString);
+
+#[derive(Newtype, Default, Debug, PartialEq)]
+#[newtype(application_tag = 1)]
+struct Realm(KerberosString);
+
+#[derive(Newtype, Default, Debug, PartialEq)]
+#[newtype(application_tag = 2, implicit)]
+struct Microseconds(Integer);
+
+#[derive(Newtype, Default, Debug, PartialEq)]
+#[newtype(context_tag = 3, implicit)]
+struct Names(SequenceOf<KerberosString>);
+
+#[derive(Newtype, Default, Debug, PartialEq)]
+#[newtype(private_tag = 4, explicit)]
+struct Wrapper<T>(T);
+
+#[derive(Sequence, Default, Debug, PartialEq)]
+struct Principal {
+    realm: Option<Realm>,
+    usec: Option<Microseconds>,
+    name: KerberosString,
+}
+
+#[test]
+fn test_build_untagged_newtype() {
+    assert_eq!(
+        vec![0x1b, 0x2, 0x61, 0x62],
+        KerberosString("ab".to_string()).build()
+    );
+}
+
+#[test]
+fn test_parse_untagged_newtype() {
+    assert_eq!(
+        KerberosString("ab".to_string()),
+        KerberosString::parse(&[0x1b, 0x2, 0x61, 0x62]).unwrap().1
+    );
+}
+
+#[test]
+fn test_untagged_newtype_tag() {
+    assert_eq!(GeneralString::tag(), KerberosString::tag());
+}
+
+#[test]
+fn test_build_explicit_newtype() {
+    assert_eq!(
+        vec![0x61, 0x4, 0x1b, 0x2, 0x61, 0x62],
+        Realm(KerberosString("ab".to_string())).build()
+    );
+}
+
+#[test]
+fn test_parse_explicit_newtype() {
+    let (raw, realm) =
+        Realm::parse(&[0x61, 0x4, 0x1b, 0x2, 0x61, 0x62, 0x22]).unwrap();
+
+    assert_eq!(&[0x22], raw);
+    assert_eq!(Realm(KerberosString("ab".to_string())), realm);
+}
+
+#[should_panic(
+    expected = "UnmatchedTag { expected: Tag { number: 1, type: Constructed, class: Application }, found: Tag { number: 2, type: Constructed, class: Application } }"
+)]
+#[test]
+fn test_parse_explicit_newtype_with_other_tag() {
+    Realm::parse(&[0x62, 0x4, 0x1b, 0x2, 0x61, 0x62]).unwrap();
+}
+
+#[should_panic(expected = "NoAllDataConsumed")]
+#[test]
+fn test_parse_explicit_newtype_with_excesive_value() {
+    Realm::parse(&[0x61, 0x5, 0x1b, 0x2, 0x61, 0x62, 0x0]).unwrap();
+}
+
+#[test]
+fn test_build_implicit_newtype() {
+    assert_eq!(vec![0x42, 0x1, 0x5], Microseconds(5).build());
+    assert_eq!(
+        vec![0xa3, 0x4, 0x1b, 0x2, 0x61, 0x62],
+        Names(vec![KerberosString("ab".to_string())]).build()
+    );
+}
+
+#[test]
+fn test_parse_implicit_newtype() {
+    assert_eq!(
+        Microseconds(5),
+        Microseconds::parse(&[0x42, 0x1, 0x5]).unwrap().1
+    );
+    assert_eq!(
+        Names(vec![KerberosString("ab".to_string())]),
+        Names::parse(&[0xa3, 0x4, 0x1b, 0x2, 0x61, 0x62]).unwrap().1
+    );
+}
+
+#[test]
+fn test_parse_and_build_implicit_newtype_of_raw() {
+    #[derive(Newtype, Debug, PartialEq)]
+    #[newtype(context_tag = 5, implicit)]
+    struct Flag(Raw<bool>);
+
+    // a true value which is not DER encoded
+    let encoded = [0x85, 0x1, 0x1];
+    let (_, flag) = Flag::parse(&encoded).unwrap();
+
+    assert!(*flag.0);
+    assert_eq!(encoded.to_vec(), flag.build());
+}
+
+#[should_panic(
+    expected = "UnmatchedTag { expected: Tag { number: 2, type: Primitive, class: Application }, found: Tag { number: 2, type: Primitive, class: Universal } }"
+)]
+#[test]
+fn test_parse_implicit_newtype_with_inner_tag() {
+    Microseconds::parse(&[0x2, 0x1, 0x5]).unwrap();
+}
+
+#[test]
+fn test_implicit_newtype_tag() {
+    assert_eq!(
+        Tag::new(2, TagType::Primitive, TagClass::Application),
+        Microseconds::tag()
+    );
+}
+
+#[test]
+fn test_newtype_tags() {
+    assert_eq!(GeneralString::TAGS, KerberosString::TAGS);
+    assert_eq!(&[Realm::tag()], Realm::TAGS);
+    assert_eq!(&[Microseconds::tag()], Microseconds::TAGS);
+}
+
+#[test]
+fn test_build_and_parse_generic_newtype() {
+    let raw = vec![0xe4, 0x3, 0x2, 0x1, 0x7];
+    assert_eq!(raw, Wrapper::<Integer>(7).build());
+    assert_eq!(Wrapper(7), Wrapper::<Integer>::parse(&raw).unwrap().1);
+}
+
+#[test]
+fn test_newtypes_in_sequence() {
+    let principal = Principal {
+        realm: None,
+        usec: Some(Microseconds(5)),
+        name: KerberosString("ab".to_string()),
+    };
+    let raw = vec![0x30, 0x7, 0x42, 0x1, 0x5, 0x1b, 0x2, 0x61, 0x62];
+
+    assert_eq!(raw, principal.build());
+    assert_eq!(principal, Principal::parse(&raw).unwrap().1);
+}
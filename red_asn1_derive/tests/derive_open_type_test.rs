@@ -0,0 +1,153 @@
+use himmelblau_red_asn1::{
+    Any, Asn1Object, Asn1OpenType, GeneralString, Integer, OctetString, Oid,
+};
+use himmelblau_red_asn1_derive::{OpenType, Sequence};
+
+#[derive(OpenType, Debug, PartialEq)]
+#[open_type(discriminant = "Integer")]
+enum PaValue {
+    #[open_value(id = 1)]
+    Number(Integer),
+    #[open_value(id = 2)]
+    Text(GeneralString),
+    #[open_value(unknown)]
+    Unknown(Any),
+}
+
+impl Default for PaValue {
+    fn default() -> Self {
+        return Self::Unknown(Any::default());
+    }
+}
+
+#[derive(Sequence, Default)]
+struct PaData {
+    #[seq_field(context_tag = 1)]
+    padata_type: Integer,
+    #[seq_field(context_tag = 2, defined_by = "padata_type")]
+    padata_value: PaValue,
+}
+
+#[derive(OpenType, Debug, PartialEq)]
+#[open_type(discriminant = "Oid")]
+enum AlgorithmParameters {
+    #[open_value(id = "Oid::new(1, 2, vec![840, 113549, 1, 1, 1])")]
+    Rsa(OctetString),
+}
+
+#[derive(Sequence, Default)]
+struct AlgorithmIdentifier {
+    algorithm: Oid,
+    #[seq_field(defined_by = "algorithm")]
+    parameters: Option<AlgorithmParameters>,
+}
+
+#[test]
+fn test_build_known_value() {
+    let padata = PaData {
+        padata_type: 2,
+        padata_value: PaValue::Text("ab".to_string()),
+    };
+
+    assert_eq!(
+        vec![
+            0x30, 0xb, 0xa1, 0x3, 0x2, 0x1, 0x2, 0xa2, 0x4, 0x1b, 0x2, 0x61,
+            0x62
+        ],
+        padata.build()
+    );
+}
+
+#[test]
+fn test_parse_known_values() {
+    let (_, padata) = PaData::parse(&[
+        0x30, 0xa, 0xa1, 0x3, 0x2, 0x1, 0x1, 0xa2, 0x3, 0x2, 0x1, 0x9,
+    ])
+    .unwrap();
+    assert_eq!(PaValue::Number(9), padata.padata_value);
+
+    let (_, padata) = PaData::parse(&[
+        0x30, 0xb, 0xa1, 0x3, 0x2, 0x1, 0x2, 0xa2, 0x4, 0x1b, 0x2, 0x61, 0x62,
+    ])
+    .unwrap();
+    assert_eq!(PaValue::Text("ab".to_string()), padata.padata_value);
+}
+
+#[test]
+fn test_parse_and_build_unknown_value() {
+    let raw = [
+        0x30, 0xb, 0xa1, 0x3, 0x2, 0x1, 0x7, 0xa2, 0x4, 0x4, 0x2, 0xff, 0xee,
+    ];
+    let (_, padata) = PaData::parse(&raw).unwrap();
+
+    assert_eq!(
+        PaValue::Unknown(Any::new(vec![0x4, 0x2, 0xff, 0xee])),
+        padata.padata_value
+    );
+    assert_eq!(raw.to_vec(), padata.build());
+}
+
+#[should_panic(
+    expected = "SequenceFieldError { sequence: \"PaData\", field: \"padata_value\", offset: 7, error: UnmatchedTag { expected: Tag { number: 27, type: Primitive, class: Universal }, found: Tag { number: 2, type: Primitive, class: Universal } } }"
+)]
+#[test]
+fn test_parse_known_value_with_bad_type() {
+    PaData::parse(&[
+        0x30, 0xa, 0xa1, 0x3, 0x2, 0x1, 0x2, 0xa2, 0x3, 0x2, 0x1, 0x9,
+    ])
+    .unwrap();
+}
+
+#[test]
+fn test_parse_optional_open_type() {
+    let (_, alg_id) = AlgorithmIdentifier::parse(&[
+        0x30, 0xf, 0x6, 0x9, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0xd, 0x1, 0x1, 0x1,
+        0x4, 0x2, 0x1, 0x2,
+    ])
+    .unwrap();
+    assert_eq!(
+        Some(AlgorithmParameters::Rsa(vec![0x1, 0x2])),
+        alg_id.parameters
+    );
+
+    let (_, alg_id) = AlgorithmIdentifier::parse(&[
+        0x30, 0xb, 0x6, 0x9, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0xd, 0x1, 0x1, 0x1,
+    ])
+    .unwrap();
+    assert_eq!(None, alg_id.parameters);
+}
+
+#[test]
+fn test_build_optional_open_type() {
+    let alg_id = AlgorithmIdentifier {
+        algorithm: Oid::new(1, 2, vec![840, 113549, 1, 1, 1]),
+        parameters: None,
+    };
+    assert_eq!(
+        vec![
+            0x30, 0xb, 0x6, 0x9, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0xd, 0x1, 0x1,
+            0x1
+        ],
+        alg_id.build()
+    );
+}
+
+#[should_panic(
+    expected = "SequenceFieldError { sequence: \"AlgorithmIdentifier\", field: \"parameters\", offset: 7, error: UnknownDiscriminant(\"AlgorithmParameters\") }"
+)]
+#[test]
+fn test_parse_unknown_value_without_unknown_variant() {
+    AlgorithmIdentifier::parse(&[
+        0x30, 0x9, 0x6, 0x3, 0x2a, 0x86, 0x48, 0x4, 0x2, 0x1, 0x2,
+    ])
+    .unwrap();
+}
+
+#[test]
+fn test_parse_open_directly() {
+    let x: &[u8] = &[0x22];
+    assert_eq!(
+        (x, PaValue::Number(9)),
+        PaValue::parse_open(&1, &[0x2, 0x1, 0x9, 0x22]).unwrap()
+    );
+}
@@ -1,4 +1,4 @@
-use himmelblau_red_asn1::{Integer, OctetString, Asn1Object, SequenceOf, INTEGER_TAG_NUMBER, OCTET_STRING_TAG_NUMBER};
+use himmelblau_red_asn1::{Any, Integer, OctetString, Asn1Components, Asn1Object, Application, Containing, Context, Explicit, Implicit, Raw, SequenceOf, Tag, TagClass, TagType, INTEGER_TAG_NUMBER, OCTET_STRING_TAG_NUMBER};
 use himmelblau_red_asn1_derive::Sequence;
 
 #[test]
@@ -9,9 +9,10 @@ fn test_define_simple() {
         data: OctetString,
     }
 
-    let mut seq = TestSequence::default();
-    seq.id = 9;
-    seq.data = vec![1, 2, 3, 4];
+    let seq = TestSequence {
+        id: 9,
+        data: vec![1, 2, 3, 4],
+    };
 
     assert_eq!(Integer::from(9), seq.id);
     assert_eq!(OctetString::from(vec![1, 2, 3, 4]), seq.data);
@@ -24,6 +25,14 @@ fn test_define_with_inner_sequenceof() {
         id: Integer,
         attrs: SequenceOf<Integer>,
     }
+
+    let seq = TestSequence {
+        id: 9,
+        attrs: vec![1, 2],
+    };
+
+    assert_eq!(Integer::from(9), seq.id);
+    assert_eq!(vec![1, 2], seq.attrs);
 }
 
 #[test]
@@ -52,8 +61,7 @@ fn test_build() {
         age: Integer,
     }
 
-    let mut p = Person::default();
-    p.age = 9;
+    let p = Person { age: 9 };
 
     assert_eq!(vec![0x30, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x9], p.build());
 }
@@ -66,8 +74,7 @@ fn test_build_with_context_tags() {
         age: Integer,
     }
 
-    let mut p = Person::default();
-    p.age = 9;
+    let p = Person { age: 9 };
 
     assert_eq!(
         vec![0x30, 0x5, 0xa0, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x9],
@@ -82,8 +89,9 @@ fn test_build_with_optional_component() {
         age: Option<Integer>,
     }
 
-    let mut p = Person::default();
-    p.age = Some(Integer::from(9));
+    let p = Person {
+        age: Some(Integer::from(9)),
+    };
 
     assert_eq!(Some(9), p.age);
 }
@@ -133,11 +141,9 @@ fn test_build_with_inner_sequenceof() {
         attrs: SequenceOf<Integer>,
     }
 
-    let mut seq = TestSequence::default();
-    let mut seqof_ints: SequenceOf<Integer> = SequenceOf::default();
-    seqof_ints.push(Integer::from(1));
-
-    seq.attrs = seqof_ints;
+    let seq = TestSequence {
+        attrs: vec![Integer::from(1)],
+    };
 
     assert_eq!(
         vec![0x30, 0x5, 0x30, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x1],
@@ -178,7 +184,9 @@ fn test_parse_empty_with_excesive_bytes() {
     assert_eq!(x, rest);
 }
 
-#[should_panic(expected = "SequenceError(\"Person\", UnmatchedTag(Universal))")]
+#[should_panic(
+    expected = "SequenceError { sequence: \"Person\", offset: 0, error: UnmatchedTag { expected: Tag { number: 16, type: Constructed, class: Universal }, found: Tag { number: 0, type: Constructed, class: Private } } }"
+)]
 #[test]
 fn test_parse_with_invalid_tag() {
     #[derive(Sequence, Default)]
@@ -201,7 +209,9 @@ fn test_parse_with_context_tags() {
     assert_eq!(9, p.age);
 }
 
-#[should_panic(expected = "SequenceError(\"Person\", NoAllDataConsumed)")]
+#[should_panic(
+    expected = "SequenceError { sequence: \"Person\", offset: 2, error: NoAllDataConsumed }"
+)]
 #[test]
 fn test_parse_with_optional_with_bad_type_tag() {
     #[derive(Sequence, Default)]
@@ -212,7 +222,9 @@ fn test_parse_with_optional_with_bad_type_tag() {
     Person::parse(&[0x30, 0x1, 0xee]).unwrap();
 }
 
-#[should_panic(expected = "SequenceError(\"Person\", NoAllDataConsumed)")]
+#[should_panic(
+    expected = "SequenceError { sequence: \"Person\", offset: 2, error: NoAllDataConsumed }"
+)]
 #[test]
 fn test_parse_with_optional_with_bad_number_type_tag() {
     #[derive(Sequence, Default)]
@@ -236,7 +248,7 @@ fn test_parse_with_optional_and_context_tag() {
 }
 
 #[should_panic(
-    expected = "SequenceFieldError(\"Person\", \"age\", EmptyTag(Universal))"
+    expected = "SequenceFieldError { sequence: \"Person\", field: \"age\", offset: 2, error: EmptyTag(Universal) }"
 )]
 #[test]
 fn test_parse_with_optional_and_context_tag_bad_context_length() {
@@ -249,7 +261,9 @@ fn test_parse_with_optional_and_context_tag_bad_context_length() {
     Person::parse(&[0x30, 0x2, 0xa0, 0x0]).unwrap();
 }
 
-#[should_panic(expected = "SequenceError(\"Person\", NoAllDataConsumed)")]
+#[should_panic(
+    expected = "SequenceError { sequence: \"Person\", offset: 2, error: NoAllDataConsumed }"
+)]
 #[test]
 fn test_bad_parse_optional_context_tag_bad_context_tag() {
     #[derive(Sequence, Default)]
@@ -261,7 +275,9 @@ fn test_bad_parse_optional_context_tag_bad_context_tag() {
     Person::parse(&[0x30, 0x1, 0xee]).unwrap();
 }
 
-#[should_panic(expected = "SequenceError(\"Person\", UnmatchedTag(Universal))")]
+#[should_panic(
+    expected = "SequenceError { sequence: \"Person\", offset: 0, error: UnmatchedTag { expected: Tag { number: 16, type: Constructed, class: Universal }, found: Tag { number: 19, type: Constructed, class: Universal } } }"
+)]
 #[test]
 fn test_parse_bad_sequence_type_tag() {
     #[derive(Sequence, Default)]
@@ -270,7 +286,9 @@ fn test_parse_bad_sequence_type_tag() {
     Person::parse(&[0x33, 0x0]).unwrap();
 }
 
-#[should_panic(expected = "SequenceError(\"Person\", NotEnoughLengthOctects)")]
+#[should_panic(
+    expected = "SequenceError { sequence: \"Person\", offset: 0, error: NotEnoughLengthOctects }"
+)]
 #[test]
 fn test_parse_bad_sequence_length() {
     #[derive(Sequence, Default)]
@@ -280,7 +298,7 @@ fn test_parse_bad_sequence_length() {
 }
 
 #[should_panic(
-    expected = "SequenceError(\"Person\", UnmatchedTag(Application))"
+    expected = "SequenceError { sequence: \"Person\", offset: 0, error: UnmatchedTag { expected: Tag { number: 0, type: Constructed, class: Application }, found: Tag { number: 1, type: Constructed, class: Application } } }"
 )]
 #[test]
 fn test_parse_bad_sequence_application_tag() {
@@ -291,7 +309,9 @@ fn test_parse_bad_sequence_application_tag() {
     Person::parse(&[0x61, 0x0]).unwrap();
 }
 
-#[should_panic(expected = "SequenceError(\"Person\", NotEnoughLengthOctects)")]
+#[should_panic(
+    expected = "SequenceError { sequence: \"Person\", offset: 0, error: NotEnoughLengthOctects }"
+)]
 #[test]
 fn test_parse_sequence_application_tag_bad_length() {
     #[derive(Sequence, Default)]
@@ -302,7 +322,7 @@ fn test_parse_sequence_application_tag_bad_length() {
 }
 
 #[should_panic(
-    expected = "SequenceFieldError(\"Person\", \"age\", UnmatchedTag(Universal))"
+    expected = "SequenceFieldError { sequence: \"Person\", field: \"age\", offset: 2, error: UnmatchedTag { expected: Tag { number: 2, type: Primitive, class: Universal }, found: Tag { number: 14, type: Constructed, class: Private } } }"
 )]
 #[test]
 fn test_bad_parse_optional_context_tag_bad_type_tag() {
@@ -315,7 +335,9 @@ fn test_bad_parse_optional_context_tag_bad_type_tag() {
     Person::parse(&[0x30, 0x3, 0xa0, 0x1, 0xee]).unwrap();
 }
 
-#[should_panic(expected = "SequenceError(\"Person\", NoDataForLength)")]
+#[should_panic(
+    expected = "SequenceError { sequence: \"Person\", offset: 0, error: NoDataForLength }"
+)]
 #[test]
 fn test_bad_parse_not_enough_data_for_length() {
     #[derive(Sequence, Default)]
@@ -324,7 +346,9 @@ fn test_bad_parse_not_enough_data_for_length() {
     Person::parse(&[0x30, 0x1]).unwrap();
 }
 
-#[should_panic(expected = "SequenceError(\"Person\", NoDataForLength)")]
+#[should_panic(
+    expected = "SequenceError { sequence: \"Person\", offset: 0, error: NoDataForLength }"
+)]
 #[test]
 fn test_bad_parse_not_enough_data_for_length_with_application_tag() {
     #[derive(Sequence, Default)]
@@ -414,7 +438,7 @@ fn test_parse_with_optional_without_context_tag() {
 }
 
 #[should_panic(
-    expected = "SequenceFieldError(\"TestSequence\", \"id\", UnmatchedTag(Universal))"
+    expected = "SequenceFieldError { sequence: \"TestSequence\", field: \"id\", offset: 2, error: UnmatchedTag { expected: Tag { number: 2, type: Primitive, class: Universal }, found: Tag { number: 4, type: Primitive, class: Universal } } }"
 )]
 #[test]
 fn test_parse_with_optional_and_context_tag_and_bad_type_tag() {
@@ -492,7 +516,7 @@ fn test_parse_with_inner_sequenceof() {
 }
 
 #[should_panic(
-    expected = "SequenceFieldError(\"TestSequence\", \"id\", EmptyTag(Context))"
+    expected = "SequenceFieldError { sequence: \"TestSequence\", field: \"id\", offset: 2, error: EmptyTag(Context) }"
 )]
 #[test]
 fn test_parse_without_required_value() {
@@ -506,7 +530,7 @@ fn test_parse_without_required_value() {
 }
 
 #[should_panic(
-    expected = "SequenceFieldError(\"SuperTestSequence\", \"inner\", SequenceFieldError(\"TestSequence\", \"id\", EmptyTag(Context)))"
+    expected = "SequenceFieldError { sequence: \"SuperTestSequence\", field: \"inner\", offset: 2, error: SequenceFieldError { sequence: \"TestSequence\", field: \"id\", offset: 4, error: EmptyTag(Context) } }"
 )]
 #[test]
 fn test_parse_without_required_value_with_inner_sequence() {
@@ -523,3 +547,1028 @@ fn test_parse_without_required_value_with_inner_sequence() {
 
     SuperTestSequence::parse(&[0x30, 0x2, 0x30, 0x0]).unwrap();
 }
+
+#[test]
+fn test_build_and_parse_with_containing() {
+    #[derive(Sequence, Debug, PartialEq, Default)]
+    struct TestSequence {
+        #[seq_field(context_tag = 0)]
+        id: Integer,
+    }
+
+    #[derive(Sequence, Default)]
+    struct SuperTestSequence {
+        #[seq_field(context_tag = 1)]
+        inner: Containing<TestSequence>,
+    }
+
+    let raw = [
+        0x30, 0xb, 0xa1, 0x9, 0x4, 0x7, 0x30, 0x5, 0xa0, 0x3,
+        INTEGER_TAG_NUMBER, 0x1, 0x9,
+    ];
+
+    let (_, seq) = SuperTestSequence::parse(&raw).unwrap();
+    assert_eq!(9, seq.inner.id);
+    assert_eq!(raw.to_vec(), seq.build());
+}
+
+#[test]
+fn test_parse_and_build_with_raw() {
+    #[derive(Sequence, Debug, PartialEq, Default)]
+    struct TestSequence {
+        #[seq_field(context_tag = 0)]
+        id: Integer,
+    }
+
+    #[derive(Sequence, Default)]
+    struct SuperTestSequence {
+        inner: Raw<TestSequence>,
+        checksum: OctetString,
+    }
+
+    // inner sequence with a length in non minimal long form
+    let raw = [
+        0x30, 0xb, 0x30, 0x81, 0x5, 0xa0, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x9,
+        OCTET_STRING_TAG_NUMBER, 0x1, 0xff,
+    ];
+
+    let (_, seq) = SuperTestSequence::parse(&raw).unwrap();
+    assert_eq!(9, seq.inner.id);
+    assert_eq!(
+        &[0x30, 0x81, 0x5, 0xa0, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x9],
+        seq.inner.raw()
+    );
+    assert_eq!(raw.to_vec(), seq.build());
+}
+
+#[test]
+fn test_parse_and_build_with_implicit_raw() {
+    #[derive(Sequence, Default)]
+    struct TestSequence {
+        #[seq_field(context_tag = 0, implicit)]
+        data: Option<Raw<OctetString>>,
+        checksum: OctetString,
+    }
+
+    // implicit value with a length in non minimal long form
+    let raw = [
+        0x30, 0x8, 0x80, 0x81, 0x2, 0x1, 0x2, OCTET_STRING_TAG_NUMBER, 0x1,
+        0xff,
+    ];
+
+    let (_, seq) = TestSequence::parse(&raw).unwrap();
+    let data = seq.data.as_ref().unwrap();
+    assert_eq!(&vec![0x1, 0x2], &**data);
+    assert_eq!(&[0x80, 0x81, 0x2, 0x1, 0x2], data.raw());
+    assert_eq!(raw.to_vec(), seq.build());
+}
+
+#[test]
+fn test_build_and_parse_with_implicit_tags() {
+    #[derive(Sequence, Default)]
+    struct TestSequence {
+        #[seq_field(context_tag = 0, implicit)]
+        id: Integer,
+        #[seq_field(context_tag = 1, implicit)]
+        data: Option<OctetString>,
+        #[seq_field(context_tag = 2, implicit)]
+        attrs: SequenceOf<Integer>,
+    }
+
+    let seq = TestSequence {
+        id: 5,
+        data: Some(vec![0x1, 0x2]),
+        attrs: vec![1],
+    };
+
+    let raw = [
+        0x30, 0xc, 0x80, 0x1, 0x5, 0x81, 0x2, 0x1, 0x2, 0xa2, 0x3,
+        INTEGER_TAG_NUMBER, 0x1, 0x1,
+    ];
+    assert_eq!(raw.to_vec(), seq.build());
+
+    let (_, seq) = TestSequence::parse(&raw).unwrap();
+    assert_eq!(5, seq.id);
+    assert_eq!(Some(vec![0x1, 0x2]), seq.data);
+    assert_eq!(vec![1], seq.attrs);
+}
+
+#[test]
+fn test_parse_with_implicit_tag_without_optional_value() {
+    #[derive(Sequence, Default)]
+    struct TestSequence {
+        #[seq_field(context_tag = 0, implicit)]
+        data: Option<OctetString>,
+        #[seq_field(context_tag = 1, implicit)]
+        id: Integer,
+    }
+
+    let (_, seq) = TestSequence::parse(&[0x30, 0x3, 0x81, 0x1, 0x5]).unwrap();
+    assert_eq!(None, seq.data);
+    assert_eq!(5, seq.id);
+}
+
+#[test]
+fn test_build_and_parse_with_implicit_tagging_by_default() {
+    #[derive(Sequence, Default)]
+    #[seq(tagging = "implicit")]
+    struct TestSequence {
+        #[seq_field(context_tag = 0)]
+        id: Integer,
+        #[seq_field(context_tag = 1, explicit)]
+        data: OctetString,
+        other: Integer,
+    }
+
+    let seq = TestSequence {
+        id: 1,
+        data: vec![0x7],
+        other: 2,
+    };
+
+    let raw = [
+        0x30, 0xb, 0x80, 0x1, 0x1, 0xa1, 0x3, OCTET_STRING_TAG_NUMBER, 0x1,
+        0x7, INTEGER_TAG_NUMBER, 0x1, 0x2,
+    ];
+    assert_eq!(raw.to_vec(), seq.build());
+
+    let (_, seq) = TestSequence::parse(&raw).unwrap();
+    assert_eq!(1, seq.id);
+    assert_eq!(vec![0x7], seq.data);
+    assert_eq!(2, seq.other);
+}
+
+#[test]
+fn test_build_and_parse_with_implicit_inner_sequences() {
+    #[derive(Sequence, Default, Debug, PartialEq)]
+    struct Inner {
+        id: Integer,
+    }
+
+    #[derive(Sequence, Default, Debug, PartialEq)]
+    #[seq(application_tag = 7)]
+    struct InnerApp {
+        id: Integer,
+    }
+
+    #[derive(Sequence, Default)]
+    struct TestSequence {
+        #[seq_field(context_tag = 0, implicit)]
+        inner: Inner,
+        #[seq_field(context_tag = 1, implicit)]
+        inner_app: Option<InnerApp>,
+    }
+
+    let mut seq = TestSequence::default();
+    seq.inner.id = 1;
+    seq.inner_app = Some(InnerApp { id: 2 });
+
+    // the implicit tag replaces the application tag
+    let raw = [
+        0x30, 0xc, 0xa0, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x1, 0xa1, 0x5, 0x30,
+        0x3, INTEGER_TAG_NUMBER, 0x1, 0x2,
+    ];
+    assert_eq!(raw.to_vec(), seq.build());
+
+    let (_, seq) = TestSequence::parse(&raw).unwrap();
+    assert_eq!(Inner { id: 1 }, seq.inner);
+    assert_eq!(Some(InnerApp { id: 2 }), seq.inner_app);
+
+    let (_, seq) = TestSequence::parse(&[
+        0x30, 0x5, 0xa0, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x1,
+    ])
+    .unwrap();
+    assert_eq!(None, seq.inner_app);
+}
+
+#[should_panic(
+    expected = "SequenceFieldError { sequence: \"TestSequence\", field: \"id\", offset: 2, error: UnmatchedTag { expected: Tag { number: 0, type: Primitive, class: Context }, found: Tag { number: 1, type: Primitive, class: Context } } }"
+)]
+#[test]
+fn test_parse_with_bad_implicit_tag() {
+    #[derive(Sequence, Default)]
+    struct TestSequence {
+        #[seq_field(context_tag = 0, implicit)]
+        id: Integer,
+    }
+
+    TestSequence::parse(&[0x30, 0x3, 0x81, 0x1, 0x5]).unwrap();
+}
+
+#[test]
+fn test_build_and_parse_optional_sequence_with_application_tag() {
+    #[derive(Sequence, Default, Debug, PartialEq)]
+    #[seq(application_tag = 7)]
+    struct Inner {
+        id: Integer,
+    }
+
+    #[derive(Sequence, Default)]
+    struct TestSequence {
+        inner: Option<Inner>,
+    }
+
+    let raw = [0x30, 0x7, 0x67, 0x5, 0x30, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x1];
+    let (_, seq) = TestSequence::parse(&raw).unwrap();
+    assert_eq!(Some(Inner { id: 1 }), seq.inner);
+    assert_eq!(raw.to_vec(), seq.build());
+}
+
+#[test]
+fn test_build_and_parse_with_application_and_private_tags() {
+    #[derive(Sequence, Default)]
+    struct TestSequence {
+        #[seq_field(application_tag = 1)]
+        id: Integer,
+        #[seq_field(private_tag = 2, implicit)]
+        data: OctetString,
+        #[seq_field(application_tag = 3)]
+        extra: Option<Integer>,
+    }
+
+    let seq = TestSequence {
+        id: 5,
+        data: vec![0x1],
+        ..Default::default()
+    };
+
+    let raw = [
+        0x30, 0x8, 0x61, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x5, 0xc2, 0x1, 0x1,
+    ];
+    assert_eq!(raw.to_vec(), seq.build());
+
+    let (_, mut seq) = TestSequence::parse(&raw).unwrap();
+    assert_eq!(5, seq.id);
+    assert_eq!(vec![0x1], seq.data);
+    assert_eq!(None, seq.extra);
+
+    seq.extra = Some(7);
+    let raw = [
+        0x30, 0xd, 0x61, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x5, 0xc2, 0x1, 0x1,
+        0x63, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x7,
+    ];
+    assert_eq!(raw.to_vec(), seq.build());
+    assert_eq!(Some(7), TestSequence::parse(&raw).unwrap().1.extra);
+}
+
+#[should_panic(
+    expected = "SequenceFieldError { sequence: \"TestSequence\", field: \"id\", offset: 2, error: UnmatchedTag { expected: Tag { number: 1, type: Constructed, class: Application }, found: Tag { number: 1, type: Constructed, class: Context } } }"
+)]
+#[test]
+fn test_parse_with_bad_application_tag() {
+    #[derive(Sequence, Default)]
+    struct TestSequence {
+        #[seq_field(application_tag = 1)]
+        id: Integer,
+    }
+
+    TestSequence::parse(&[0x30, 0x5, 0xa1, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x5])
+        .unwrap();
+}
+
+#[should_panic(
+    expected = "SequenceFieldError { sequence: \"TestSequence\", field: \"id\", offset: 2, error: UnmatchedTag { expected: Tag { number: 1, type: Primitive, class: Private }, found: Tag { number: 1, type: Primitive, class: Context } } }"
+)]
+#[test]
+fn test_parse_with_bad_implicit_private_tag() {
+    #[derive(Sequence, Default)]
+    struct TestSequence {
+        #[seq_field(private_tag = 1, implicit)]
+        id: Integer,
+    }
+
+    TestSequence::parse(&[0x30, 0x3, 0x81, 0x1, 0x5]).unwrap();
+}
+
+#[should_panic(
+    expected = "SequenceFieldError { sequence: \"TestSequence\", field: \"id\", offset: 2, error: EmptyTag(Private) }"
+)]
+#[test]
+fn test_parse_without_private_tag() {
+    #[derive(Sequence, Default)]
+    struct TestSequence {
+        #[seq_field(private_tag = 1)]
+        id: Integer,
+    }
+
+    TestSequence::parse(&[0x30, 0x0]).unwrap();
+}
+
+#[test]
+fn test_build_and_parse_with_implicit_application_tag() {
+    #[derive(Sequence, Default, Debug, PartialEq)]
+    #[seq(application_tag = 0, implicit)]
+    struct BindRequest {
+        version: Integer,
+        name: OctetString,
+    }
+
+    let request = BindRequest {
+        version: 3,
+        name: vec![0x61],
+    };
+
+    let raw = [
+        0x60, 0x6, INTEGER_TAG_NUMBER, 0x1, 0x3, OCTET_STRING_TAG_NUMBER, 0x1,
+        0x61,
+    ];
+    assert_eq!(raw.to_vec(), request.build());
+    assert_eq!(request, BindRequest::parse(&raw).unwrap().1);
+}
+
+#[test]
+fn test_build_and_parse_with_implicit_tagging_in_application_tag() {
+    #[derive(Sequence, Default, Debug, PartialEq)]
+    #[seq(application_tag = 1, tagging = "implicit")]
+    struct TestSequence {
+        #[seq_field(context_tag = 0)]
+        id: Integer,
+    }
+
+    #[derive(Sequence, Default, Debug, PartialEq)]
+    #[seq(application_tag = 2, tagging = "implicit", explicit)]
+    struct ExplicitTestSequence {
+        #[seq_field(context_tag = 0)]
+        id: Integer,
+    }
+
+    let raw = [0x61, 0x3, 0x80, 0x1, 0x5];
+    assert_eq!(raw.to_vec(), TestSequence { id: 5 }.build());
+    assert_eq!(TestSequence { id: 5 }, TestSequence::parse(&raw).unwrap().1);
+
+    let raw = [0x62, 0x5, 0x30, 0x3, 0x80, 0x1, 0x5];
+    assert_eq!(raw.to_vec(), ExplicitTestSequence { id: 5 }.build());
+    assert_eq!(
+        ExplicitTestSequence { id: 5 },
+        ExplicitTestSequence::parse(&raw).unwrap().1
+    );
+}
+
+#[test]
+fn test_build_and_parse_with_other_class_tags() {
+    #[derive(Sequence, Default)]
+    #[seq(private_tag = 2)]
+    struct PrivateSequence {}
+
+    #[derive(Sequence, Default)]
+    #[seq(context_tag = 1, implicit)]
+    struct ContextSequence {}
+
+    assert_eq!(vec![0xe2, 0x2, 0x30, 0x0], PrivateSequence {}.build());
+    PrivateSequence::parse(&[0xe2, 0x2, 0x30, 0x0]).unwrap();
+
+    assert_eq!(vec![0xa1, 0x0], ContextSequence {}.build());
+    ContextSequence::parse(&[0xa1, 0x0]).unwrap();
+}
+
+#[test]
+fn test_parse_optional_sequence_with_implicit_application_tag() {
+    #[derive(Sequence, Default, Debug, PartialEq)]
+    #[seq(application_tag = 0, implicit)]
+    struct Inner {
+        id: Integer,
+    }
+
+    #[derive(Sequence, Default)]
+    struct TestSequence {
+        inner: Option<Inner>,
+        id: Integer,
+    }
+
+    let (_, seq) = TestSequence::parse(&[
+        0x30, 0x8, 0x60, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x1, INTEGER_TAG_NUMBER,
+        0x1, 0x2,
+    ])
+    .unwrap();
+    assert_eq!(Some(Inner { id: 1 }), seq.inner);
+    assert_eq!(2, seq.id);
+
+    let (_, seq) =
+        TestSequence::parse(&[0x30, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x2])
+            .unwrap();
+    assert_eq!(None, seq.inner);
+}
+
+#[should_panic(
+    expected = "SequenceError { sequence: \"BindRequest\", offset: 0, error: UnmatchedTag { expected: Tag { number: 0, type: Constructed, class: Application }, found: Tag { number: 16, type: Constructed, class: Universal } } }"
+)]
+#[test]
+fn test_parse_with_bad_implicit_application_tag() {
+    #[derive(Sequence, Default)]
+    #[seq(application_tag = 0, implicit)]
+    struct BindRequest {}
+
+    BindRequest::parse(&[0x30, 0x0]).unwrap();
+}
+
+#[should_panic(
+    expected = "SequenceError { sequence: \"TestSequence\", offset: 0, error: UnmatchedTag { expected: Tag { number: 2, type: Constructed, class: Private }, found: Tag { number: 2, type: Constructed, class: Application } } }"
+)]
+#[test]
+fn test_parse_with_bad_private_tag() {
+    #[derive(Sequence, Default)]
+    #[seq(private_tag = 2)]
+    struct TestSequence {}
+
+    TestSequence::parse(&[0x62, 0x2, 0x30, 0x0]).unwrap();
+}
+
+#[test]
+fn test_build_and_parse_with_tagged_types() {
+    type Version = Explicit<Application, 1, Integer>;
+    type Flags = Implicit<Context, 0, OctetString>;
+
+    #[derive(Sequence, Default)]
+    struct TestSequence {
+        version: Version,
+        flags: Option<Flags>,
+    }
+
+    let mut seq = TestSequence::default();
+    *seq.version = 2;
+    seq.flags = Some(Flags::new(vec![0x1]));
+
+    let raw = [0x30, 0x8, 0x61, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x2, 0x80, 0x1, 0x1];
+    assert_eq!(raw.to_vec(), seq.build());
+
+    let (_, seq) = TestSequence::parse(&raw).unwrap();
+    assert_eq!(2, *seq.version);
+    assert_eq!(Some(Flags::new(vec![0x1])), seq.flags);
+}
+
+#[test]
+fn test_build_and_parse_with_default_values() {
+    #[derive(Sequence, Default)]
+    struct Extension {
+        id: Integer,
+        #[seq_field(default = false)]
+        critical: bool,
+        extn_value: OctetString,
+    }
+
+    let extension = Extension {
+        id: 1,
+        extn_value: vec![0xff],
+        ..Default::default()
+    };
+
+    let raw = [
+        0x30, 0x6, INTEGER_TAG_NUMBER, 0x1, 0x1, OCTET_STRING_TAG_NUMBER, 0x1,
+        0xff,
+    ];
+    assert_eq!(raw.to_vec(), extension.build());
+
+    let (_, extension) = Extension::parse(&raw).unwrap();
+    assert!(!extension.critical);
+
+    let raw = [
+        0x30, 0x9, INTEGER_TAG_NUMBER, 0x1, 0x1, 0x1, 0x1, 0xff,
+        OCTET_STRING_TAG_NUMBER, 0x1, 0xff,
+    ];
+    let (_, extension) = Extension::parse(&raw).unwrap();
+    assert!(extension.critical);
+    assert_eq!(raw.to_vec(), extension.build());
+}
+
+#[test]
+fn test_build_and_parse_with_tagged_default_values() {
+    #[derive(Sequence)]
+    struct TestSequence {
+        #[seq_field(context_tag = 0, default = 0)]
+        version: Integer,
+        #[seq_field(context_tag = 1, implicit, default = "vec![0x1]")]
+        data: OctetString,
+    }
+
+    impl Default for TestSequence {
+        fn default() -> Self {
+            return Self {
+                version: 0,
+                data: vec![0x1],
+            };
+        }
+    }
+
+    let (_, seq) = TestSequence::parse(&[0x30, 0x0]).unwrap();
+    assert_eq!(0, seq.version);
+    assert_eq!(vec![0x1], seq.data);
+    assert_eq!(vec![0x30, 0x0], seq.build());
+
+    let raw = [
+        0x30, 0x9, 0xa0, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x2, 0x81, 0x2, 0x1,
+        0x2,
+    ];
+    let (_, seq) = TestSequence::parse(&raw).unwrap();
+    assert_eq!(2, seq.version);
+    assert_eq!(vec![0x1, 0x2], seq.data);
+    assert_eq!(raw.to_vec(), seq.build());
+
+    // encoded default values are accepted out of strict mode
+    let (_, seq) = TestSequence::parse(&[
+        0x30, 0x5, 0xa0, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x0,
+    ])
+    .unwrap();
+    assert_eq!(0, seq.version);
+}
+
+#[should_panic(
+    expected = "SequenceFieldError { sequence: \"TestSequence\", field: \"version\", offset: 2, error: IncorrectValue(\"Encoded default value\") }"
+)]
+#[test]
+fn test_parse_encoded_default_value_in_strict_mode() {
+    #[derive(Sequence, Default)]
+    #[seq(strict)]
+    struct TestSequence {
+        #[seq_field(context_tag = 0, default = 0)]
+        version: Integer,
+    }
+
+    TestSequence::parse(&[0x30, 0x5, 0xa0, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x0])
+        .unwrap();
+}
+
+#[should_panic(
+    expected = "SequenceFieldError { sequence: \"TestSequence\", field: \"critical\", offset: 2, error: IncorrectValue(\"Encoded default value\") }"
+)]
+#[test]
+fn test_parse_encoded_untagged_default_value_in_strict_mode() {
+    #[derive(Sequence, Default)]
+    #[seq(strict)]
+    struct TestSequence {
+        #[seq_field(default = false)]
+        critical: bool,
+    }
+
+    TestSequence::parse(&[0x30, 0x3, 0x1, 0x1, 0x0]).unwrap();
+}
+
+#[test]
+fn test_parse_extensible_with_unknown_elements() {
+    #[derive(Sequence, Default)]
+    #[seq(extensible)]
+    struct TestSequence {
+        id: Integer,
+    }
+
+    let (_, seq) = TestSequence::parse(&[
+        0x30, 0x6, INTEGER_TAG_NUMBER, 0x1, 0x1, OCTET_STRING_TAG_NUMBER, 0x1,
+        0xff,
+    ])
+    .unwrap();
+
+    assert_eq!(1, seq.id);
+    assert_eq!(vec![0x30, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x1], seq.build());
+}
+
+#[should_panic(
+    expected = "SequenceError { sequence: \"TestSequence\", offset: 5, error: NoDataForLength }"
+)]
+#[test]
+fn test_parse_extensible_with_invalid_unknown_elements() {
+    #[derive(Sequence, Default)]
+    #[seq(extensible)]
+    struct TestSequence {
+        id: Integer,
+    }
+
+    TestSequence::parse(&[
+        0x30, 0x5, INTEGER_TAG_NUMBER, 0x1, 0x1, OCTET_STRING_TAG_NUMBER, 0x5,
+    ])
+    .unwrap();
+}
+
+#[test]
+fn test_parse_and_build_keeping_unknown_elements() {
+    #[derive(Sequence, Default)]
+    struct TestSequence {
+        id: Integer,
+        #[seq_field(unknown)]
+        extensions: Vec<Any>,
+    }
+
+    let raw = [
+        0x30, 0xa, INTEGER_TAG_NUMBER, 0x1, 0x1, OCTET_STRING_TAG_NUMBER, 0x1,
+        0xff, 0xa3, 0x2, 0x5, 0x0,
+    ];
+    let (_, seq) = TestSequence::parse(&raw).unwrap();
+
+    assert_eq!(1, seq.id);
+    assert_eq!(
+        vec![
+            Any::new(vec![OCTET_STRING_TAG_NUMBER, 0x1, 0xff]),
+            Any::new(vec![0xa3, 0x2, 0x5, 0x0])
+        ],
+        seq.extensions
+    );
+    assert_eq!(raw.to_vec(), seq.build());
+
+    let (_, seq) =
+        TestSequence::parse(&[0x30, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x1])
+            .unwrap();
+    assert_eq!(Vec::<Any>::new(), seq.extensions);
+}
+
+#[test]
+fn test_build_and_parse_with_generics() {
+    #[derive(Sequence, Default, Debug, PartialEq)]
+    struct Signed<T> {
+        tbs: T,
+        alg: Integer,
+        sig: OctetString,
+    }
+
+    #[derive(Sequence, Default, Debug, PartialEq)]
+    struct Pair<K, V>
+    where
+        K: Clone,
+    {
+        key: K,
+        #[seq_field(context_tag = 0)]
+        values: Option<SequenceOf<V>>,
+    }
+
+    let signed = Signed {
+        tbs: Pair {
+            key: 1,
+            values: Some(vec![vec![0x1]]),
+        },
+        alg: 2,
+        sig: vec![0x3],
+    };
+
+    let raw = [
+        0x30, 0x12, 0x30, 0xa, INTEGER_TAG_NUMBER, 0x1, 0x1, 0xa0, 0x5, 0x30,
+        0x3, OCTET_STRING_TAG_NUMBER, 0x1, 0x1, INTEGER_TAG_NUMBER, 0x1, 0x2,
+        OCTET_STRING_TAG_NUMBER, 0x1, 0x3,
+    ];
+    assert_eq!(raw.to_vec(), signed.build());
+    assert_eq!(
+        signed,
+        Signed::<Pair<Integer, OctetString>>::parse(&raw).unwrap().1
+    );
+}
+
+#[test]
+fn test_build_and_parse_with_generic_marker() {
+    // the type parameter is not encoded, so it is not an Asn1Object
+    #[derive(Debug, PartialEq)]
+    struct Kerberos;
+
+    #[derive(Sequence, Debug, PartialEq)]
+    struct Key<P> {
+        value: OctetString,
+        #[seq_field(skip, default = "std::marker::PhantomData")]
+        protocol: std::marker::PhantomData<P>,
+    }
+
+    let key = Key::<Kerberos> {
+        value: vec![0x1],
+        protocol: std::marker::PhantomData,
+    };
+
+    let raw = [0x30, 0x3, OCTET_STRING_TAG_NUMBER, 0x1, 0x1];
+    assert_eq!(raw.to_vec(), key.build());
+    assert_eq!(key, Key::<Kerberos>::parse(&raw).unwrap().1);
+}
+
+#[test]
+fn test_parse_generic_optional_field_with_distinct_tags() {
+    #[derive(Sequence, Debug, PartialEq)]
+    struct Versioned<T> {
+        data: Option<T>,
+        version: Integer,
+    }
+
+    let raw = [
+        0x30, 0x6, OCTET_STRING_TAG_NUMBER, 0x1, 0x1, INTEGER_TAG_NUMBER, 0x1,
+        0x2,
+    ];
+    assert_eq!(
+        Versioned {
+            data: Some(vec![0x1]),
+            version: 2
+        },
+        Versioned::<OctetString>::parse(&raw).unwrap().1
+    );
+    assert_eq!(
+        &[Versioned::<OctetString>::tag()],
+        Versioned::<OctetString>::TAGS
+    );
+}
+
+mod qualified {
+    // Asn1Object is not imported, so the derive must not rely on it
+    pub type Number = himmelblau_red_asn1::Integer;
+
+    #[derive(himmelblau_red_asn1_derive::Sequence, Default, Debug, PartialEq)]
+    pub struct Qualified {
+        pub id: himmelblau_red_asn1::Integer,
+        #[seq_field(context_tag = 0)]
+        pub numbers: Option<Vec<Number>>,
+        pub data: std::option::Option<himmelblau_red_asn1::OctetString>,
+    }
+}
+
+#[test]
+fn test_build_and_parse_with_qualified_types() {
+    let seq = qualified::Qualified {
+        id: 1,
+        numbers: Some(vec![2, 3]),
+        data: Some(vec![0x4]),
+    };
+
+    let raw = [
+        0x30, 0x10, INTEGER_TAG_NUMBER, 0x1, 0x1, 0xa0, 0x8, 0x30, 0x6,
+        INTEGER_TAG_NUMBER, 0x1, 0x2, INTEGER_TAG_NUMBER, 0x1, 0x3,
+        OCTET_STRING_TAG_NUMBER, 0x1, 0x4,
+    ];
+    assert_eq!(raw.to_vec(), seq.build());
+    assert_eq!(seq, qualified::Qualified::parse(&raw).unwrap().1);
+}
+
+#[test]
+fn test_parse_with_qualified_optional_types() {
+    let seq = qualified::Qualified::parse(&[
+        0x30, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x1,
+    ])
+    .unwrap()
+    .1;
+
+    assert_eq!(1, seq.id);
+    assert_eq!(None, seq.numbers);
+    assert_eq!(None, seq.data);
+}
+
+type Maybe<T> = Option<T>;
+
+#[derive(Sequence, Default, Debug, PartialEq)]
+struct TestOptionalFlag {
+    #[seq_field(context_tag = 0, optional)]
+    id: Maybe<Integer>,
+    #[seq_field(context_tag = 1)]
+    data: OctetString,
+}
+
+#[test]
+fn test_build_and_parse_optional_flag() {
+    let mut seq = TestOptionalFlag {
+        data: vec![0x1],
+        ..Default::default()
+    };
+
+    let raw = [0x30, 0x5, 0xa1, 0x3, OCTET_STRING_TAG_NUMBER, 0x1, 0x1];
+    assert_eq!(raw.to_vec(), seq.build());
+    assert_eq!(seq, TestOptionalFlag::parse(&raw).unwrap().1);
+
+    seq.id = Some(7);
+    let raw = [
+        0x30, 0xa, 0xa0, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x7, 0xa1, 0x3,
+        OCTET_STRING_TAG_NUMBER, 0x1, 0x1,
+    ];
+    assert_eq!(raw.to_vec(), seq.build());
+    assert_eq!(seq, TestOptionalFlag::parse(&raw).unwrap().1);
+}
+
+/// Type named as Option, which is not optional
+mod version {
+    #[derive(himmelblau_red_asn1_derive::Newtype, Debug, PartialEq)]
+    pub struct Option(pub himmelblau_red_asn1::Integer);
+}
+
+#[test]
+fn test_build_and_parse_required_fields_named_option() {
+    use version::Option;
+
+    #[derive(Sequence, Debug, PartialEq)]
+    struct TestSequence {
+        #[seq_field(context_tag = 0)]
+        qualified: version::Option,
+        #[seq_field(context_tag = 1, optional = false)]
+        imported: Option,
+    }
+
+    let seq = TestSequence {
+        qualified: version::Option(1),
+        imported: Option(2),
+    };
+
+    let raw = [
+        0x30, 0xa, 0xa0, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x1, 0xa1, 0x3,
+        INTEGER_TAG_NUMBER, 0x1, 0x2,
+    ];
+    assert_eq!(raw.to_vec(), seq.build());
+    assert_eq!(seq, TestSequence::parse(&raw).unwrap().1);
+    assert!(
+        TestSequence::parse(&[0x30, 0x5, 0xa1, 0x3, INTEGER_TAG_NUMBER, 0x1, 0x2])
+            .is_err()
+    );
+}
+
+/// Type without Default, which can only be created from a valid port
+#[derive(Debug, PartialEq)]
+struct Port(u16);
+
+impl Asn1Object for Port {
+    fn tag() -> himmelblau_red_asn1::Tag {
+        return Integer::tag();
+    }
+
+    fn build_value(&self) -> Vec<u8> {
+        return Integer::from(self.0).build_value();
+    }
+
+    fn decode_value(raw: &[u8]) -> himmelblau_red_asn1::Result<Self> {
+        let value = Integer::decode_value(raw)?;
+        if value <= 0 || value > u16::MAX as Integer {
+            return Err(himmelblau_red_asn1::Error::IncorrectValue(
+                format!("Invalid port {}", value),
+            ));
+        }
+        return Ok(Self(value as u16));
+    }
+}
+
+#[derive(Sequence, Debug, PartialEq)]
+struct Service {
+    #[seq_field(context_tag = 0)]
+    port: Port,
+    #[seq_field(context_tag = 1)]
+    backup: Option<Port>,
+}
+
+#[test]
+fn test_build_and_decode_without_default() {
+    let service = Service {
+        port: Port(88),
+        backup: None,
+    };
+    let raw = vec![0x30, 0x5, 0xa0, 0x3, 0x2, 0x1, 0x58];
+
+    assert_eq!(raw, service.build());
+    assert_eq!(service, Service::decode(&raw).unwrap());
+    assert_eq!((&[][..], service), Service::parse(&raw).unwrap());
+}
+
+#[should_panic(expected = "NoAllDataConsumed")]
+#[test]
+fn test_decode_with_excesive_data() {
+    Service::decode(&[0x30, 0x5, 0xa0, 0x3, 0x2, 0x1, 0x58, 0x0]).unwrap();
+}
+
+#[should_panic(
+    expected = "SequenceFieldError { sequence: \"Service\", field: \"backup\", offset: 7, error: IncorrectValue(\"Invalid port 0\") }"
+)]
+#[test]
+fn test_decode_with_invalid_field_value() {
+    Service::decode(&[
+        0x30, 0xa, 0xa0, 0x3, 0x2, 0x1, 0x58, 0xa1, 0x3, 0x2, 0x1, 0x0,
+    ])
+    .unwrap();
+}
+
+/*
+Header ::= SEQUENCE {
+    version [0] INTEGER,
+    kind    [1] INTEGER
+}
+
+Message ::= SEQUENCE {
+    COMPONENTS OF Header,
+    data    [2] OCTET STRING OPTIONAL
+}
+*/
+#[derive(Sequence, Debug, PartialEq)]
+struct Header {
+    #[seq_field(context_tag = 0)]
+    version: Integer,
+    #[seq_field(context_tag = 1)]
+    kind: Integer,
+}
+
+#[derive(Sequence, Debug, PartialEq)]
+struct Message {
+    #[seq_field(flatten)]
+    header: Header,
+    #[seq_field(context_tag = 2)]
+    data: Option<OctetString>,
+}
+
+#[test]
+fn test_build_and_decode_flattened_field() {
+    let message = Message {
+        header: Header {
+            version: 5,
+            kind: 10,
+        },
+        data: Some(vec![0x1]),
+    };
+    let raw = vec![
+        0x30, 0xf, 0xa0, 0x3, 0x2, 0x1, 0x5, 0xa1, 0x3, 0x2, 0x1, 0xa, 0xa2,
+        0x3, 0x4, 0x1, 0x1,
+    ];
+
+    assert_eq!(raw, message.build());
+    assert_eq!(message, Message::decode(&raw).unwrap());
+}
+
+#[test]
+fn test_decode_flattened_field_followed_by_absent_field() {
+    let message = Message::decode(&[
+        0x30, 0xa, 0xa0, 0x3, 0x2, 0x1, 0x5, 0xa1, 0x3, 0x2, 0x1, 0xa,
+    ])
+    .unwrap();
+
+    assert_eq!(Header { version: 5, kind: 10 }, message.header);
+    assert_eq!(None, message.data);
+}
+
+#[should_panic(
+    expected = "SequenceFieldError { sequence: \"Message\", field: \"header\", offset: 2, error: SequenceFieldError { sequence: \"Header\", field: \"kind\", offset: 7, error: UnmatchedTag { expected: Tag { number: 1, type: Constructed, class: Context }, found: Tag { number: 2, type: Constructed, class: Context } } } }"
+)]
+#[test]
+fn test_decode_flattened_field_without_component() {
+    Message::decode(&[
+        0x30, 0xa, 0xa0, 0x3, 0x2, 0x1, 0x5, 0xa2, 0x3, 0x4, 0x1, 0x1,
+    ])
+    .unwrap();
+}
+
+#[test]
+fn test_tags_of_components() {
+    #[derive(Sequence)]
+    struct Trailer {
+        #[seq_field(context_tag = 3)]
+        first: Option<Integer>,
+        #[seq_field(context_tag = 4)]
+        second: Integer,
+        #[seq_field(context_tag = 5)]
+        third: Option<Integer>,
+    }
+
+    let context_tag =
+        |number| Tag::new(number, TagType::Constructed, TagClass::Context);
+
+    assert_eq!(&[context_tag(0)], Header::FIRST_TAGS);
+    assert!(Header::LAST_TAGS.is_empty());
+    assert_eq!(&[context_tag(3), context_tag(4)], Trailer::FIRST_TAGS);
+    assert_eq!(&[context_tag(5)], Trailer::LAST_TAGS);
+}
+
+#[derive(Sequence, Debug, PartialEq)]
+#[seq(post_parse = "compute_total")]
+struct Invoice {
+    #[seq_field(context_tag = 0)]
+    price: Integer,
+    #[seq_field(skip)]
+    total: Integer,
+    #[seq_field(skip, default = "String::from(\"EUR\")")]
+    currency: String,
+    #[seq_field(context_tag = 1)]
+    units: Option<Integer>,
+}
+
+fn compute_total(invoice: &mut Invoice) -> himmelblau_red_asn1::Result<()> {
+    if invoice.price < 0 {
+        return Err(himmelblau_red_asn1::Error::IncorrectValue(
+            "Negative price".to_string(),
+        ));
+    }
+    invoice.total = invoice.price * invoice.units.unwrap_or(1);
+    return Ok(());
+}
+
+#[test]
+fn test_build_ignores_skipped_fields() {
+    let invoice = Invoice {
+        price: 3,
+        total: 99,
+        currency: "USD".to_string(),
+        units: Some(2),
+    };
+
+    assert_eq!(
+        vec![0x30, 0xa, 0xa0, 0x3, 0x2, 0x1, 0x3, 0xa1, 0x3, 0x2, 0x1, 0x2],
+        invoice.build()
+    );
+}
+
+#[test]
+fn test_decode_initializes_skipped_fields() {
+    let invoice = Invoice::decode(&[
+        0x30, 0xa, 0xa0, 0x3, 0x2, 0x1, 0x3, 0xa1, 0x3, 0x2, 0x1, 0x2,
+    ])
+    .unwrap();
+
+    assert_eq!(3, invoice.price);
+    assert_eq!(6, invoice.total);
+    assert_eq!("EUR", invoice.currency);
+    assert_eq!(Some(2), invoice.units);
+}
+
+#[should_panic(
+    expected = "SequenceError { sequence: \"Invoice\", offset: 2, error: IncorrectValue(\"Negative price\") }"
+)]
+#[test]
+fn test_decode_with_post_parse_error() {
+    Invoice::decode(&[0x30, 0x5, 0xa0, 0x3, 0x2, 0x1, 0xff]).unwrap();
+}
@@ -0,0 +1,12 @@
+use himmelblau_red_asn1::Integer;
+use himmelblau_red_asn1_derive::Sequence;
+
+#[derive(Sequence)]
+#[seq(automatic_tags)]
+struct Person {
+    age: Option<Integer>,
+    #[seq_field(context_tag = 0)]
+    height: Integer,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use himmelblau_red_asn1::{Implicit, Context, Integer, OctetString};
+use himmelblau_red_asn1_derive::Choice;
+
+#[derive(Choice)]
+enum Key {
+    #[choice_field(context_tag = 0)]
+    Id(Integer),
+    Serial(Implicit<Context, 0, OctetString>),
+}
+
+fn main() {}
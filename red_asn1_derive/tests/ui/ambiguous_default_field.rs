@@ -0,0 +1,11 @@
+use himmelblau_red_asn1::Integer;
+use himmelblau_red_asn1_derive::Sequence;
+
+#[derive(Sequence)]
+struct Version {
+    #[seq_field(default = 1)]
+    major: Integer,
+    minor: Integer,
+}
+
+fn main() {}
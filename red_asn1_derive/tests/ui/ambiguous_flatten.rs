@@ -0,0 +1,20 @@
+use himmelblau_red_asn1::Integer;
+use himmelblau_red_asn1_derive::Sequence;
+
+#[derive(Sequence)]
+struct Header {
+    #[seq_field(context_tag = 0)]
+    version: Option<Integer>,
+    #[seq_field(context_tag = 1)]
+    kind: Integer,
+}
+
+#[derive(Sequence)]
+struct Message {
+    #[seq_field(context_tag = 1)]
+    id: Option<Integer>,
+    #[seq_field(flatten)]
+    header: Header,
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+use himmelblau_red_asn1::{GeneralString, Integer};
+use himmelblau_red_asn1_derive::{Choice, Sequence};
+
+#[derive(Choice)]
+enum PrincipalName {
+    Id(Integer),
+    Name(GeneralString),
+}
+
+#[derive(Sequence)]
+struct Principal {
+    name: Option<PrincipalName>,
+    #[seq_field(context_tag = 0)]
+    realm: GeneralString,
+    kvno: GeneralString,
+}
+
+#[derive(Sequence)]
+struct Ticket {
+    #[seq_field(context_tag = 0)]
+    sname: Option<PrincipalName>,
+    #[seq_field(context_tag = 1)]
+    realm: GeneralString,
+    #[seq_field(context_tag = 2)]
+    kvno: Option<Integer>,
+    principal: Option<PrincipalName>,
+    version: Integer,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use himmelblau_red_asn1::Integer;
+use himmelblau_red_asn1_derive::Sequence;
+
+#[derive(Sequence)]
+struct Person {
+    age: Option<Integer>,
+    height: Option<Integer>,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use himmelblau_red_asn1::Integer;
+use himmelblau_red_asn1_derive::Sequence;
+
+#[derive(Sequence)]
+struct Header {
+    version: Integer,
+}
+
+#[derive(Sequence)]
+#[seq(automatic_tags)]
+struct Message {
+    id: Integer,
+    #[seq_field(flatten)]
+    header: Header,
+}
+
+fn main() {}
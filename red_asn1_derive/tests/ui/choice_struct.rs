@@ -0,0 +1,9 @@
+use himmelblau_red_asn1::Integer;
+use himmelblau_red_asn1_derive::Choice;
+
+#[derive(Choice, Default)]
+struct Name {
+    id: Integer,
+}
+
+fn main() {}
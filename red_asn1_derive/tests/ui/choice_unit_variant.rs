@@ -0,0 +1,10 @@
+use himmelblau_red_asn1::Integer;
+use himmelblau_red_asn1_derive::Choice;
+
+#[derive(Choice)]
+enum Name {
+    Id(Integer),
+    Anonymous,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use himmelblau_red_asn1::Any;
+use himmelblau_red_asn1_derive::Sequence;
+
+#[derive(Sequence, Default)]
+struct AlgorithmIdentifier {
+    #[seq_field(defined_by = "algorithm")]
+    parameters: Option<Any>,
+}
+
+fn main() {}
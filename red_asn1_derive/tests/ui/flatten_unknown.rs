@@ -0,0 +1,18 @@
+use himmelblau_red_asn1::{Any, Integer};
+use himmelblau_red_asn1_derive::Sequence;
+
+#[derive(Sequence)]
+struct Header {
+    version: Integer,
+    #[seq_field(unknown)]
+    extensions: Vec<Any>,
+}
+
+#[derive(Sequence)]
+struct Message {
+    #[seq_field(flatten)]
+    header: Header,
+    id: Integer,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use himmelblau_red_asn1::{Integer, OctetString};
+use himmelblau_red_asn1_derive::{Choice, Sequence};
+
+#[derive(Choice)]
+enum Key {
+    Id(Integer),
+    Serial(OctetString),
+}
+
+#[derive(Sequence)]
+struct Account {
+    #[seq_field(context_tag = 0, implicit)]
+    key: Key,
+}
+
+fn main() {}
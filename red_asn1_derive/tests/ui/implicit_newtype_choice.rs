@@ -0,0 +1,14 @@
+use himmelblau_red_asn1::{Integer, OctetString};
+use himmelblau_red_asn1_derive::{Choice, Newtype};
+
+#[derive(Choice)]
+enum Key {
+    Id(Integer),
+    Serial(OctetString),
+}
+
+#[derive(Newtype)]
+#[newtype(application_tag = 1, implicit)]
+struct TaggedKey(Key);
+
+fn main() {}
@@ -0,0 +1,20 @@
+use himmelblau_red_asn1_derive::Sequence;
+
+mod flag {
+    pub fn build(value: &bool) -> Vec<u8> {
+        return vec![0x1, 0x1, *value as u8];
+    }
+
+    pub fn parse(raw: &[u8]) -> himmelblau_red_asn1::Result<(&[u8], bool)> {
+        return Ok((&raw[3..], raw[2] != 0));
+    }
+}
+
+#[derive(Sequence)]
+#[seq(tagging = "implicit")]
+struct Account {
+    #[seq_field(context_tag = 0, with = "flag")]
+    enabled: bool,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use himmelblau_red_asn1::Integer;
+use himmelblau_red_asn1_derive::Sequence;
+
+#[derive(Sequence, Default)]
+struct Person {
+    #[seq_field = "context_tag"]
+    age: Integer,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use himmelblau_red_asn1::{GeneralString, SequenceOf};
+use himmelblau_red_asn1_derive::Sequence;
+
+#[derive(Sequence)]
+struct PrincipalName {
+    #[seq_field(context_tag = 0, size = "one or more")]
+    name_string: SequenceOf<GeneralString>,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use himmelblau_red_asn1::Integer;
+use himmelblau_red_asn1_derive::Sequence;
+
+#[derive(Sequence)]
+struct Header {
+    version: Integer,
+}
+
+#[derive(Sequence)]
+struct Message {
+    #[seq_field(context_tag = 0, flatten)]
+    header: Header,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use himmelblau_red_asn1::Integer;
+use himmelblau_red_asn1_derive::Sequence;
+
+#[derive(Sequence)]
+struct Person {
+    #[seq_field(context_tag = 0)]
+    age: Integer,
+    #[seq_field(context_tag = 1, skip)]
+    cached_age: Integer,
+}
+
+fn main() {}
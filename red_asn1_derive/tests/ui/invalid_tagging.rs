@@ -0,0 +1,11 @@
+use himmelblau_red_asn1::Integer;
+use himmelblau_red_asn1_derive::Sequence;
+
+#[derive(Sequence, Default)]
+#[seq(tagging = "automatic")]
+struct Person {
+    #[seq_field(context_tag = 0)]
+    age: Integer,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use himmelblau_red_asn1::Integer;
+use himmelblau_red_asn1_derive::Newtype;
+
+#[derive(Newtype, Default)]
+struct Range(Integer, Integer);
+
+fn main() {}
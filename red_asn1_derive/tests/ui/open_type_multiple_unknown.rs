@@ -0,0 +1,15 @@
+use himmelblau_red_asn1::{Any, OctetString};
+use himmelblau_red_asn1_derive::OpenType;
+
+#[derive(OpenType)]
+#[open_type(discriminant = "himmelblau_red_asn1::Integer")]
+enum Parameters {
+    #[open_value(id = 1)]
+    Rsa(OctetString),
+    #[open_value(unknown)]
+    Unknown(Any),
+    #[open_value(unknown)]
+    Other(Any),
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use himmelblau_red_asn1::OctetString;
+use himmelblau_red_asn1_derive::OpenType;
+
+#[derive(OpenType)]
+#[open_type(discriminant = "himmelblau_red_asn1::Integer")]
+enum Parameters {
+    #[open_value(id = 1)]
+    Rsa(OctetString),
+    #[open_value(unknown)]
+    Unknown(OctetString),
+}
+
+fn main() {}
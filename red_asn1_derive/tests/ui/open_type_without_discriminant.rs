@@ -0,0 +1,10 @@
+use himmelblau_red_asn1::OctetString;
+use himmelblau_red_asn1_derive::OpenType;
+
+#[derive(OpenType)]
+enum Parameters {
+    #[open_value(id = 1)]
+    Rsa(OctetString),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use himmelblau_red_asn1::OctetString;
+use himmelblau_red_asn1_derive::OpenType;
+
+#[derive(OpenType)]
+#[open_type(discriminant = "himmelblau_red_asn1::Integer")]
+enum Parameters {
+    Rsa(OctetString),
+}
+
+fn main() {}
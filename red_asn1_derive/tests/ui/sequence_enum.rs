@@ -0,0 +1,9 @@
+use himmelblau_red_asn1::Integer;
+use himmelblau_red_asn1_derive::Sequence;
+
+#[derive(Sequence)]
+enum Person {
+    Age(Integer),
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use himmelblau_red_asn1::Integer;
+use himmelblau_red_asn1_derive::Sequence;
+
+#[derive(Sequence, Default)]
+struct Age(Integer);
+
+fn main() {}
@@ -0,0 +1,12 @@
+use himmelblau_red_asn1::{Any, Integer};
+use himmelblau_red_asn1_derive::Sequence;
+
+#[derive(Sequence, Default)]
+#[seq(extensible)]
+struct Person {
+    #[seq_field(unknown)]
+    unknown: Vec<Any>,
+    age: Integer,
+}
+
+fn main() {}
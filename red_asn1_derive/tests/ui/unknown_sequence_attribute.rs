@@ -0,0 +1,10 @@
+use himmelblau_red_asn1::Integer;
+use himmelblau_red_asn1_derive::Sequence;
+
+#[derive(Sequence, Default)]
+#[seq(application_tag = 1, strcit)]
+struct Person {
+    age: Integer,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use himmelblau_red_asn1::{Any, Integer, Oid};
+use himmelblau_red_asn1_derive::{OpenType, Sequence};
+
+#[derive(OpenType)]
+#[open_type(discriminant = "Oid")]
+enum Parameters {
+    #[open_value(unknown)]
+    Unknown(Any),
+}
+
+#[derive(Sequence)]
+struct AlgorithmIdentifier {
+    algorithm: Oid,
+    #[seq_field(defined_by = "algorithm")]
+    parameters: Option<Parameters>,
+    version: Integer,
+}
+
+fn main() {}